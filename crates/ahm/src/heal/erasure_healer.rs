@@ -20,7 +20,7 @@ use crate::heal::{
 use crate::{Error, Result};
 use futures::future::join_all;
 use rustfs_common::heal_channel::{HealOpts, HealScanMode};
-use rustfs_ecstore::disk::DiskStore;
+use rustfs_ecstore::disk::{DiskActivityGuard, DiskStore};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{error, info, warn};
@@ -52,6 +52,7 @@ impl ErasureSetHealer {
     #[tracing::instrument(skip(self, buckets), fields(set_disk_id = %set_disk_id, bucket_count = buckets.len()))]
     pub async fn heal_erasure_set(&self, buckets: &[String], set_disk_id: &str) -> Result<()> {
         info!("Starting erasure set heal");
+        let _healing = DiskActivityGuard::healing(self.disk.clone());
 
         // 1. generate or get task id
         let task_id = self.get_or_create_task_id(set_disk_id).await?;
@@ -653,6 +653,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
         let disk = new_disk(&endpoint, &disk_option).await.unwrap();
 
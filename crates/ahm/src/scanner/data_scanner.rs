@@ -30,7 +30,7 @@ use rustfs_ecstore::{
     bucket::versioning::VersioningApi,
     bucket::versioning_sys::BucketVersioningSys,
     data_usage::{aggregate_local_snapshots, compute_bucket_usage, store_data_usage_in_backend},
-    disk::{DiskAPI, DiskStore, RUSTFS_META_BUCKET, WalkDirOptions},
+    disk::{DiskAPI, DiskActivityGuard, DiskStore, RUSTFS_META_BUCKET, WalkDirOptions},
     set_disk::SetDisks,
     store_api::ObjectInfo,
 };
@@ -1634,6 +1634,7 @@ impl Scanner {
                         return Err(Error::Other("Semaphore acquisition failed".to_string()));
                     }
                 };
+                let _scanning = DiskActivityGuard::scanning(disk.clone());
                 scanner.scan_disk(&disk).await
             };
 
@@ -1911,6 +1912,9 @@ impl Scanner {
             forward_to: None,
             limit: 0,
             disk_id: String::new(),
+            // Shutdown stops the walk, the entries listed so far are still processed
+            cancel: get_ahm_services_cancel_token().cloned(),
+            skip_internal: true,
         };
 
         // Use a buffer to collect scan results for processing
@@ -80,12 +80,7 @@ const SERVER_PING_TIMEOUT: Duration = Duration::from_secs(1);
 // }
 
 async fn is_server_resolvable(endpoint: &Endpoint) -> Result<()> {
-    let addr = format!(
-        "{}://{}:{}",
-        endpoint.url.scheme(),
-        endpoint.url.host_str().unwrap(),
-        endpoint.url.port().unwrap()
-    );
+    let addr = endpoint.try_grid_host()?;
 
     let ping_task = async {
         let mut fbb = flatbuffers::FlatBufferBuilder::new();
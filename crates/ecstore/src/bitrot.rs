@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::disk::error::DiskError;
-use crate::disk::{self, DiskAPI as _, DiskStore};
+use crate::disk::{self, DiskAPI as _, DiskStore, ReadOptions};
 use crate::erasure_coding::{BitrotReader, BitrotWriterWrapper, CustomWriter};
 use rustfs_utils::HashAlgorithm;
 use std::io::Cursor;
@@ -52,7 +52,10 @@ pub async fn create_bitrot_reader(
         Ok(Some(reader))
     } else if let Some(disk) = disk {
         // Read from disk
-        match disk.read_file_stream(bucket, path, offset, length - offset).await {
+        match disk
+            .read_file_stream(bucket, path, offset, length - offset, &ReadOptions::default())
+            .await
+        {
             Ok(rd) => {
                 let reader = BitrotReader::new(rd, shard_size, checksum_algo);
                 Ok(Some(reader))
@@ -186,4 +189,81 @@ mod tests {
         println!("error: {error:?}");
         assert_eq!(error, DiskError::DiskNotFound);
     }
+
+    #[tokio::test]
+    async fn test_erasure_round_trip_direct_io() {
+        use crate::disk::endpoint::Endpoint;
+        use crate::disk::{DiskOption, new_disk};
+        use crate::erasure_coding::Erasure;
+        use std::sync::Arc;
+        use tempfile::TempDir;
+
+        let test_dir = TempDir::new().unwrap();
+        let opt = DiskOption {
+            direct_io: true,
+            ..Default::default()
+        };
+        let mut disks = Vec::new();
+        for i in 0..4 {
+            let dir = test_dir.path().join(format!("d{i}"));
+            tokio::fs::create_dir_all(&dir).await.unwrap();
+            let disk = new_disk(&Endpoint::try_from(dir.to_str().unwrap()).unwrap(), &opt)
+                .await
+                .unwrap();
+            disk.make_volume("bucket").await.unwrap();
+            disks.push(disk);
+        }
+
+        // Shard files large enough for O_DIRECT with a tail that is not block aligned
+        let erasure = Arc::new(Erasure::new(2, 2, 1 << 20));
+        let data: Vec<u8> = (0..(2 << 20) + 12345).map(|i| (i % 251) as u8).collect();
+        let algo = HashAlgorithm::HighwayHash256;
+
+        let mut writers = Vec::new();
+        for disk in disks.iter() {
+            let shard_file_size = erasure.shard_file_size(data.len() as i64);
+            let writer = create_bitrot_writer(
+                false,
+                Some(disk),
+                "bucket",
+                "object/part.1",
+                shard_file_size,
+                erasure.shard_size(),
+                algo.clone(),
+            )
+            .await
+            .unwrap();
+            writers.push(Some(writer));
+        }
+        let (_, total) = erasure
+            .clone()
+            .encode(Cursor::new(data.clone()), &mut writers, 3)
+            .await
+            .unwrap();
+        assert_eq!(total, data.len());
+        drop(writers);
+
+        let shard_file_size = erasure.shard_file_size(data.len() as i64) as usize;
+        let mut readers = Vec::new();
+        for disk in disks.iter() {
+            let reader = create_bitrot_reader(
+                None,
+                Some(disk),
+                "bucket",
+                "object/part.1",
+                0,
+                shard_file_size,
+                erasure.shard_size(),
+                algo.clone(),
+            )
+            .await
+            .unwrap();
+            readers.push(reader);
+        }
+        let mut got = Vec::new();
+        let (n, err) = erasure.decode(&mut got, readers, 0, data.len(), data.len()).await;
+        assert!(err.is_none(), "{err:?}");
+        assert_eq!(n, data.len());
+        assert_eq!(got, data);
+    }
 }
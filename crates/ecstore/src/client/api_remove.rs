@@ -38,7 +38,7 @@ use crate::{
     disk::DiskAPI,
     store_api::{GetObjectReader, ObjectInfo, StorageAPI},
 };
-use rustfs_utils::hash::EMPTY_STRING_SHA256_HASH;
+use rustfs_utils::hash::{EMPTY_STRING_SHA256_HASH, sum_sha256_hex};
 
 pub struct RemoveBucketOptions {
     _forced_delete: bool,
@@ -330,7 +330,7 @@ impl TransitionClient {
                         content_body: ReaderImpl::Body(Bytes::from(remove_bytes.clone())),
                         content_length: remove_bytes.len() as i64,
                         content_md5_base64: base64_encode(&HashAlgorithm::Md5.hash_encode(&remove_bytes).as_ref()),
-                        content_sha256_hex: base64_encode(&HashAlgorithm::SHA256.hash_encode(&remove_bytes).as_ref()),
+                        content_sha256_hex: sum_sha256_hex(&remove_bytes),
                         custom_header: headers,
                         object_name: "".to_string(),
                         stream_sha256: false,
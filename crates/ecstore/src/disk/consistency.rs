@@ -0,0 +1,260 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::error::{DiskError, Result};
+use super::error_reduce::{OBJECT_OP_IGNORED_ERRS, is_ignored_err};
+use super::{DiskAPI, DiskStore, ReadOptions};
+use futures::future::join_all;
+use rustfs_filemeta::FileInfo;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Outcome of comparing one logical object across an erasure set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyVerdict {
+    /// Every online disk agrees.
+    Consistent,
+    /// A quorum agrees, but some online disks disagree or miss the object.
+    NeedsHeal,
+    /// No group of agreeing disks reaches the read quorum.
+    QuorumLost,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsistencyReport {
+    pub verdict: ConsistencyVerdict,
+    /// Number of disks agreeing with the majority version.
+    pub agreeing: usize,
+    /// Indices of online disks that disagree with the majority version.
+    pub outliers: Vec<usize>,
+    /// Indices of disks that were offline and therefore not compared.
+    pub offline: Vec<usize>,
+}
+
+/// Fields that must match between disks holding the same object version.
+///
+/// Bitrot checksums in `erasure.checksums` are per shard and are expected to differ,
+/// so only the object level part checksums are compared.
+#[derive(Debug, PartialEq)]
+struct VersionSignature {
+    version_id: Option<Uuid>,
+    deleted: bool,
+    mod_time: Option<OffsetDateTime>,
+    num_versions: usize,
+    size: i64,
+    parts: Vec<(usize, usize, i64, String, Option<Vec<(String, String)>>)>,
+}
+
+impl From<&FileInfo> for VersionSignature {
+    fn from(fi: &FileInfo) -> Self {
+        let parts = fi
+            .parts
+            .iter()
+            .map(|p| {
+                let checksums = p.checksums.as_ref().map(|m| {
+                    let mut sums: Vec<(String, String)> = m.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                    sums.sort();
+                    sums
+                });
+                (p.number, p.size, p.actual_size, p.etag.clone(), checksums)
+            })
+            .collect();
+
+        Self {
+            version_id: fi.version_id,
+            deleted: fi.deleted,
+            mod_time: fi.mod_time,
+            num_versions: fi.num_versions,
+            size: fi.size,
+            parts,
+        }
+    }
+}
+
+/// Reads `object` from every disk and reports whether the copies agree.
+pub async fn check_object_consistency(
+    disks: &[Option<DiskStore>],
+    bucket: &str,
+    object: &str,
+    version_id: &str,
+    read_quorum: usize,
+) -> ConsistencyReport {
+    let opts = ReadOptions::default();
+    let futures = disks.iter().map(|disk| {
+        let opts = &opts;
+        async move {
+            match disk {
+                Some(disk) => disk.read_version("", bucket, object, version_id, opts).await,
+                None => Err(DiskError::DiskNotFound),
+            }
+        }
+    });
+
+    let results = join_all(futures).await;
+
+    evaluate_consistency(&results, read_quorum)
+}
+
+/// Compares per-disk `read_version` results and derives a verdict.
+///
+/// Disks that are offline (see `OBJECT_OP_IGNORED_ERRS`) are treated as absent. Any other
+/// error, such as a missing file, counts as a disagreement.
+pub fn evaluate_consistency(results: &[Result<FileInfo>], read_quorum: usize) -> ConsistencyReport {
+    let mut offline = Vec::new();
+    let mut groups: Vec<(VersionSignature, Vec<usize>)> = Vec::new();
+
+    for (idx, result) in results.iter().enumerate() {
+        match result {
+            Ok(fi) => {
+                let sig = VersionSignature::from(fi);
+                match groups.iter_mut().find(|(s, _)| *s == sig) {
+                    Some((_, members)) => members.push(idx),
+                    None => groups.push((sig, vec![idx])),
+                }
+            }
+            Err(err) if is_ignored_err(OBJECT_OP_IGNORED_ERRS, err) => offline.push(idx),
+            Err(_) => {}
+        }
+    }
+
+    let majority = groups
+        .iter()
+        .max_by_key(|(_, members)| members.len())
+        .map(|(_, members)| members.clone())
+        .unwrap_or_default();
+    let agreeing = majority.len();
+
+    let outliers: Vec<usize> = (0..results.len())
+        .filter(|idx| !majority.contains(idx) && !offline.contains(idx))
+        .collect();
+
+    let verdict = if agreeing == 0 || agreeing < read_quorum {
+        ConsistencyVerdict::QuorumLost
+    } else if outliers.is_empty() {
+        ConsistencyVerdict::Consistent
+    } else {
+        ConsistencyVerdict::NeedsHeal
+    };
+
+    ConsistencyReport {
+        verdict,
+        agreeing,
+        outliers,
+        offline,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_filemeta::ObjectPartInfo;
+
+    fn file_info(version_id: Uuid, mod_time: OffsetDateTime, etag: &str) -> FileInfo {
+        FileInfo {
+            volume: "bucket".to_string(),
+            name: "object".to_string(),
+            version_id: Some(version_id),
+            mod_time: Some(mod_time),
+            size: 1024,
+            num_versions: 1,
+            parts: vec![ObjectPartInfo {
+                etag: etag.to_string(),
+                number: 1,
+                size: 1024,
+                actual_size: 1024,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_all_disks_agree() {
+        let vid = Uuid::new_v4();
+        let now = OffsetDateTime::now_utc();
+        let results: Vec<Result<FileInfo>> = (0..4).map(|_| Ok(file_info(vid, now, "etag"))).collect();
+
+        let report = evaluate_consistency(&results, 3);
+        assert_eq!(report.verdict, ConsistencyVerdict::Consistent);
+        assert_eq!(report.agreeing, 4);
+        assert!(report.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_single_outlier_needs_heal() {
+        let vid = Uuid::new_v4();
+        let now = OffsetDateTime::now_utc();
+        let mut results: Vec<Result<FileInfo>> = (0..4).map(|_| Ok(file_info(vid, now, "etag"))).collect();
+        results[2] = Ok(file_info(vid, now, "stale-etag"));
+
+        let report = evaluate_consistency(&results, 3);
+        assert_eq!(report.verdict, ConsistencyVerdict::NeedsHeal);
+        assert_eq!(report.agreeing, 3);
+        assert_eq!(report.outliers, vec![2]);
+    }
+
+    #[test]
+    fn test_missing_file_is_outlier() {
+        let vid = Uuid::new_v4();
+        let now = OffsetDateTime::now_utc();
+        let mut results: Vec<Result<FileInfo>> = (0..4).map(|_| Ok(file_info(vid, now, "etag"))).collect();
+        results[0] = Err(DiskError::FileNotFound);
+
+        let report = evaluate_consistency(&results, 3);
+        assert_eq!(report.verdict, ConsistencyVerdict::NeedsHeal);
+        assert_eq!(report.outliers, vec![0]);
+    }
+
+    #[test]
+    fn test_offline_disk_is_not_a_disagreement() {
+        let vid = Uuid::new_v4();
+        let now = OffsetDateTime::now_utc();
+        let mut results: Vec<Result<FileInfo>> = (0..4).map(|_| Ok(file_info(vid, now, "etag"))).collect();
+        results[1] = Err(DiskError::DiskNotFound);
+
+        let report = evaluate_consistency(&results, 3);
+        assert_eq!(report.verdict, ConsistencyVerdict::Consistent);
+        assert_eq!(report.offline, vec![1]);
+        assert!(report.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_split_loses_quorum() {
+        let now = OffsetDateTime::now_utc();
+        let (v1, v2) = (Uuid::new_v4(), Uuid::new_v4());
+        let results: Vec<Result<FileInfo>> = vec![
+            Ok(file_info(v1, now, "etag")),
+            Ok(file_info(v1, now, "etag")),
+            Ok(file_info(v2, now, "etag")),
+            Ok(file_info(v2, now, "etag")),
+        ];
+
+        let report = evaluate_consistency(&results, 3);
+        assert_eq!(report.verdict, ConsistencyVerdict::QuorumLost);
+        assert_eq!(report.agreeing, 2);
+        assert_eq!(report.outliers.len(), 2);
+    }
+
+    #[test]
+    fn test_mod_time_mismatch_detected() {
+        let vid = Uuid::new_v4();
+        let now = OffsetDateTime::now_utc();
+        let mut results: Vec<Result<FileInfo>> = (0..3).map(|_| Ok(file_info(vid, now, "etag"))).collect();
+        results[0] = Ok(file_info(vid, now - time::Duration::seconds(5), "etag"));
+
+        let report = evaluate_consistency(&results, 2);
+        assert_eq!(report.verdict, ConsistencyVerdict::NeedsHeal);
+        assert_eq!(report.outliers, vec![0]);
+    }
+}
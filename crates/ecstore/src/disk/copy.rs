@@ -0,0 +1,157 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::disk::{DiskAPI, DiskError, ReadOptions, Result};
+use crate::erasure_coding::bitrot_shard_file_size;
+use rustfs_filemeta::FileMeta;
+use rustfs_utils::path::path_join_buf;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Copies the latest version of the object at `volume/path` from `src` to `dst`.
+///
+/// Part files are streamed from `read_file_stream` into `create_file` without buffering them
+/// whole, then the version metadata read with `read_xl` is written with `write_metadata`, so the
+/// object only becomes visible on `dst` once its data is in place. Returns `ShortWrite` when the
+/// source delivers less data than the metadata promises.
+pub async fn copy_object<S, D>(src: &S, dst: &D, volume: &str, path: &str) -> Result<()>
+where
+    S: DiskAPI + ?Sized,
+    D: DiskAPI + ?Sized,
+{
+    let raw = src.read_xl(volume, path, true).await?;
+    let fi = FileMeta::load(&raw.buf)?.into_fileinfo(volume, path, "", true, false, true)?;
+
+    if let Some(data_dir) = fi.data_dir
+        && !fi.inline_data()
+        && !fi.is_remote()
+    {
+        let data_dir = data_dir.to_string();
+        for part in fi.parts.iter() {
+            let part_path = path_join_buf(&[path, &data_dir, &format!("part.{}", part.number)]);
+            let size = bitrot_shard_file_size(
+                fi.erasure.shard_file_size(part.size as i64) as usize,
+                fi.erasure.shard_size(),
+                fi.erasure.get_checksum_info(part.number).algorithm,
+            );
+
+            copy_file(src, dst, volume, &part_path, size).await?;
+        }
+    }
+
+    dst.write_metadata("", volume, path, fi).await
+}
+
+/// Streams the `size` byte file at `volume/path` from `src` to `dst`.
+pub async fn copy_file<S, D>(src: &S, dst: &D, volume: &str, path: &str, size: usize) -> Result<()>
+where
+    S: DiskAPI + ?Sized,
+    D: DiskAPI + ?Sized,
+{
+    let reader = src.read_file_stream(volume, path, 0, size, &ReadOptions::default()).await?;
+    let mut writer = dst.create_file("", volume, path, size as i64).await?;
+
+    copy_stream(reader, &mut writer, size).await?;
+    writer.shutdown().await?;
+
+    Ok(())
+}
+
+/// Copies exactly `size` bytes, failing with `ShortWrite` when `reader` ends early.
+async fn copy_stream<R, W>(reader: R, writer: &mut W, size: usize) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let copied = tokio::io::copy(&mut reader.take(size as u64), writer).await?;
+    if copied < size as u64 {
+        return Err(DiskError::ShortWrite);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::STORAGE_FORMAT_FILE;
+    use crate::disk::endpoint::Endpoint;
+    use crate::disk::local::LocalDisk;
+    use bytes::Bytes;
+    use rustfs_filemeta::{ChecksumInfo, FileInfo};
+    use rustfs_utils::HashAlgorithm;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    async fn new_disk(dir: &TempDir) -> LocalDisk {
+        let endpoint = Endpoint::try_from(dir.path().to_str().unwrap()).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("bucket").await.unwrap();
+        disk
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_between_local_disks() {
+        let (src_dir, dst_dir) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+        let (src, dst) = (new_disk(&src_dir).await, new_disk(&dst_dir).await);
+
+        let mut fi = FileInfo::new("object", 2, 2);
+        fi.volume = "bucket".to_string();
+        fi.name = "object".to_string();
+        fi.version_id = Some(Uuid::new_v4());
+        fi.data_dir = Some(Uuid::new_v4());
+        fi.mod_time = Some(OffsetDateTime::now_utc());
+        fi.size = 3 << 20;
+        fi.add_object_part(1, "etag".to_string(), fi.size as usize, fi.mod_time, fi.size, None, None);
+        fi.erasure.checksums.push(ChecksumInfo {
+            part_number: 1,
+            algorithm: HashAlgorithm::HighwayHash256S,
+            ..Default::default()
+        });
+
+        let size = bitrot_shard_file_size(
+            fi.erasure.shard_file_size(fi.size) as usize,
+            fi.erasure.shard_size(),
+            HashAlgorithm::HighwayHash256S,
+        );
+        let data: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+        let part_path = format!("object/{}/part.1", fi.data_dir.unwrap());
+        src.write_all("bucket", &part_path, Bytes::from(data.clone())).await.unwrap();
+        src.write_metadata("", "bucket", "object", fi.clone()).await.unwrap();
+
+        copy_object(&src, &dst, "bucket", "object").await.unwrap();
+
+        assert_eq!(dst.read_all("bucket", &part_path).await.unwrap(), Bytes::from(data));
+        let copied = dst
+            .read_version("", "bucket", "object", "", &ReadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(copied.version_id, fi.version_id);
+        assert_eq!(copied.data_dir, fi.data_dir);
+        assert_eq!(copied.size, fi.size);
+        assert!(dst.read_all("bucket", &format!("object/{STORAGE_FORMAT_FILE}")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_copy_stream_short_write() {
+        let mut out = Vec::new();
+        let err = copy_stream(Cursor::new(vec![1u8; 10]), &mut out, 16).await.unwrap_err();
+        assert_eq!(err, DiskError::ShortWrite);
+
+        let mut out = Vec::new();
+        copy_stream(Cursor::new(vec![1u8; 20]), &mut out, 16).await.unwrap();
+        assert_eq!(out.len(), 16);
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rustfs_utils::os::O_DIRECT;
+use std::fmt::Debug;
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{self, Write};
+use std::os::unix::fs::{FileExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::AsyncWrite;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// Offset, length and memory alignment required for `O_DIRECT` writes.
+pub const DIRECT_IO_ALIGNMENT: usize = 4096;
+
+/// Amount of data collected before it is written out, a multiple of `DIRECT_IO_ALIGNMENT`.
+const DIRECT_IO_BUFFER_SIZE: usize = 1 << 20;
+
+/// Reports whether files under `dir` can be opened with `O_DIRECT`.
+pub fn supports_direct_io(dir: &Path) -> bool {
+    let probe = dir.join(format!(".direct-io-probe-{}", Uuid::new_v4()));
+    let res = OpenOptions::new()
+        .create_new(true)
+        .write(true)
+        .custom_flags(O_DIRECT)
+        .open(&probe);
+    let _ = std::fs::remove_file(&probe);
+
+    res.is_ok()
+}
+
+/// Buffer whose contents start at an address aligned to `DIRECT_IO_ALIGNMENT`.
+struct AlignedBuf {
+    raw: Vec<u8>,
+    start: usize,
+    len: usize,
+}
+
+impl AlignedBuf {
+    fn new() -> Self {
+        let raw = vec![0u8; DIRECT_IO_BUFFER_SIZE + DIRECT_IO_ALIGNMENT];
+        let start = raw.as_ptr().align_offset(DIRECT_IO_ALIGNMENT);
+        Self { raw, start, len: 0 }
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == DIRECT_IO_BUFFER_SIZE
+    }
+
+    fn extend(&mut self, buf: &[u8]) -> usize {
+        let n = buf.len().min(DIRECT_IO_BUFFER_SIZE - self.len);
+        let at = self.start + self.len;
+        self.raw[at..at + n].copy_from_slice(&buf[..n]);
+        self.len += n;
+        n
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.raw[self.start..self.start + self.len]
+    }
+}
+
+struct Inner {
+    file: File,
+    path: PathBuf,
+    buf: AlignedBuf,
+    offset: u64,
+}
+
+impl Inner {
+    /// Writes a full buffer, which is always a whole number of aligned blocks.
+    fn write_buf(&mut self) -> io::Result<()> {
+        self.file.write_all(self.buf.as_slice())?;
+        self.offset += self.buf.len as u64;
+        self.buf.len = 0;
+        Ok(())
+    }
+
+    /// Writes the aligned part of the buffer directly and the unaligned tail through the page cache.
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        let data = self.buf.as_slice();
+        let aligned = data.len() - data.len() % DIRECT_IO_ALIGNMENT;
+        self.file.write_all(&data[..aligned])?;
+
+        let tail = &data[aligned..];
+        if !tail.is_empty() {
+            let file = OpenOptions::new().write(true).open(&self.path)?;
+            file.write_all_at(tail, self.offset + aligned as u64)?;
+        }
+
+        Ok(())
+    }
+}
+
+enum State {
+    Idle(Box<Inner>),
+    Writing(JoinHandle<(Box<Inner>, io::Result<()>)>),
+    Finishing(JoinHandle<io::Result<()>>),
+    Done,
+}
+
+/// Writer that bypasses the page cache with `O_DIRECT`.
+///
+/// Data is collected in an aligned buffer and written out in full blocks on a blocking
+/// thread. The final unaligned tail is written without `O_DIRECT` on shutdown, so the
+/// writer must be shut down for the file to be complete.
+pub struct DirectFileWriter {
+    path: PathBuf,
+    state: State,
+}
+
+impl DirectFileWriter {
+    /// Creates or truncates the file at `path` and opens it with `O_DIRECT`.
+    pub async fn create(path: &Path) -> io::Result<Self> {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .custom_flags(O_DIRECT)
+            .open(path)
+            .await?
+            .into_std()
+            .await;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            state: State::Idle(Box::new(Inner {
+                file,
+                path: path.to_path_buf(),
+                buf: AlignedBuf::new(),
+                offset: 0,
+            })),
+        })
+    }
+
+    fn take_idle(&mut self) -> Box<Inner> {
+        match std::mem::replace(&mut self.state, State::Done) {
+            State::Idle(inner) => inner,
+            _ => unreachable!("writer is not idle"),
+        }
+    }
+
+    /// Waits for a pending block write to complete.
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let State::Writing(handle) = &mut self.state {
+            match ready!(Pin::new(handle).poll(cx)) {
+                Ok((inner, res)) => {
+                    self.state = State::Idle(inner);
+                    res?;
+                }
+                Err(err) => {
+                    self.state = State::Done;
+                    return Poll::Ready(Err(io::Error::other(err)));
+                }
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Debug for DirectFileWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DirectFileWriter").field("path", &self.path).finish()
+    }
+}
+
+impl AsyncWrite for DirectFileWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        loop {
+            ready!(this.poll_pending(cx))?;
+
+            match &mut this.state {
+                State::Idle(inner) if !inner.buf.is_full() => return Poll::Ready(Ok(inner.buf.extend(buf))),
+                State::Idle(_) => {
+                    let mut inner = this.take_idle();
+                    this.state = State::Writing(tokio::task::spawn_blocking(move || {
+                        let res = inner.write_buf();
+                        (inner, res)
+                    }));
+                }
+                _ => return Poll::Ready(Err(io::Error::other("write after shutdown"))),
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Partial blocks can't be written with O_DIRECT, they stay buffered until shutdown
+        self.get_mut().poll_pending(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            ready!(this.poll_pending(cx))?;
+
+            match &mut this.state {
+                State::Idle(_) => {
+                    let inner = this.take_idle();
+                    this.state = State::Finishing(tokio::task::spawn_blocking(move || inner.finish()));
+                }
+                State::Finishing(handle) => {
+                    let res = ready!(Pin::new(handle).poll(cx));
+                    this.state = State::Done;
+                    return Poll::Ready(res.map_err(io::Error::other)?);
+                }
+                State::Writing(_) => unreachable!("pending write already polled"),
+                State::Done => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::io::AsyncWriteExt;
+
+    #[tokio::test]
+    async fn test_direct_write_matches_buffered() {
+        let dir = TempDir::new().unwrap();
+        if !supports_direct_io(dir.path()) {
+            return;
+        }
+
+        let data: Vec<u8> = (0..3 * DIRECT_IO_BUFFER_SIZE + 12345).map(|i| (i % 251) as u8).collect();
+
+        let direct_path = dir.path().join("direct");
+        let mut w = DirectFileWriter::create(&direct_path).await.unwrap();
+        for chunk in data.chunks(100_000) {
+            w.write_all(chunk).await.unwrap();
+        }
+        w.shutdown().await.unwrap();
+
+        let buffered_path = dir.path().join("buffered");
+        let mut f = tokio::fs::File::create(&buffered_path).await.unwrap();
+        f.write_all(&data).await.unwrap();
+        f.shutdown().await.unwrap();
+
+        let direct = tokio::fs::read(&direct_path).await.unwrap();
+        assert_eq!(direct.len(), data.len());
+        assert_eq!(direct, tokio::fs::read(&buffered_path).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_direct_write_aligned_and_empty() {
+        let dir = TempDir::new().unwrap();
+        if !supports_direct_io(dir.path()) {
+            return;
+        }
+
+        for size in [0, DIRECT_IO_ALIGNMENT, DIRECT_IO_BUFFER_SIZE] {
+            let path = dir.path().join(format!("file-{size}"));
+            let data = vec![7u8; size];
+
+            let mut w = DirectFileWriter::create(&path).await.unwrap();
+            w.write_all(&data).await.unwrap();
+            w.shutdown().await.unwrap();
+
+            assert_eq!(tokio::fs::read(&path).await.unwrap(), data);
+        }
+    }
+}
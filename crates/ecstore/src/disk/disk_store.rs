@@ -14,11 +14,15 @@
 
 use crate::disk::{
     CheckPartsResp, DeleteOptions, DiskAPI, DiskError, DiskInfo, DiskInfoOptions, DiskLocation, Endpoint, Error,
-    FileInfoVersions, ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp, Result, UpdateMetadataOpts, VolumeInfo,
-    WalkDirOptions, local::LocalDisk,
+    FORMAT_CONFIG_FILE, FileInfoVersions, RUSTFS_META_BUCKET, ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp,
+    Result, UpdateMetadataOpts, VolumeInfo, WalkDirOptions,
+    local::LocalDisk,
+    metered::{DiskMetricsSnapshot, DiskOp, DiskOpRecorder},
 };
+use crate::rpc::Codec;
 use bytes::Bytes;
 use rustfs_filemeta::{FileInfo, ObjectPartInfo, RawFileInfo};
+use rustfs_utils::HashAlgorithm;
 use rustfs_utils::string::parse_bool_with_default;
 use std::{
     path::PathBuf,
@@ -39,9 +43,17 @@ const DISK_HEALTH_FAULTY: u32 = 1;
 
 pub const ENV_RUSTFS_DRIVE_ACTIVE_MONITORING: &str = "RUSTFS_DRIVE_ACTIVE_MONITORING";
 pub const ENV_RUSTFS_DRIVE_MAX_TIMEOUT_DURATION: &str = "RUSTFS_DRIVE_MAX_TIMEOUT_DURATION";
+pub const ENV_RUSTFS_DRIVE_ONLINE_CACHE_TTL_MS: &str = "RUSTFS_DRIVE_ONLINE_CACHE_TTL_MS";
+pub const ENV_RUSTFS_DRIVE_DIRECT_IO: &str = "RUSTFS_DRIVE_DIRECT_IO";
+pub const ENV_RUSTFS_DRIVE_MAX_OPEN_FILES: &str = "RUSTFS_DRIVE_MAX_OPEN_FILES";
+pub const ENV_RUSTFS_DRIVE_HTTP_CHUNK_SIZE: &str = "RUSTFS_DRIVE_HTTP_CHUNK_SIZE";
+pub const ENV_RUSTFS_DRIVE_COMPRESSION: &str = "RUSTFS_DRIVE_COMPRESSION";
+pub const ENV_RUSTFS_DRIVE_WRITE_CHECKSUM: &str = "RUSTFS_DRIVE_WRITE_CHECKSUM";
 pub const CHECK_EVERY: Duration = Duration::from_secs(15);
 pub const SKIP_IF_SUCCESS_BEFORE: Duration = Duration::from_secs(5);
 pub const CHECK_TIMEOUT_DURATION: Duration = Duration::from_secs(5);
+/// Consecutive failed background probes after which a disk is marked faulty.
+pub const FAULTY_AFTER_FAILED_PROBES: u32 = 3;
 
 lazy_static::lazy_static! {
     static ref TEST_DATA: Bytes = Bytes::from(vec![42u8; 2048]);
@@ -54,6 +66,60 @@ pub fn get_max_timeout_duration() -> Duration {
         .unwrap_or(Duration::from_secs(30))
 }
 
+/// How often a remote disk's health monitor refreshes its online state, in milliseconds. Defaults to one second.
+pub fn get_online_cache_ttl() -> Duration {
+    std::env::var(ENV_RUSTFS_DRIVE_ONLINE_CACHE_TTL_MS)
+        .map(|v| Duration::from_millis(v.parse::<u64>().unwrap_or(1000)))
+        .unwrap_or(Duration::from_secs(1))
+}
+
+/// Whether large files are written to local drives with `O_DIRECT`. Off by default.
+pub fn get_drive_direct_io() -> bool {
+    std::env::var(ENV_RUSTFS_DRIVE_DIRECT_IO)
+        .map(|v| v.parse::<bool>().unwrap_or(false))
+        .unwrap_or(false)
+}
+
+/// Maximum files each local drive keeps open at once. 0, the default, means no limit.
+pub fn get_drive_max_open_files() -> usize {
+    std::env::var(ENV_RUSTFS_DRIVE_MAX_OPEN_FILES)
+        .map(|v| v.parse::<usize>().unwrap_or(0))
+        .unwrap_or(0)
+}
+
+/// Buffer size in bytes for remote drive HTTP streams. 0, the default, streams unbuffered.
+pub fn get_drive_http_chunk_size() -> usize {
+    std::env::var(ENV_RUSTFS_DRIVE_HTTP_CHUNK_SIZE)
+        .map(|v| v.parse::<usize>().unwrap_or(0))
+        .unwrap_or(0)
+}
+
+/// Codec (`gzip` or `zstd`) for file streams to remote drives. Unset or unknown values disable compression.
+pub fn get_drive_compression() -> Option<Codec> {
+    std::env::var(ENV_RUSTFS_DRIVE_COMPRESSION).ok()?.parse().ok()
+}
+
+/// Algorithm local drives checksum written files with (`sha256`, `highwayhash256`, `highwayhash256s`,
+/// `blake2b512`, `blake3`, `md5`, `crc32c` or `xxh3`). Unset or unknown values disable it.
+pub fn get_drive_write_checksum() -> Option<HashAlgorithm> {
+    match std::env::var(ENV_RUSTFS_DRIVE_WRITE_CHECKSUM)
+        .ok()?
+        .trim()
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "sha256" => Some(HashAlgorithm::SHA256),
+        "highwayhash256" => Some(HashAlgorithm::HighwayHash256),
+        "highwayhash256s" => Some(HashAlgorithm::HighwayHash256S),
+        "blake2b512" => Some(HashAlgorithm::BLAKE2b512),
+        "blake3" => Some(HashAlgorithm::Blake3),
+        "md5" => Some(HashAlgorithm::Md5),
+        "crc32c" => Some(HashAlgorithm::Crc32c),
+        "xxh3" => Some(HashAlgorithm::Xxh3),
+        _ => None,
+    }
+}
+
 /// DiskHealthTracker tracks the health status of a disk.
 /// Similar to Go's diskHealthTracker.
 #[derive(Debug)]
@@ -66,6 +132,8 @@ pub struct DiskHealthTracker {
     pub status: AtomicU32,
     /// Atomic number of waiting operations
     pub waiting: AtomicU32,
+    /// Consecutive failed background probes
+    pub failed_probes: AtomicU32,
 }
 
 impl DiskHealthTracker {
@@ -81,6 +149,7 @@ impl DiskHealthTracker {
             last_started: AtomicI64::new(now),
             status: AtomicU32::new(DISK_HEALTH_OK),
             waiting: AtomicU32::new(0),
+            failed_probes: AtomicU32::new(0),
         }
     }
 
@@ -105,9 +174,21 @@ impl DiskHealthTracker {
 
     /// Set disk as OK
     pub fn set_ok(&self) {
+        self.failed_probes.store(0, Ordering::Relaxed);
         self.status.store(DISK_HEALTH_OK, Ordering::Release);
     }
 
+    /// Record a successful background probe, resetting the failure streak
+    pub fn record_probe_success(&self) {
+        self.failed_probes.store(0, Ordering::Relaxed);
+    }
+
+    /// Record a failed background probe. Returns true if this failure marked the disk faulty.
+    pub fn record_probe_failure(&self) -> bool {
+        let failed = self.failed_probes.fetch_add(1, Ordering::Relaxed) + 1;
+        failed >= FAULTY_AFTER_FAILED_PROBES && self.swap_ok_to_faulty()
+    }
+
     pub fn swap_ok_to_faulty(&self) -> bool {
         self.status
             .compare_exchange(DISK_HEALTH_OK, DISK_HEALTH_FAULTY, Ordering::AcqRel, Ordering::Relaxed)
@@ -174,6 +255,8 @@ pub struct LocalDiskWrapper {
     cancel_token: CancellationToken,
     /// Disk ID for stale checking
     disk_id: Arc<RwLock<Option<Uuid>>>,
+    /// Per operation counters reported by `disk_info`
+    ops: Arc<DiskOpRecorder>,
 }
 
 impl LocalDiskWrapper {
@@ -191,6 +274,7 @@ impl LocalDiskWrapper {
             health_check: health_check && env_health_check,
             cancel_token: CancellationToken::new(),
             disk_id: Arc::new(RwLock::new(None)),
+            ops: Arc::new(DiskOpRecorder::default()),
         };
 
         ret.start_monitoring();
@@ -202,6 +286,16 @@ impl LocalDiskWrapper {
         self.disk.clone()
     }
 
+    /// See `LocalDisk::make_volumes_each`.
+    pub async fn make_volumes_each(&self, volumes: Vec<&str>) -> Result<Vec<Result<()>>> {
+        self.track_disk_health(
+            DiskOp::MakeVolumes,
+            || async { self.disk.make_volumes_each(volumes).await },
+            get_max_timeout_duration(),
+        )
+        .await
+    }
+
     /// Start the disk monitoring if health_check is enabled
     pub fn start_monitoring(&self) {
         if self.health_check {
@@ -256,8 +350,7 @@ impl LocalDiskWrapper {
 
 
 
-                    let test_obj = format!("health-check-{}", Uuid::new_v4());
-                    if Self::perform_health_check(disk.clone(), &TEST_BUCKET, &test_obj, &TEST_DATA, true, CHECK_TIMEOUT_DURATION).await.is_err() && health.swap_ok_to_faulty() {
+                    if Self::probe_writable(disk.clone(), &health, CHECK_TIMEOUT_DURATION).await {
                         // Health check failed, disk is considered faulty
 
                         health.increment_waiting(); // Balance the increment from failed operation
@@ -275,7 +368,32 @@ impl LocalDiskWrapper {
         }
     }
 
-    /// Perform a health check by writing and reading a test file
+    /// Runs one writability probe and returns true if its failure marked the disk faulty.
+    async fn probe_writable(disk: Arc<LocalDisk>, health: &DiskHealthTracker, timeout_duration: Duration) -> bool {
+        let test_obj = format!("health-check-{}", Uuid::new_v4());
+        let result = Self::perform_health_check(disk, &TEST_BUCKET, &test_obj, &TEST_DATA, true, timeout_duration).await;
+        Self::record_probe(health, result)
+    }
+
+    /// Records the outcome of one probe and returns true if its failure marked the disk faulty.
+    fn record_probe(health: &DiskHealthTracker, result: Result<()>) -> bool {
+        match result {
+            Ok(()) => {
+                health.record_probe_success();
+                false
+            }
+            Err(_) => health.record_probe_failure(),
+        }
+    }
+
+    /// Whether background probes have marked the disk faulty
+    pub fn is_faulty(&self) -> bool {
+        self.health.is_faulty()
+    }
+
+    /// Perform a health check by writing and reading a test file.
+    ///
+    /// Read-only disks reject the write, so they are probed by reading `format.json` instead.
     async fn perform_health_check(
         disk: Arc<LocalDisk>,
         test_bucket: &str,
@@ -284,8 +402,15 @@ impl LocalDiskWrapper {
         check_faulty_only: bool,
         timeout_duration: Duration,
     ) -> Result<()> {
-        // Perform health check with timeout
-        let health_check_result = tokio::time::timeout(timeout_duration, async {
+        let check = async {
+            if disk.read_only {
+                // A drive that answers, even without a format, is healthy
+                return match disk.read_all(RUSTFS_META_BUCKET, FORMAT_CONFIG_FILE).await {
+                    Ok(_) | Err(DiskError::FileNotFound) | Err(DiskError::VolumeNotFound) => Ok(()),
+                    Err(e) => Err(e),
+                };
+            }
+
             // Try to write test data
             disk.write_all(test_bucket, test_filename, test_data.clone()).await?;
 
@@ -311,7 +436,7 @@ impl LocalDiskWrapper {
                 test_filename,
                 DeleteOptions {
                     recursive: false,
-                    immediate: false,
+                    immediate: true,
                     undo_write: false,
                     old_data_dir: None,
                 },
@@ -319,10 +444,17 @@ impl LocalDiskWrapper {
             .await?;
 
             Ok(())
-        })
-        .await;
+        };
+
+        Self::run_health_check(check, check_faulty_only, timeout_duration).await
+    }
 
-        match health_check_result {
+    /// Awaits `check` for at most `timeout_duration`. A check that does not finish in time marks the disk faulty.
+    async fn run_health_check<F>(check: F, check_faulty_only: bool, timeout_duration: Duration) -> Result<()>
+    where
+        F: std::future::Future<Output = Result<()>>,
+    {
+        match tokio::time::timeout(timeout_duration, check).await {
             Ok(result) => match result {
                 Ok(()) => Ok(()),
                 Err(e) => {
@@ -420,9 +552,23 @@ impl LocalDiskWrapper {
         *self.disk_id.read().await
     }
 
+    /// Returns a snapshot of the per operation counters of this disk.
+    pub fn metrics(&self) -> DiskMetricsSnapshot {
+        self.ops.metrics()
+    }
+
     /// Track disk health for an operation.
-    /// This method should wrap disk operations to ensure health checking.
-    pub async fn track_disk_health<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
+    /// This method should wrap disk operations to ensure health checking. The call is counted
+    /// in the metrics of `op`.
+    pub async fn track_disk_health<T, F, Fut>(&self, op: DiskOp, operation: F, timeout_duration: Duration) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        self.ops.metered(op, self.run_tracked(operation, timeout_duration)).await
+    }
+
+    async fn run_tracked<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
     where
         F: FnOnce() -> Fut,
         Fut: std::future::Future<Output = Result<T>>,
@@ -472,6 +618,43 @@ impl LocalDiskWrapper {
             }
         }
     }
+
+    async fn delete_versions_tracked(
+        &self,
+        volume: &str,
+        versions: Vec<FileInfoVersions>,
+        opts: DeleteOptions,
+    ) -> Vec<Option<Error>> {
+        // Check if disk is faulty before proceeding
+        if self.health.is_faulty() {
+            return vec![Some(DiskError::FaultyDisk); versions.len()];
+        }
+
+        // Check if disk is stale
+        if let Err(e) = self.check_disk_stale().await {
+            return vec![Some(e); versions.len()];
+        }
+
+        // Record operation start
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as i64;
+        self.health.last_started.store(now, Ordering::Relaxed);
+        self.health.increment_waiting();
+
+        // Execute the operation
+        let result = self.disk.delete_versions(volume, versions, opts).await;
+
+        self.health.decrement_waiting();
+        let has_err = result.iter().any(|e| e.is_some());
+        if !has_err {
+            // Log success and decrement waiting counter
+            self.health.log_success();
+        }
+
+        result
+    }
 }
 
 #[async_trait::async_trait]
@@ -481,6 +664,10 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn is_online(&self) -> bool {
+        if self.health.is_faulty() {
+            return false;
+        }
+
         let Ok(Some(disk_id)) = self.disk.get_disk_id().await else {
             return false;
         };
@@ -521,6 +708,14 @@ impl DiskAPI for LocalDiskWrapper {
         self.set_disk_id_internal(id).await
     }
 
+    fn set_scanning(&self, scanning: bool) {
+        self.disk.set_scanning(scanning)
+    }
+
+    fn set_healing(&self, healing: bool) {
+        self.disk.set_healing(healing)
+    }
+
     fn path(&self) -> PathBuf {
         self.disk.path()
     }
@@ -540,48 +735,71 @@ impl DiskAPI for LocalDiskWrapper {
             return Ok(info);
         }
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
+        let mut result = self
+            .ops
+            .metered(DiskOp::DiskInfo, async {
+                if self.health.is_faulty() {
+                    return Err(DiskError::FaultyDisk);
+                }
 
-        let result = self.disk.disk_info(opts).await?;
+                let result = self.disk.disk_info(opts).await?;
 
-        if let Some(current_disk_id) = *self.disk_id.read().await
-            && Some(current_disk_id) != result.id
-        {
-            return Err(DiskError::DiskNotFound);
-        };
+                if let Some(current_disk_id) = *self.disk_id.read().await
+                    && Some(current_disk_id) != result.id
+                {
+                    return Err(DiskError::DiskNotFound);
+                };
+
+                Ok(result)
+            })
+            .await?;
+
+        if opts.metrics {
+            self.ops.metrics().fill_disk_metrics(&mut result.metrics);
+        }
 
         Ok(result)
     }
 
     async fn make_volume(&self, volume: &str) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.make_volume(volume).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::MakeVolume,
+            || async { self.disk.make_volume(volume).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn make_volumes(&self, volumes: Vec<&str>) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.make_volumes(volumes).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::MakeVolumes,
+            || async { self.disk.make_volumes(volumes).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn list_volumes(&self) -> Result<Vec<VolumeInfo>> {
-        self.track_disk_health(|| async { self.disk.list_volumes().await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::ListVolumes, || async { self.disk.list_volumes().await }, Duration::ZERO)
             .await
     }
 
     async fn stat_volume(&self, volume: &str) -> Result<VolumeInfo> {
-        self.track_disk_health(|| async { self.disk.stat_volume(volume).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::StatVolume,
+            || async { self.disk.stat_volume(volume).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn delete_volume(&self, volume: &str) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.delete_volume(volume).await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::DeleteVolume, || async { self.disk.delete_volume(volume).await }, Duration::ZERO)
             .await
     }
 
     async fn walk_dir<W: tokio::io::AsyncWrite + Unpin + Send>(&self, opts: WalkDirOptions, wr: &mut W) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.walk_dir(opts, wr).await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::WalkDir, || async { self.disk.walk_dir(opts, wr).await }, Duration::ZERO)
             .await
     }
 
@@ -594,6 +812,7 @@ impl DiskAPI for LocalDiskWrapper {
         opts: DeleteOptions,
     ) -> Result<()> {
         self.track_disk_health(
+            DiskOp::DeleteVersion,
             || async { self.disk.delete_version(volume, path, fi, force_del_marker, opts).await },
             get_max_timeout_duration(),
         )
@@ -601,44 +820,23 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn delete_versions(&self, volume: &str, versions: Vec<FileInfoVersions>, opts: DeleteOptions) -> Vec<Option<Error>> {
-        // Check if disk is faulty before proceeding
-        if self.health.is_faulty() {
-            return vec![Some(DiskError::FaultyDisk); versions.len()];
-        }
-
-        // Check if disk is stale
-        if let Err(e) = self.check_disk_stale().await {
-            return vec![Some(e); versions.len()];
-        }
-
-        // Record operation start
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as i64;
-        self.health.last_started.store(now, Ordering::Relaxed);
-        self.health.increment_waiting();
-
-        // Execute the operation
-        let result = self.disk.delete_versions(volume, versions, opts).await;
-
-        self.health.decrement_waiting();
-        let has_err = result.iter().any(|e| e.is_some());
-        if !has_err {
-            // Log success and decrement waiting counter
-            self.health.log_success();
-        }
-
-        result
+        self.ops
+            .metered_delete_versions(self.delete_versions_tracked(volume, versions, opts))
+            .await
     }
 
     async fn delete_paths(&self, volume: &str, paths: &[String]) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.delete_paths(volume, paths).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::DeletePaths,
+            || async { self.disk.delete_paths(volume, paths).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn write_metadata(&self, org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
         self.track_disk_health(
+            DiskOp::WriteMetadata,
             || async { self.disk.write_metadata(org_volume, volume, path, fi).await },
             get_max_timeout_duration(),
         )
@@ -647,6 +845,7 @@ impl DiskAPI for LocalDiskWrapper {
 
     async fn update_metadata(&self, volume: &str, path: &str, fi: FileInfo, opts: &UpdateMetadataOpts) -> Result<()> {
         self.track_disk_health(
+            DiskOp::UpdateMetadata,
             || async { self.disk.update_metadata(volume, path, fi, opts).await },
             get_max_timeout_duration(),
         )
@@ -662,6 +861,7 @@ impl DiskAPI for LocalDiskWrapper {
         opts: &ReadOptions,
     ) -> Result<FileInfo> {
         self.track_disk_health(
+            DiskOp::ReadVersion,
             || async { self.disk.read_version(org_volume, volume, path, version_id, opts).await },
             get_max_timeout_duration(),
         )
@@ -669,8 +869,12 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn read_xl(&self, volume: &str, path: &str, read_data: bool) -> Result<RawFileInfo> {
-        self.track_disk_health(|| async { self.disk.read_xl(volume, path, read_data).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::ReadXl,
+            || async { self.disk.read_xl(volume, path, read_data).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn rename_data(
@@ -682,6 +886,7 @@ impl DiskAPI for LocalDiskWrapper {
         dst_path: &str,
     ) -> Result<RenameDataResp> {
         self.track_disk_health(
+            DiskOp::RenameData,
             || async { self.disk.rename_data(src_volume, src_path, fi, dst_volume, dst_path).await },
             get_max_timeout_duration(),
         )
@@ -690,6 +895,7 @@ impl DiskAPI for LocalDiskWrapper {
 
     async fn list_dir(&self, origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>> {
         self.track_disk_health(
+            DiskOp::ListDir,
             || async { self.disk.list_dir(origvolume, volume, dir_path, count).await },
             get_max_timeout_duration(),
         )
@@ -697,25 +903,38 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn read_file(&self, volume: &str, path: &str) -> Result<crate::disk::FileReader> {
-        self.track_disk_health(|| async { self.disk.read_file(volume, path).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::ReadFile,
+            || async { self.disk.read_file(volume, path).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
-    async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<crate::disk::FileReader> {
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<crate::disk::FileReader> {
         self.track_disk_health(
-            || async { self.disk.read_file_stream(volume, path, offset, length).await },
+            DiskOp::ReadFileStream,
+            || async { self.disk.read_file_stream(volume, path, offset, length, opts).await },
             get_max_timeout_duration(),
         )
         .await
     }
 
     async fn append_file(&self, volume: &str, path: &str) -> Result<crate::disk::FileWriter> {
-        self.track_disk_health(|| async { self.disk.append_file(volume, path).await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::AppendFile, || async { self.disk.append_file(volume, path).await }, Duration::ZERO)
             .await
     }
 
     async fn create_file(&self, origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<crate::disk::FileWriter> {
         self.track_disk_health(
+            DiskOp::CreateFile,
             || async { self.disk.create_file(origvolume, volume, path, file_size).await },
             Duration::ZERO,
         )
@@ -724,6 +943,7 @@ impl DiskAPI for LocalDiskWrapper {
 
     async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()> {
         self.track_disk_health(
+            DiskOp::RenameFile,
             || async { self.disk.rename_file(src_volume, src_path, dst_volume, dst_path).await },
             get_max_timeout_duration(),
         )
@@ -732,6 +952,7 @@ impl DiskAPI for LocalDiskWrapper {
 
     async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()> {
         self.track_disk_health(
+            DiskOp::RenamePart,
             || async { self.disk.rename_part(src_volume, src_path, dst_volume, dst_path, meta).await },
             get_max_timeout_duration(),
         )
@@ -739,37 +960,191 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.delete(volume, path, opt).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_health(
+            DiskOp::Delete,
+            || async { self.disk.delete(volume, path, opt).await },
+            get_max_timeout_duration(),
+        )
+        .await
+    }
+
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()> {
+        self.track_disk_health(
+            DiskOp::Truncate,
+            || async { self.disk.truncate(volume, path, size).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
-        self.track_disk_health(|| async { self.disk.verify_file(volume, path, fi).await }, Duration::ZERO)
-            .await
+        self.track_disk_health(
+            DiskOp::VerifyFile,
+            || async { self.disk.verify_file(volume, path, fi).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn check_parts(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
-        self.track_disk_health(|| async { self.disk.check_parts(volume, path, fi).await }, Duration::ZERO)
-            .await
+        self.track_disk_health(
+            DiskOp::CheckParts,
+            || async { self.disk.check_parts(volume, path, fi).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn read_parts(&self, bucket: &str, paths: &[String]) -> Result<Vec<ObjectPartInfo>> {
-        self.track_disk_health(|| async { self.disk.read_parts(bucket, paths).await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::ReadParts, || async { self.disk.read_parts(bucket, paths).await }, Duration::ZERO)
             .await
     }
 
     async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>> {
-        self.track_disk_health(|| async { self.disk.read_multiple(req).await }, Duration::ZERO)
+        self.track_disk_health(DiskOp::ReadMultiple, || async { self.disk.read_multiple(req).await }, Duration::ZERO)
             .await
     }
 
-    async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.write_all(volume, path, data).await }, get_max_timeout_duration())
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>> {
+        self.track_disk_health(DiskOp::StatPaths, || async { self.disk.stat_paths(volume, paths).await }, Duration::ZERO)
             .await
     }
 
+    async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+        self.track_disk_health(
+            DiskOp::WriteAll,
+            || async { self.disk.write_all(volume, path, data).await },
+            get_max_timeout_duration(),
+        )
+        .await
+    }
+
     async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
-        self.track_disk_health(|| async { self.disk.read_all(volume, path).await }, get_max_timeout_duration())
+        self.track_disk_health(
+            DiskOp::ReadAll,
+            || async { self.disk.read_all(volume, path).await },
+            get_max_timeout_duration(),
+        )
+        .await
+    }
+
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes> {
+        self.track_disk_health(
+            DiskOp::ReadRange,
+            || async { self.disk.read_range(volume, path, offset, length, exact).await },
+            get_max_timeout_duration(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::format::FormatV3;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_health_tracker_faulty_after_repeated_failures() {
+        let health = DiskHealthTracker::new();
+
+        assert!(!health.record_probe_failure());
+        health.record_probe_success();
+        for _ in 1..FAULTY_AFTER_FAILED_PROBES {
+            assert!(!health.record_probe_failure());
+        }
+        assert!(!health.is_faulty());
+
+        assert!(health.record_probe_failure());
+        assert!(health.is_faulty());
+
+        health.set_ok();
+        assert!(!health.is_faulty());
+        assert!(!health.record_probe_failure());
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_wrapper_offline_after_failed_probes() {
+        let test_dir = TempDir::new().unwrap();
+        let meta_dir = test_dir.path().join(RUSTFS_META_BUCKET);
+        tokio::fs::create_dir_all(&meta_dir).await.unwrap();
+
+        let mut fm = FormatV3::new(1, 1);
+        fm.erasure.this = fm.erasure.sets[0][0];
+        tokio::fs::write(meta_dir.join(FORMAT_CONFIG_FILE), fm.to_bytes().unwrap())
+            .await
+            .unwrap();
+
+        let mut endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        endpoint.set_set_index(0);
+        endpoint.set_disk_index(0);
+        let disk = Arc::new(LocalDisk::new(&endpoint, false).await.unwrap());
+        let wrapper = LocalDiskWrapper::new(disk, false);
+        assert!(wrapper.is_online().await);
+
+        // A disk that hangs never finishes its check, each probe times out
+        for _ in 0..FAULTY_AFTER_FAILED_PROBES {
+            let hung = std::future::pending::<Result<()>>();
+            let result = LocalDiskWrapper::run_health_check(hung, true, Duration::from_millis(10)).await;
+            assert_eq!(result, Err(DiskError::FaultyDisk));
+            LocalDiskWrapper::record_probe(&wrapper.health, result);
+        }
+
+        assert!(wrapper.is_faulty());
+        assert!(!wrapper.is_online().await);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_read_only_disk() {
+        let test_dir = TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = Arc::new(LocalDisk::new(&endpoint, false).await.unwrap().with_read_only(true));
+
+        // The write probe would be refused, the read probe passes
+        for _ in 0..FAULTY_AFTER_FAILED_PROBES {
+            let res = LocalDiskWrapper::perform_health_check(
+                disk.clone(),
+                &TEST_BUCKET,
+                "probe",
+                &TEST_DATA,
+                false,
+                CHECK_TIMEOUT_DURATION,
+            )
+            .await;
+            assert!(res.is_ok(), "{res:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_wrapper_metrics() {
+        let test_dir = TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let wrapper = LocalDiskWrapper::new(Arc::new(LocalDisk::new(&endpoint, false).await.unwrap()), false);
+        wrapper.make_volume("bucket").await.unwrap();
+        wrapper
+            .write_all("bucket", "object", Bytes::from_static(b"data"))
+            .await
+            .unwrap();
+        wrapper.read_all("bucket", "object").await.unwrap();
+        assert!(wrapper.read_all("bucket", "missing").await.is_err());
+
+        assert_eq!(wrapper.metrics().ops["read_all"].calls, 2);
+
+        let info = wrapper.disk_info(&DiskInfoOptions::default()).await.unwrap();
+        assert!(info.metrics.api_calls.is_empty());
+
+        let info = wrapper
+            .disk_info(&DiskInfoOptions {
+                metrics: true,
+                ..Default::default()
+            })
             .await
+            .unwrap();
+        assert_eq!(info.metrics.api_calls.get("make_volume"), Some(&1));
+        assert_eq!(info.metrics.api_calls.get("read_all"), Some(&2));
+        assert_eq!(info.metrics.api_errors.get("read_all"), Some(&1));
+        assert_eq!(info.metrics.api_calls.get("disk_info"), Some(&2));
     }
 }
@@ -14,6 +14,7 @@
 
 use super::error::{Error, Result};
 use path_absolutize::Absolutize;
+use rustfs_utils::string::has_ellipses;
 use rustfs_utils::{is_local_host, is_socket_addr};
 use std::{fmt::Display, path::Path};
 use tracing::debug;
@@ -50,6 +51,26 @@ impl Display for Endpoint {
     }
 }
 
+/// Endpoints order by pool, set and disk index, so sorting yields the canonical layout.
+/// The URL and locality break ties, keeping the order consistent with `Eq`.
+impl Ord for Endpoint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.pool_idx, self.set_idx, self.disk_idx, &self.url, self.is_local).cmp(&(
+            other.pool_idx,
+            other.set_idx,
+            other.disk_idx,
+            &other.url,
+            other.is_local,
+        ))
+    }
+}
+
+impl PartialOrd for Endpoint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl TryFrom<&str> for Endpoint {
     /// The type returned in the event of a conversion error.
     type Error = Error;
@@ -133,6 +154,33 @@ impl TryFrom<&str> for Endpoint {
 }
 
 impl Endpoint {
+    /// Parses a single endpoint from an `http(s)://host[:port]/path` URL, a `file://` URL or a
+    /// bare local path. Paths and `file://` URLs are local, URLs with a host are remote until
+    /// `update_is_local` resolves the host. Ellipses patterns must be expanded beforehand.
+    pub fn parse(s: &str) -> Result<Endpoint> {
+        if has_ellipses(&[s]) {
+            return Err(Error::other("invalid endpoint: ellipses patterns must be expanded first"));
+        }
+
+        if s.starts_with("file://") {
+            let url = Url::parse(s).map_err(|e| Error::other(format!("invalid URL endpoint format: {e}")))?;
+            let path = url
+                .to_file_path()
+                .map_err(|_| Error::other("invalid file URL endpoint: only local paths are supported"))?;
+            let path = path.to_str().ok_or_else(|| Error::other("invalid path"))?;
+            return Self::try_from(path);
+        }
+
+        if let Ok(url) = Url::parse(s)
+            && (url.scheme() == "http" || url.scheme() == "https")
+            && url.host_str().is_none_or(str::is_empty)
+        {
+            return Err(Error::other("invalid URL endpoint format: empty host name"));
+        }
+
+        Self::try_from(s)
+    }
+
     /// returns type of endpoint.
     pub fn get_type(&self) -> EndpointType {
         if self.url.scheme() == "file" {
@@ -184,6 +232,14 @@ impl Endpoint {
         }
     }
 
+    /// Like `grid_host`, but fails for an endpoint without a host instead of returning an empty string.
+    pub fn try_grid_host(&self) -> Result<String> {
+        match self.url.host_str() {
+            Some(host) if !host.is_empty() => Ok(self.grid_host()),
+            _ => Err(Error::other(format!("endpoint {} has no host", self.url))),
+        }
+    }
+
     pub fn host_port(&self) -> String {
         match (self.url.host(), self.url.port()) {
             (Some(host), Some(port)) => {
@@ -582,4 +638,112 @@ mod test {
 
         assert_eq!(set.len(), 2);
     }
+
+    #[test]
+    fn test_endpoint_ordering() {
+        let mut expected = Vec::new();
+        for pool in 0..2 {
+            for set in 0..2 {
+                for disk in 0..3 {
+                    let mut ep = Endpoint::try_from(format!("http://node{disk}:9000/data{set}").as_str()).unwrap();
+                    ep.set_pool_index(pool);
+                    ep.set_set_index(set);
+                    ep.set_disk_index(disk);
+                    expected.push(ep);
+                }
+            }
+        }
+
+        let mut shuffled = expected.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 7);
+        shuffled.swap(3, 10);
+        assert_ne!(shuffled, expected);
+        shuffled.sort();
+        assert_eq!(shuffled, expected);
+
+        // The URL breaks ties between endpoints with the same indices
+        let a = Endpoint::try_from("http://a:9000/data").unwrap();
+        let b = Endpoint::try_from("http://b:9000/data").unwrap();
+        assert!(a < b);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_endpoint_grid_host_host_port_round_trip() {
+        for url in [
+            "http://example.com:9000/data",
+            "https://10.0.0.1:9001/export",
+            "http://example.com/data",
+        ] {
+            let ep = Endpoint::try_from(url).unwrap();
+
+            let from_grid = Endpoint::try_from(format!("{}/data", ep.grid_host()).as_str()).unwrap();
+            assert_eq!(from_grid.host_port(), ep.host_port());
+            assert_eq!(from_grid.grid_host(), ep.grid_host());
+
+            let from_host_port = Endpoint::try_from(format!("{}://{}/data", ep.url.scheme(), ep.host_port()).as_str()).unwrap();
+            assert_eq!(from_host_port.grid_host(), ep.grid_host());
+        }
+    }
+
+    #[test]
+    fn test_endpoint_try_grid_host() {
+        let ep = Endpoint::try_from("http://example.com:9000/data").unwrap();
+        assert_eq!(ep.try_grid_host().unwrap(), "http://example.com:9000");
+
+        // A URL that lost its host, e.g. from a hand-written config, is an error rather than a panic
+        let mut hostless = ep.clone();
+        hostless.url = Url::parse("unix:/run/rustfs.sock").unwrap();
+        assert_eq!(hostless.grid_host(), "");
+        assert_eq!(hostless.host_port(), "");
+        assert!(hostless.try_grid_host().is_err());
+
+        let local = Endpoint::try_from("/tmp/data").unwrap();
+        assert!(local.try_grid_host().is_err());
+    }
+
+    #[test]
+    fn test_endpoint_parse() {
+        let local = Endpoint::parse("/data/disk1").unwrap();
+        assert!(local.is_local);
+        assert_eq!(local.get_type(), EndpointType::Path);
+        assert_eq!(local.get_file_path(), "/data/disk1");
+
+        let remote = Endpoint::parse("http://host:9000/data").unwrap();
+        assert!(!remote.is_local);
+        assert_eq!(remote.get_type(), EndpointType::Url);
+        assert_eq!(remote.host_port(), "host:9000");
+        assert_eq!(remote.url.path(), "/data");
+
+        let remote = Endpoint::parse("https://host/export/disk1").unwrap();
+        assert_eq!(remote.grid_host(), "https://host");
+        assert_eq!((remote.pool_idx, remote.set_idx, remote.disk_idx), (-1, -1, -1));
+
+        #[cfg(unix)]
+        {
+            let file = Endpoint::parse("file:///data/disk1").unwrap();
+            assert!(file.is_local);
+            assert_eq!(file, local);
+        }
+    }
+
+    #[test]
+    fn test_endpoint_parse_invalid() {
+        for s in [
+            "",
+            "/",
+            "http://:9000/data",
+            "http://host:9000/",
+            "ftp://host/data",
+            "http://host:99999/data",
+            "https://host/export/disk{1...4}",
+        ] {
+            assert!(Endpoint::parse(s).is_err(), "{s}");
+        }
+
+        // File URLs naming another host don't point at a local drive
+        #[cfg(unix)]
+        assert!(Endpoint::parse("file://otherhost/data").is_err());
+    }
 }
@@ -148,9 +148,51 @@ pub enum DiskError {
 
     #[error("invalid path")]
     InvalidPath,
+
+    #[error("drive is closed")]
+    DiskClosed,
 }
 
 impl DiskError {
+    /// Rebuilds an error reported by a peer as its `to_u32` code and message.
+    ///
+    /// The code wins when it names a specific variant. Io errors, and peers that only sent a
+    /// message, are matched with `from_error_info`; anything unknown stays an io error carrying
+    /// the message.
+    pub fn from_remote(code: u32, msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        if let Some(err) = DiskError::from_u32(code)
+            && !matches!(err, DiskError::Io(_))
+        {
+            return err;
+        }
+
+        DiskError::from_error_info(&msg).unwrap_or_else(|| DiskError::other(msg))
+    }
+
+    /// Recovers well known errors that a peer reported only as an error message: the message
+    /// of any variant, a raw `EXDEV` io error or a quorum error raised above the disk layer.
+    pub fn from_error_info(info: &str) -> Option<DiskError> {
+        // Codes are contiguous from 0x01, so walking them until `from_u32` gives up covers every variant
+        if let Some(err) = (0x01..)
+            .map_while(DiskError::from_u32)
+            .find(|err| !matches!(err, DiskError::Io(_)) && err.to_string() == info)
+        {
+            return Some(err);
+        }
+
+        let info = info.to_lowercase();
+        if info.contains("cross-device link") || info.contains("rename across devices") {
+            Some(DiskError::CrossDeviceLink)
+        } else if info.contains("erasure write quorum") {
+            Some(DiskError::ErasureWriteQuorum)
+        } else if info.contains("erasure read quorum") {
+            Some(DiskError::ErasureReadQuorum)
+        } else {
+            None
+        }
+    }
+
     pub fn other<E>(error: E) -> Self
     where
         E: Into<Box<dyn std::error::Error + Send + Sync>>,
@@ -235,6 +277,7 @@ impl From<rustfs_filemeta::Error> for DiskError {
             rustfs_filemeta::Error::FileVersionNotFound => DiskError::FileVersionNotFound,
             rustfs_filemeta::Error::FileCorrupt => DiskError::FileCorrupt,
             rustfs_filemeta::Error::MethodNotAllowed => DiskError::MethodNotAllowed,
+            rustfs_filemeta::Error::OutdatedXLMeta => DiskError::OutdatedXLMeta,
             e => DiskError::other(e),
         }
     }
@@ -257,21 +300,19 @@ impl From<DiskError> for std::io::Error {
 
 impl From<tonic::Status> for DiskError {
     fn from(e: tonic::Status) -> Self {
-        DiskError::other(e.message().to_string())
+        // Keep apart the statuses of a peer that could not be reached, callers may retry those
+        let kind = match e.code() {
+            tonic::Code::Unavailable => std::io::ErrorKind::NotConnected,
+            tonic::Code::DeadlineExceeded => std::io::ErrorKind::TimedOut,
+            _ => std::io::ErrorKind::Other,
+        };
+        DiskError::Io(std::io::Error::new(kind, e.message().to_string()))
     }
 }
 
 impl From<rustfs_protos::proto_gen::node_service::Error> for DiskError {
     fn from(e: rustfs_protos::proto_gen::node_service::Error) -> Self {
-        if let Some(err) = DiskError::from_u32(e.code) {
-            if matches!(err, DiskError::Io(_)) {
-                DiskError::other(e.error_info)
-            } else {
-                err
-            }
-        } else {
-            DiskError::other(e.error_info)
-        }
+        DiskError::from_remote(e.code, e.error_info)
     }
 }
 
@@ -377,6 +418,7 @@ impl Clone for DiskError {
             DiskError::SourceStalled => DiskError::SourceStalled,
             DiskError::Timeout => DiskError::Timeout,
             DiskError::InvalidPath => DiskError::InvalidPath,
+            DiskError::DiskClosed => DiskError::DiskClosed,
         }
     }
 }
@@ -426,6 +468,7 @@ impl DiskError {
             DiskError::SourceStalled => 0x28,
             DiskError::Timeout => 0x29,
             DiskError::InvalidPath => 0x2A,
+            DiskError::DiskClosed => 0x2B,
         }
     }
 
@@ -473,6 +516,7 @@ impl DiskError {
             0x28 => Some(DiskError::SourceStalled),
             0x29 => Some(DiskError::Timeout),
             0x2A => Some(DiskError::InvalidPath),
+            0x2B => Some(DiskError::DiskClosed),
             _ => None,
         }
     }
@@ -549,6 +593,7 @@ mod tests {
             DiskError::DriveIsRoot,
             DiskError::FaultyRemoteDisk,
             DiskError::FaultyDisk,
+            DiskError::DiskClosed,
             DiskError::DiskAccessDenied,
             DiskError::FileNotFound,
             DiskError::FileVersionNotFound,
@@ -663,6 +708,60 @@ mod tests {
         assert_eq!(DiskError::from_u32(999), None);
     }
 
+    #[test]
+    fn test_proto_error_roundtrip() {
+        // Remote read_all/write_all surface errors through the node service proto error
+        let proto_err: rustfs_protos::proto_gen::node_service::Error = DiskError::FileNotFound.into();
+        assert_eq!(DiskError::from(proto_err), DiskError::FileNotFound);
+
+        let proto_err: rustfs_protos::proto_gen::node_service::Error = DiskError::VolumeNotFound.into();
+        assert_eq!(DiskError::from(proto_err), DiskError::VolumeNotFound);
+
+        // Io errors keep their message across the wire
+        let proto_err: rustfs_protos::proto_gen::node_service::Error = DiskError::other("remote failure").into();
+        let disk_error = DiskError::from(proto_err);
+        assert!(matches!(disk_error, DiskError::Io(_)));
+        assert!(disk_error.to_string().contains("remote failure"));
+    }
+
+    #[test]
+    fn test_proto_error_info_mapping() {
+        // Errors sent as plain io errors are recovered from their message
+        let proto_err = rustfs_protos::proto_gen::node_service::Error {
+            code: DiskError::other("").to_u32(),
+            error_info: "io error Invalid cross-device link (os error 18)".to_string(),
+        };
+        assert_eq!(DiskError::from(proto_err), DiskError::CrossDeviceLink);
+
+        let proto_err = rustfs_protos::proto_gen::node_service::Error {
+            code: 0,
+            error_info: "erasure write quorum".to_string(),
+        };
+        assert_eq!(DiskError::from(proto_err), DiskError::ErasureWriteQuorum);
+
+        assert_eq!(DiskError::from_error_info("Erasure read quorum"), Some(DiskError::ErasureReadQuorum));
+        assert_eq!(DiskError::from_error_info("remote failure"), None);
+
+        // The newest coded variant is recovered from its message too
+        let last = (0x01..).map_while(DiskError::from_u32).last().unwrap();
+        assert_eq!(DiskError::from_error_info(&last.to_string()), Some(last));
+    }
+
+    #[test]
+    fn test_from_remote() {
+        assert_eq!(DiskError::from_remote(0x10, "file not found"), DiskError::FileNotFound);
+        assert_eq!(DiskError::from_remote(0x14, ""), DiskError::VolumeExists);
+
+        // Peers that only send a message still map to the variant it names
+        let msg = DiskError::VolumeNotEmpty.to_string();
+        assert_eq!(DiskError::from_remote(0, msg.as_str()), DiskError::VolumeNotEmpty);
+        assert_eq!(DiskError::from_remote(DiskError::other("").to_u32(), msg), DiskError::VolumeNotEmpty);
+
+        let err = DiskError::from_remote(0, "remote failure");
+        assert!(matches!(err, DiskError::Io(_)));
+        assert!(err.to_string().contains("remote failure"));
+    }
+
     #[test]
     fn test_disk_error_equality() {
         assert_eq!(DiskError::FileNotFound, DiskError::FileNotFound);
@@ -26,6 +26,7 @@ pub fn to_file_error(io_err: std::io::Error) -> std::io::Error {
         std::io::ErrorKind::InvalidInput => DiskError::FileNotFound.into(),
         std::io::ErrorKind::InvalidData => DiskError::FileCorrupt.into(),
         std::io::ErrorKind::StorageFull => DiskError::DiskFull.into(),
+        std::io::ErrorKind::CrossesDevices => DiskError::CrossDeviceLink.into(),
         _ => io_err,
     }
 }
@@ -33,13 +34,13 @@ pub fn to_file_error(io_err: std::io::Error) -> std::io::Error {
 pub fn to_volume_error(io_err: std::io::Error) -> std::io::Error {
     match io_err.kind() {
         std::io::ErrorKind::NotFound => DiskError::VolumeNotFound.into(),
-        std::io::ErrorKind::PermissionDenied => DiskError::DiskAccessDenied.into(),
+        std::io::ErrorKind::PermissionDenied => DiskError::VolumeAccessDenied.into(),
         std::io::ErrorKind::DirectoryNotEmpty => DiskError::VolumeNotEmpty.into(),
         std::io::ErrorKind::NotADirectory => DiskError::IsNotRegular.into(),
         std::io::ErrorKind::Other => match io_err.downcast::<DiskError>() {
             Ok(err) => match err {
                 DiskError::FileNotFound => DiskError::VolumeNotFound.into(),
-                DiskError::FileAccessDenied => DiskError::DiskAccessDenied.into(),
+                DiskError::FileAccessDenied => DiskError::VolumeAccessDenied.into(),
                 err => err.into(),
             },
             Err(err) => to_file_error(err),
@@ -192,9 +193,9 @@ mod tests {
         let result = to_volume_error(create_io_error(ErrorKind::NotFound));
         assert!(contains_disk_error(result, DiskError::VolumeNotFound));
 
-        // Test PermissionDenied -> DiskAccessDenied
+        // Test PermissionDenied -> VolumeAccessDenied
         let result = to_volume_error(create_io_error(ErrorKind::PermissionDenied));
-        assert!(contains_disk_error(result, DiskError::DiskAccessDenied));
+        assert!(contains_disk_error(result, DiskError::VolumeAccessDenied));
 
         // Test DirectoryNotEmpty -> VolumeNotEmpty
         let result = to_volume_error(create_io_error(ErrorKind::DirectoryNotEmpty));
@@ -212,10 +213,10 @@ mod tests {
         let result = to_volume_error(io_error);
         assert!(contains_disk_error(result, DiskError::VolumeNotFound));
 
-        // Test Other error kind with FileAccessDenied DiskError -> DiskAccessDenied
+        // Test Other error kind with FileAccessDenied DiskError -> VolumeAccessDenied
         let io_error = create_io_error_with_disk_error(DiskError::FileAccessDenied);
         let result = to_volume_error(io_error);
-        assert!(contains_disk_error(result, DiskError::DiskAccessDenied));
+        assert!(contains_disk_error(result, DiskError::VolumeAccessDenied));
 
         // Test Other error kind with other DiskError -> passthrough
         let io_error = create_io_error_with_disk_error(DiskError::DiskFull);
@@ -434,7 +435,7 @@ mod tests {
         // Test volume error conversion with different input types
         let test_cases = vec![
             (ErrorKind::NotFound, DiskError::VolumeNotFound),
-            (ErrorKind::PermissionDenied, DiskError::DiskAccessDenied),
+            (ErrorKind::PermissionDenied, DiskError::VolumeAccessDenied),
             (ErrorKind::DirectoryNotEmpty, DiskError::VolumeNotEmpty),
         ];
 
@@ -46,6 +46,22 @@ pub fn reduce_quorum_errs(errors: &[Option<Error>], ignored_errs: &[Error], quor
     if max_count >= quorun { err } else { Some(quorun_err) }
 }
 
+/// Reduces the per-disk results of an operation across an erasure set.
+///
+/// Offline disks never count towards an outcome. Returns `ErasureReadQuorum` when fewer than
+/// `read_quorum` disks agree, `ErasureWriteQuorum` when they reach the read but not the write
+/// quorum, and otherwise the agreed outcome: `None` for success or the dominant error.
+pub fn reduce_errors(errors: &[Option<Error>], read_quorum: usize, write_quorum: usize) -> Option<Error> {
+    let (max_count, err) = reduce_errs(errors, BASE_IGNORED_ERRS);
+    if max_count < read_quorum {
+        Some(Error::ErasureReadQuorum)
+    } else if max_count < write_quorum {
+        Some(Error::ErasureWriteQuorum)
+    } else {
+        err
+    }
+}
+
 pub fn reduce_errs(errors: &[Option<Error>], ignored_errs: &[Error]) -> (usize, Option<Error>) {
     let nil_error = Error::other("nil".to_string());
 
@@ -145,6 +161,41 @@ mod tests {
         assert_eq!(res, Some(quorum_err));
     }
 
+    #[test]
+    fn test_reduce_errors_read_quorum() {
+        // 6 drives, read quorum 3, write quorum 4
+        let offline = Some(Error::DiskNotFound);
+        let errors = vec![None, None, offline.clone(), offline.clone(), offline.clone(), offline.clone()];
+        assert_eq!(reduce_errors(&errors, 3, 4), Some(Error::ErasureReadQuorum));
+
+        let errors = vec![None, None, None, offline.clone(), offline.clone(), offline.clone()];
+        assert_ne!(reduce_errors(&errors, 3, 4), Some(Error::ErasureReadQuorum));
+    }
+
+    #[test]
+    fn test_reduce_errors_write_quorum() {
+        let offline = Some(Error::FaultyDisk);
+        let errors = vec![None, None, None, offline.clone(), offline.clone(), offline.clone()];
+        assert_eq!(reduce_errors(&errors, 3, 4), Some(Error::ErasureWriteQuorum));
+
+        let errors = vec![None, None, None, None, offline.clone(), offline.clone()];
+        assert_eq!(reduce_errors(&errors, 3, 4), None);
+    }
+
+    #[test]
+    fn test_reduce_errors_dominant_error() {
+        let not_found = Some(Error::FileNotFound);
+        let errors = vec![
+            not_found.clone(),
+            not_found.clone(),
+            not_found.clone(),
+            not_found.clone(),
+            None,
+            None,
+        ];
+        assert_eq!(reduce_errors(&errors, 3, 4), Some(Error::FileNotFound));
+    }
+
     #[test]
     fn test_count_errs() {
         let e1 = err_io("a");
@@ -0,0 +1,387 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::error::DiskError;
+use rustfs_filemeta::ObjectPartInfo;
+use rustfs_utils::HashAlgorithm;
+use rustfs_utils::hash::HashState;
+use rustfs_utils::string::parse_bool_with_default;
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll, ready};
+use tokio::fs::File;
+use tokio::io::AsyncWrite;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Environment variable to remove partially written files when their writer is dropped. Defaults to on,
+/// set it to false to keep them around.
+pub const ENV_RUSTFS_DRIVE_CLEANUP_PARTIAL_WRITES: &str = "RUSTFS_DRIVE_CLEANUP_PARTIAL_WRITES";
+
+static INCOMPLETE_WRITES: AtomicU64 = AtomicU64::new(0);
+
+static DEFAULT_PARTIAL_WRITE_POLICY: LazyLock<PartialWritePolicy> = LazyLock::new(|| {
+    let cleanup = std::env::var(ENV_RUSTFS_DRIVE_CLEANUP_PARTIAL_WRITES)
+        .map(|v| parse_bool_with_default(&v, true))
+        .unwrap_or(true);
+    if cleanup {
+        PartialWritePolicy::Remove
+    } else {
+        PartialWritePolicy::Keep
+    }
+});
+
+/// Number of files dropped before all of their expected bytes were written.
+pub fn incomplete_writes_total() -> u64 {
+    INCOMPLETE_WRITES.load(Ordering::Relaxed)
+}
+
+/// What to do with a file whose writer is dropped before it was committed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartialWritePolicy {
+    /// Remove the partial file.
+    #[default]
+    Remove,
+    /// Leave the partial file in place.
+    Keep,
+}
+
+/// Suffix of the file recording the checksum of a file written by `create_file`.
+///
+/// Distinct from the `part.N.meta` files of multipart uploads, which `read_parts` parses.
+pub const WRITE_CHECKSUM_SUFFIX: &str = ".checksum";
+
+/// Path of the file that records the checksum of the file at `path`.
+///
+/// It sits next to the file and moves with it: `rename_data` carries it along with the data dir,
+/// `rename_part` renames it with the part and deletes remove it with the file.
+pub fn write_checksum_path(path: &Path) -> PathBuf {
+    let mut p = path.as_os_str().to_owned();
+    p.push(WRITE_CHECKSUM_SUFFIX);
+    PathBuf::from(p)
+}
+
+/// Key under which the checksum computed while writing is stored in `ObjectPartInfo::checksums`.
+///
+/// Kept apart from the S3 checksum names, the digest covers the file as stored on this drive.
+pub fn write_checksum_key(algo: &HashAlgorithm) -> String {
+    format!("x-rustfs-file-{algo:?}").to_lowercase()
+}
+
+/// Returns the algorithm and hex digest of the checksum recorded by `create_file` in `part`, if any.
+pub fn recorded_write_checksum(part: &ObjectPartInfo) -> Option<(HashAlgorithm, String)> {
+    const CANDIDATES: [HashAlgorithm; 8] = [
+        HashAlgorithm::HighwayHash256S,
+        HashAlgorithm::HighwayHash256,
+        HashAlgorithm::SHA256,
+        HashAlgorithm::BLAKE2b512,
+        HashAlgorithm::Blake3,
+        HashAlgorithm::Md5,
+        HashAlgorithm::Crc32c,
+        HashAlgorithm::Xxh3,
+    ];
+
+    let sums = part.checksums.as_ref()?;
+    CANDIDATES
+        .into_iter()
+        .find_map(|algo| sums.get(&write_checksum_key(&algo)).map(|sum| (algo, sum.clone())))
+}
+
+/// Rolling checksum of the bytes passed to a `LocalFileWriter`.
+struct WriteChecksum {
+    algo: HashAlgorithm,
+    state: HashState,
+}
+
+impl std::fmt::Debug for WriteChecksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WriteChecksum").field("algo", &self.algo).finish()
+    }
+}
+
+impl WriteChecksum {
+    /// Stores the digest next to `path` (see `write_checksum_path`), on the blocking pool.
+    fn persist(self, path: &Path, size: u64) -> io::Result<JoinHandle<io::Result<()>>> {
+        let sum = hex_simd::encode_to_string(self.state.finalize(), hex_simd::AsciiCase::Lower);
+        let part = ObjectPartInfo {
+            size: size as usize,
+            checksums: Some(HashMap::from([(write_checksum_key(&self.algo), sum)])),
+            ..Default::default()
+        };
+        let buf = part.marshal_msg().map_err(io::Error::other)?;
+        let sum_path = write_checksum_path(path);
+        Ok(tokio::task::spawn_blocking(move || std::fs::write(sum_path, buf)))
+    }
+}
+
+/// Writer returned by `LocalDisk::create_file`.
+///
+/// Tracks the number of bytes written against the size announced to `create_file`.
+/// Shutting the writer down commits the file and fails with `ShortWrite` or `MoreData`
+/// when the byte count does not match. A writer dropped with a size mismatch and no
+/// commit is counted as an incomplete write and handled according to its policy.
+#[derive(Debug)]
+pub struct LocalFileWriter<W = File> {
+    inner: W,
+    path: PathBuf,
+    expected: Option<u64>,
+    written: u64,
+    committed: bool,
+    policy: PartialWritePolicy,
+    checksum: Option<WriteChecksum>,
+    persisting: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl<W> LocalFileWriter<W> {
+    /// Wraps `inner`. A non-positive `file_size` means the size is unknown and is not checked.
+    pub fn new(inner: W, path: PathBuf, file_size: i64) -> Self {
+        Self {
+            inner,
+            path,
+            expected: (file_size > 0).then_some(file_size as u64),
+            written: 0,
+            committed: false,
+            policy: *DEFAULT_PARTIAL_WRITE_POLICY,
+            checksum: None,
+            persisting: None,
+        }
+    }
+
+    /// Hashes the written bytes with `algo` and stores the digest next to the file
+    /// (see `write_checksum_path`) when the writer is committed.
+    pub fn with_checksum(mut self, algo: HashAlgorithm) -> Self {
+        self.checksum = Some(WriteChecksum {
+            state: algo.new_state(),
+            algo,
+        });
+        self
+    }
+
+    pub fn with_policy(mut self, policy: PartialWritePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    fn check_size(&self) -> Result<(), DiskError> {
+        match self.expected {
+            Some(expected) if self.written < expected => Err(DiskError::ShortWrite),
+            Some(expected) if self.written > expected => Err(DiskError::MoreData),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for LocalFileWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let n = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.written += n as u64;
+        if let Some(checksum) = this.checksum.as_mut() {
+            checksum.state.update(&buf[..n]);
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.committed {
+            this.check_size()?;
+        }
+
+        if this.persisting.is_none() {
+            ready!(Pin::new(&mut this.inner).poll_shutdown(cx))?;
+            if let Some(checksum) = this.checksum.take() {
+                this.persisting = Some(checksum.persist(&this.path, this.written)?);
+            }
+        }
+        if let Some(persisting) = this.persisting.as_mut() {
+            let res = ready!(Pin::new(persisting).poll(cx));
+            this.persisting = None;
+            res.map_err(io::Error::other)??;
+        }
+        this.committed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W> Drop for LocalFileWriter<W> {
+    fn drop(&mut self) {
+        if self.committed || self.check_size().is_ok() {
+            return;
+        }
+
+        INCOMPLETE_WRITES.fetch_add(1, Ordering::Relaxed);
+        warn!(
+            "file {:?} dropped before commit, written {} of {:?} bytes",
+            self.path, self.written, self.expected
+        );
+
+        if self.policy == PartialWritePolicy::Remove
+            && let Err(err) = std::fs::remove_file(&self.path)
+            && err.kind() != io::ErrorKind::NotFound
+        {
+            warn!("remove partial file {:?} failed: {}", self.path, err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use tokio::io::AsyncWriteExt;
+
+    async fn create(dir: &TempDir, name: &str, file_size: i64) -> LocalFileWriter {
+        let path = dir.path().join(name);
+        let file = File::create(&path).await.unwrap();
+        LocalFileWriter::new(file, path, file_size)
+    }
+
+    #[tokio::test]
+    async fn test_commit_with_expected_size() {
+        let dir = TempDir::new().unwrap();
+        let mut w = create(&dir, "complete", 5).await;
+        w.write_all(b"hello").await.unwrap();
+        w.shutdown().await.unwrap();
+        assert_eq!(w.written(), 5);
+        drop(w);
+
+        assert_eq!(tokio::fs::read(dir.path().join("complete")).await.unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_commit_persists_checksum() {
+        let dir = TempDir::new().unwrap();
+        let algo = HashAlgorithm::Blake3;
+        let mut w = create(&dir, "summed", 5).await.with_checksum(algo.clone());
+        w.write_all(b"hello").await.unwrap();
+        assert!(!write_checksum_path(&dir.path().join("summed")).exists());
+        w.shutdown().await.unwrap();
+        assert!(!dir.path().join("summed.meta").exists());
+
+        let buf = tokio::fs::read(write_checksum_path(&dir.path().join("summed")))
+            .await
+            .unwrap();
+        let part = ObjectPartInfo::unmarshal(&buf).unwrap();
+        assert_eq!(part.size, 5);
+        let (recorded, sum) = recorded_write_checksum(&part).unwrap();
+        assert_eq!(recorded, algo);
+        assert_eq!(sum, rustfs_utils::hash::sum_blake3_hex(b"hello"));
+    }
+
+    #[tokio::test]
+    async fn test_commit_size_mismatch() {
+        let dir = TempDir::new().unwrap();
+
+        let mut short = create(&dir, "short", 10).await;
+        short.write_all(b"hello").await.unwrap();
+        let err = short.shutdown().await.unwrap_err();
+        assert_eq!(DiskError::from(err), DiskError::ShortWrite);
+
+        let mut more = create(&dir, "more", 3).await;
+        more.write_all(b"hello").await.unwrap();
+        let err = more.shutdown().await.unwrap_err();
+        assert_eq!(DiskError::from(err), DiskError::MoreData);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_size_is_not_checked() {
+        let dir = TempDir::new().unwrap();
+        let mut w = create(&dir, "unknown", -1).await;
+        w.write_all(b"hello").await.unwrap();
+        w.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_drop_without_commit_removes_partial_file() {
+        let dir = TempDir::new().unwrap();
+        let before = incomplete_writes_total();
+
+        let mut w = create(&dir, "partial", 10).await.with_policy(PartialWritePolicy::Remove);
+        w.write_all(b"hello").await.unwrap();
+        w.flush().await.unwrap();
+        drop(w);
+
+        assert!(!dir.path().join("partial").exists());
+        assert!(incomplete_writes_total() > before);
+    }
+
+    #[tokio::test]
+    async fn test_drop_without_commit_removes_partial_file_by_default() {
+        let dir = TempDir::new().unwrap();
+
+        let mut w = create(&dir, "default", 10).await;
+        w.write_all(b"hello").await.unwrap();
+        w.flush().await.unwrap();
+        drop(w);
+
+        assert!(!dir.path().join("default").exists());
+    }
+
+    #[tokio::test]
+    async fn test_drop_without_commit_keeps_partial_file() {
+        let dir = TempDir::new().unwrap();
+
+        let mut w = create(&dir, "kept", 10).await.with_policy(PartialWritePolicy::Keep);
+        w.write_all(b"hello").await.unwrap();
+        w.flush().await.unwrap();
+        drop(w);
+
+        assert!(dir.path().join("kept").exists());
+    }
+
+    #[tokio::test]
+    async fn test_commit_stores_checksum() {
+        let dir = TempDir::new().unwrap();
+        let mut w = create(&dir, "summed", 11).await.with_checksum(HashAlgorithm::Crc32c);
+        w.write_all(b"hello ").await.unwrap();
+        w.write_all(b"world").await.unwrap();
+        w.shutdown().await.unwrap();
+
+        let buf = tokio::fs::read(write_checksum_path(&dir.path().join("summed")))
+            .await
+            .unwrap();
+        let part = ObjectPartInfo::unmarshal(&buf).unwrap();
+        assert_eq!(part.size, 11);
+        assert_eq!(
+            part.checksums.unwrap()[&write_checksum_key(&HashAlgorithm::Crc32c)],
+            rustfs_utils::hash::sum_crc32c_hex(b"hello world")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drop_after_all_bytes_keeps_file() {
+        let dir = TempDir::new().unwrap();
+
+        let mut w = create(&dir, "full", 5).await.with_policy(PartialWritePolicy::Remove);
+        w.write_all(b"hello").await.unwrap();
+        w.flush().await.unwrap();
+        drop(w);
+
+        assert!(dir.path().join("full").exists());
+    }
+}
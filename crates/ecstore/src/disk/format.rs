@@ -173,6 +173,26 @@ impl FormatV3 {
         serde_json::to_string(self)
     }
 
+    /// Decodes `format.json`, rejecting data that isn't JSON or carries an unknown
+    /// version or backend with `CorruptedFormat`.
+    pub fn load(data: &[u8]) -> Result<Self> {
+        let fm = Self::try_from(data).map_err(|_| DiskError::CorruptedFormat)?;
+
+        if fm.version != FormatMetaVersion::V1
+            || fm.format == FormatBackend::Unknown
+            || fm.erasure.version == FormatErasureVersion::Unknown
+        {
+            return Err(DiskError::CorruptedFormat);
+        }
+
+        Ok(fm)
+    }
+
+    /// Encodes the format as the JSON stored in `format.json`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(Error::other)
+    }
+
     /// returns the i,j'th position of the input `diskID` against the reference
     ///
     /// format, after successful validation.
@@ -247,6 +267,79 @@ impl FormatV3 {
     }
 }
 
+/// Erasure section of a version 1 `format.json`, a single set listed as `jbod`.
+#[derive(Deserialize)]
+struct FormatErasureV1 {
+    disk: Uuid,
+    jbod: Vec<Uuid>,
+}
+
+/// Erasure section of a version 2 `format.json`, which predates `distributionAlgo`.
+#[derive(Deserialize)]
+struct FormatErasureV2 {
+    this: Uuid,
+    sets: Vec<Vec<Uuid>>,
+}
+
+#[derive(Deserialize)]
+struct FormatErasureVersionOnly {
+    version: FormatErasureVersion,
+}
+
+/// Top level of an older `format.json`, generic over its erasure section.
+#[derive(Deserialize)]
+struct FormatLegacy<T> {
+    version: FormatMetaVersion,
+    format: FormatBackend,
+    #[serde(default)]
+    id: Uuid,
+    #[serde(rename = "xl")]
+    erasure: T,
+}
+
+/// Upgrades a version 1, 2 or 3 `format.json` to `FormatV3`, keeping the disk UUID and
+/// set membership. Migrated formats keep the original `CRCMOD` distribution so objects
+/// hash to the same sets. Unrecognized input is reported as `CorruptedFormat`.
+pub fn migrate_to_v3(data: &[u8]) -> Result<FormatV3> {
+    fn parse<T: for<'de> Deserialize<'de>>(data: &[u8]) -> Result<FormatLegacy<T>> {
+        let fm: FormatLegacy<T> = serde_json::from_slice(data).map_err(|_| DiskError::CorruptedFormat)?;
+        if fm.version != FormatMetaVersion::V1 || fm.format == FormatBackend::Unknown {
+            return Err(DiskError::CorruptedFormat);
+        }
+        Ok(fm)
+    }
+
+    let (version, format, id, this, sets) = match parse::<FormatErasureVersionOnly>(data)?.erasure.version {
+        FormatErasureVersion::V3 => return FormatV3::load(data),
+        FormatErasureVersion::V2 => {
+            let fm = parse::<FormatErasureV2>(data)?;
+            (fm.version, fm.format, fm.id, fm.erasure.this, fm.erasure.sets)
+        }
+        FormatErasureVersion::V1 => {
+            let fm = parse::<FormatErasureV1>(data)?;
+            (fm.version, fm.format, fm.id, fm.erasure.disk, vec![fm.erasure.jbod])
+        }
+        FormatErasureVersion::Unknown => return Err(DiskError::CorruptedFormat),
+    };
+
+    if !sets.iter().flatten().any(|id| *id == this) {
+        return Err(DiskError::CorruptedFormat);
+    }
+
+    Ok(FormatV3 {
+        version,
+        format,
+        id,
+        erasure: FormatErasureV3 {
+            version: FormatErasureVersion::V3,
+            this,
+            sets,
+            distribution_algo: DistributionAlgoVersion::V1,
+        },
+        disk_info: None,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -543,4 +636,97 @@ mod test {
         assert_eq!(original.erasure.sets.len(), deserialized.erasure.sets.len());
         assert_eq!(original.erasure.distribution_algo, deserialized.erasure.distribution_algo);
     }
+
+    #[test]
+    fn test_format_v3_load_round_trip() {
+        let mut original = FormatV3::new(2, 4);
+        original.erasure.this = original.erasure.sets[1][2];
+
+        let loaded = FormatV3::load(&original.to_bytes().unwrap()).unwrap();
+        assert_eq!(loaded, original);
+        assert_eq!(loaded.find_disk_index_by_disk_id(loaded.erasure.this).unwrap(), (1, 2));
+
+        let single = FormatV3::new(1, 1);
+        assert_eq!(FormatV3::load(&single.to_bytes().unwrap()).unwrap().format, FormatBackend::ErasureSingle);
+    }
+
+    #[test]
+    fn test_migrate_v1_to_v3() {
+        let disks = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let id = Uuid::new_v4();
+        let data = format!(
+            r#"{{"version":"1","format":"xl","id":"{id}","xl":{{"version":"1","disk":"{}","jbod":["{}","{}","{}","{}"]}}}}"#,
+            disks[2], disks[0], disks[1], disks[2], disks[3]
+        );
+
+        let fm = migrate_to_v3(data.as_bytes()).unwrap();
+        assert_eq!(fm.id, id);
+        assert_eq!(fm.format, FormatBackend::Erasure);
+        assert_eq!(fm.erasure.version, FormatErasureVersion::V3);
+        assert_eq!(fm.erasure.this, disks[2]);
+        assert_eq!(fm.erasure.sets, vec![disks.to_vec()]);
+        assert_eq!(fm.erasure.distribution_algo, DistributionAlgoVersion::V1);
+        assert_eq!(fm.find_disk_index_by_disk_id(disks[2]).unwrap(), (0, 2));
+
+        // The upgraded format loads as a regular V3 document
+        assert_eq!(FormatV3::load(&fm.to_bytes().unwrap()).unwrap(), fm);
+    }
+
+    #[test]
+    fn test_migrate_v2_to_v3() {
+        let sets = vec![vec![Uuid::new_v4(), Uuid::new_v4()], vec![Uuid::new_v4(), Uuid::new_v4()]];
+        let this = sets[1][0];
+        let data = serde_json::json!({
+            "version": "1",
+            "format": "xl",
+            "id": Uuid::new_v4(),
+            "xl": { "version": "2", "this": this, "sets": sets },
+        })
+        .to_string();
+
+        let fm = migrate_to_v3(data.as_bytes()).unwrap();
+        assert_eq!(fm.erasure.this, this);
+        assert_eq!(fm.erasure.sets, sets);
+        assert_eq!(fm.find_disk_index_by_disk_id(this).unwrap(), (1, 0));
+
+        // V3 input passes through unchanged
+        let v3 = FormatV3::new(1, 4);
+        assert_eq!(migrate_to_v3(&v3.to_bytes().unwrap()).unwrap(), v3);
+    }
+
+    #[test]
+    fn test_migrate_rejects_unrecognized() {
+        let disk = Uuid::new_v4();
+        for data in [
+            "not json".to_string(),
+            r#"{"version":"1","format":"xl"}"#.to_string(),
+            format!(r#"{{"version":"1","format":"xl","xl":{{"version":"4","this":"{disk}","sets":[["{disk}"]]}}}}"#),
+            format!(r#"{{"version":"2","format":"xl","xl":{{"version":"1","disk":"{disk}","jbod":["{disk}"]}}}}"#),
+            format!(r#"{{"version":"1","format":"fs","xl":{{"version":"1","disk":"{disk}","jbod":["{disk}"]}}}}"#),
+            format!(
+                r#"{{"version":"1","format":"xl","xl":{{"version":"1","disk":"{disk}","jbod":["{}"]}}}}"#,
+                Uuid::new_v4()
+            ),
+        ] {
+            assert_eq!(migrate_to_v3(data.as_bytes()).unwrap_err(), DiskError::CorruptedFormat, "{data}");
+        }
+    }
+
+    #[test]
+    fn test_format_v3_load_rejects_unknown_tags() {
+        let valid = FormatV3::new(1, 4).to_json().unwrap();
+        assert!(FormatV3::load(valid.as_bytes()).is_ok());
+
+        for (from, to) in [
+            (r#""version":"1""#, r#""version":"9""#),
+            (r#""format":"xl""#, r#""format":"fs""#),
+            (r#""version":"3","this""#, r#""version":"7","this""#),
+        ] {
+            assert!(valid.contains(from), "{from}");
+            let data = valid.replacen(from, to, 1);
+            assert_eq!(FormatV3::load(data.as_bytes()).unwrap_err(), DiskError::CorruptedFormat, "{to}");
+        }
+
+        assert_eq!(FormatV3::load(b"not json").unwrap_err(), DiskError::CorruptedFormat);
+    }
 }
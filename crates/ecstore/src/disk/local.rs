@@ -17,51 +17,91 @@ use crate::data_usage::local_snapshot::ensure_data_usage_layout;
 use crate::disk::{
     BUCKET_META_PREFIX, CHECK_PART_FILE_CORRUPT, CHECK_PART_FILE_NOT_FOUND, CHECK_PART_SUCCESS, CHECK_PART_UNKNOWN,
     CHECK_PART_VOLUME_NOT_FOUND, CheckPartsResp, DeleteOptions, DiskAPI, DiskInfo, DiskInfoOptions, DiskLocation, DiskMetrics,
-    FileInfoVersions, FileReader, FileWriter, RUSTFS_META_BUCKET, RUSTFS_META_TMP_DELETED_BUCKET, ReadMultipleReq,
-    ReadMultipleResp, ReadOptions, RenameDataResp, STORAGE_FORMAT_FILE, STORAGE_FORMAT_FILE_BACKUP, UpdateMetadataOpts,
-    VolumeInfo, WalkDirOptions, conv_part_err_to_int,
+    FileInfoVersions, FileReader, FileWriter, RUSTFS_META_BUCKET, RUSTFS_META_DEDUP_BUCKET, RUSTFS_META_TMP_DELETED_BUCKET,
+    ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp, STORAGE_FORMAT_FILE, STORAGE_FORMAT_FILE_BACKUP, SpanTimer,
+    UpdateMetadataOpts, VolumeInfo, VolumeUsage, WalkDirOptions, conv_part_err_to_int,
     endpoint::Endpoint,
     error::{DiskError, Error, FileAccessDeniedWithContext, Result},
     error_conv::{to_access_error, to_file_error, to_unformatted_disk_error, to_volume_error},
-    format::FormatV3,
-    fs::{O_APPEND, O_CREATE, O_RDONLY, O_TRUNC, O_WRONLY, access, lstat, lstat_std, remove, remove_all_std, remove_std, rename},
+    file_writer::{LocalFileWriter, recorded_write_checksum, write_checksum_path},
+    format::{FormatV3, migrate_to_v3},
+    fs::{
+        O_APPEND, O_CREATE, O_RDONLY, O_TRUNC, O_WRONLY, access, lstat, lstat_std, remove, remove_all, remove_all_std, remove_std,
+    },
     os,
-    os::{check_path_length, is_empty_dir, is_root_disk, rename_all},
+    os::{check_path_length, is_empty_dir, is_root_disk, rename_all, rename_all_or_copy, sync_dir},
 };
 use crate::erasure_coding::bitrot_verify;
 use crate::file_cache::{get_global_file_cache, prefetch_metadata_patterns, read_metadata_cached};
 use crate::global::{GLOBAL_IsErasureSD, GLOBAL_RootDiskThreshold};
 use bytes::Bytes;
+use futures::{StreamExt, stream};
 use parking_lot::RwLock as ParkingLotRwLock;
+use rustfs_common::data_usage::{DataUsageCache, DataUsageEntry, SizeSummary};
+use rustfs_common::heal_channel::HealScanMode;
 use rustfs_filemeta::{
-    Cache, FileInfo, FileInfoOpts, FileMeta, MetaCacheEntry, MetacacheWriter, ObjectPartInfo, Opts, RawFileInfo, UpdateFn,
-    get_file_info, read_xl_meta_no_data,
+    Cache, FileInfo, FileInfoOpts, FileMeta, FileMetaVersion, MetaCacheEntry, MetacacheWriter, ObjectPartInfo, Opts, RawFileInfo,
+    UpdateFn, get_file_info, read_xl_meta_no_data,
 };
-use rustfs_utils::HashAlgorithm;
+use rustfs_utils::hash::digest_matches_hex;
 use rustfs_utils::os::get_info;
 use rustfs_utils::path::{
-    GLOBAL_DIR_SUFFIX, GLOBAL_DIR_SUFFIX_WITH_SLASH, SLASH_SEPARATOR_STR, clean, decode_dir_object, encode_dir_object,
-    has_suffix, path_join, path_join_buf,
+    GLOBAL_DIR_SUFFIX, GLOBAL_DIR_SUFFIX_WITH_SLASH, SLASH_SEPARATOR_STR, decode_dir_object, encode_dir_object, has_suffix,
+    path_join, path_join_buf,
 };
+use rustfs_utils::string::match_simple;
+use rustfs_utils::{HashAlgorithm, HashingReader};
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::io::SeekFrom;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, OnceLock};
-use std::time::Duration;
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, SystemTime};
 use std::{
     fs::Metadata,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 use time::OffsetDateTime;
 use tokio::fs::{self, File};
-use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, ErrorKind};
-use tokio::sync::RwLock;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, ErrorKind, ReadBuf};
+use tokio::sync::mpsc::Sender;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::time::interval;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Files smaller than this are written through the page cache even when `O_DIRECT` is enabled.
+#[cfg(target_os = "linux")]
+const DIRECT_IO_MIN_FILE_SIZE: i64 = 128 * 1024;
+
+/// How long trashed entries are kept before the background cleanup removes them.
+const TRASH_RETENTION: Duration = Duration::from_secs(60 * 15);
+
+/// Age after which leftovers in the meta tmp bucket are removed when the disk is opened.
+const TMP_RETENTION: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Size of the buffer `VerifyingReader` uses for the bytes it hashes but does not return.
+const VERIFY_SCRATCH_SIZE: usize = 64 * 1024;
+
+/// Number of leading bytes `warm_up` reads from each file, enough for the xl.meta header and
+/// small inline objects.
+const WARM_UP_READ_SIZE: u64 = 64 * 1024;
+
+/// Maximum number of volumes created at once by `make_volumes`.
+const MAKE_VOLUMES_CONCURRENCY: usize = 16;
+
+/// Maximum number of files read at once by `warm_up`.
+const WARM_UP_CONCURRENCY: usize = 16;
+
+/// Pause taken by the namespace scanner whenever its `ShouldSleepFn` asks to throttle.
+const SCANNER_SLEEP: Duration = Duration::from_millis(10);
+
+/// Called by the namespace scanner before each object; returning `true` pauses the scan briefly.
+pub type ShouldSleepFn = Option<Arc<dyn Fn() -> bool + Send + Sync>>;
+
 #[derive(Debug, Clone)]
 pub struct FormatInfo {
     pub id: Option<Uuid>,
@@ -76,6 +116,113 @@ pub enum InternalBuf<'a> {
     Owned(Bytes),
 }
 
+/// Reader or writer that holds an open-file permit of its disk until it is dropped.
+struct OpenFileGuard<T> {
+    inner: T,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl<T> OpenFileGuard<T> {
+    fn new(inner: T, permit: Option<OwnedSemaphorePermit>) -> Self {
+        Self { inner, _permit: permit }
+    }
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for OpenFileGuard<T> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+    }
+}
+
+impl<T: AsyncWrite + Unpin> AsyncWrite for OpenFileGuard<T> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Reader returned by `read_file_stream` when `ReadOptions::verify` is set.
+///
+/// Only `offset..offset + length` is returned, but the whole file is hashed: the bytes before the
+/// range are read and discarded up front and the rest is drained once the range is exhausted.
+/// The digest is then compared with the checksum recorded at write time, and a mismatch fails
+/// the final read with `FileCorrupt`.
+struct VerifyingReader<R> {
+    inner: Option<HashingReader<R>>,
+    expected: String,
+    skip: u64,
+    remaining: u64,
+    scratch: Vec<u8>,
+}
+
+impl<R: AsyncRead + Unpin> VerifyingReader<R> {
+    fn new(inner: R, algo: &HashAlgorithm, expected: String, offset: u64, length: u64) -> Self {
+        Self {
+            inner: Some(HashingReader::new(inner, algo)),
+            expected,
+            skip: offset,
+            remaining: length,
+            scratch: vec![0; VERIFY_SCRATCH_SIZE],
+        }
+    }
+
+    /// Reads up to `limit` bytes into the scratch buffer.
+    fn poll_scratch(&mut self, cx: &mut Context<'_>, limit: usize) -> Poll<std::io::Result<usize>> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Poll::Ready(Ok(0));
+        };
+        let mut buf = ReadBuf::new(&mut self.scratch[..limit]);
+        ready!(Pin::new(inner).poll_read(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for VerifyingReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        while this.skip > 0 {
+            let limit = this.skip.min(this.scratch.len() as u64) as usize;
+            let n = ready!(this.poll_scratch(cx, limit))?;
+            if n == 0 {
+                return Poll::Ready(Err(DiskError::FileCorrupt.into()));
+            }
+            this.skip -= n as u64;
+        }
+
+        if this.remaining > 0 {
+            let limit = this.remaining.min(buf.remaining().min(this.scratch.len()) as u64) as usize;
+            if limit == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            let n = ready!(this.poll_scratch(cx, limit))?;
+            if n == 0 {
+                return Poll::Ready(Err(DiskError::FileCorrupt.into()));
+            }
+            buf.put_slice(&this.scratch[..n]);
+            this.remaining -= n as u64;
+            return Poll::Ready(Ok(()));
+        }
+
+        while ready!(this.poll_scratch(cx, this.scratch.len()))? > 0 {}
+
+        if let Some(inner) = this.inner.take()
+            && !digest_matches_hex(&inner.finalize(), &this.expected)
+        {
+            return Poll::Ready(Err(DiskError::FileCorrupt.into()));
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
 pub struct LocalDisk {
     pub root: PathBuf,
     pub format_path: PathBuf,
@@ -83,11 +230,20 @@ pub struct LocalDisk {
     pub endpoint: Endpoint,
     pub disk_info_cache: Arc<Cache<DiskInfo>>,
     pub scanning: AtomicU32,
+    pub healing: AtomicU32,
     pub rotational: bool,
     pub fstype: String,
     pub major: u64,
     pub minor: u64,
     pub nrrequests: u64,
+    /// Large files are written with `O_DIRECT`, only set when the drive supports it.
+    pub direct_io: bool,
+    /// Bounds the files opened by `read_file`, `read_file_stream`, `create_file` and `append_file`.
+    open_files: Option<Arc<Semaphore>>,
+    /// Every call that would modify the drive fails with `DiskAccessDenied`.
+    pub read_only: bool,
+    /// Files written by `create_file` are hashed with this algorithm and the digest is kept next to them.
+    pub write_checksum: Option<HashAlgorithm>,
     // Performance optimization fields
     path_cache: Arc<ParkingLotRwLock<HashMap<String, PathBuf>>>,
     current_dir: Arc<OnceLock<PathBuf>>,
@@ -134,22 +290,30 @@ impl LocalDisk {
 
         ensure_data_usage_layout(&root).await.map_err(DiskError::from)?;
 
-        if cleanup {
-            // TODO: remove temporary data
-        }
-
         // Use optimized path resolution instead of absolutize_virtually
         let format_path = root.join(RUSTFS_META_BUCKET).join(super::FORMAT_CONFIG_FILE);
         debug!("format_path: {:?}", format_path);
-        let (format_data, format_meta) = read_file_exists(&format_path).await?;
+        let (mut format_data, mut format_meta) = read_file_exists(&format_path).await?;
 
         let mut id = None;
         // let mut format_legacy = false;
         let mut format_last_check = None;
 
         if !format_data.is_empty() {
-            let s = format_data.as_ref();
-            let fm = FormatV3::try_from(s).map_err(Error::other)?;
+            let fm = match FormatV3::load(&format_data) {
+                Ok(fm) => fm,
+                Err(err) => {
+                    // Older layouts are upgraded in place, anything else is still corrupt
+                    let fm = migrate_to_v3(&format_data).map_err(|_| err)?;
+                    info!("migrating {:?} to format V3", format_path);
+
+                    let tmp_path = format_path.with_file_name(format!("{}.{}", super::FORMAT_CONFIG_FILE, Uuid::new_v4()));
+                    fs::write(&tmp_path, fm.to_bytes()?).await.map_err(to_file_error)?;
+                    fs::rename(&tmp_path, &format_path).await.map_err(to_file_error)?;
+                    (format_data, format_meta) = read_file_exists(&format_path).await?;
+                    fm
+                }
+            };
             let (set_idx, disk_idx) = fm.find_disk_index_by_disk_id(fm.erasure.this)?;
 
             if set_idx as i32 != ep.set_idx || disk_idx as i32 != ep.disk_idx {
@@ -201,7 +365,6 @@ impl LocalDisk {
 
         let cache = Cache::new(update_fn, Duration::from_secs(1), Opts::default());
 
-        // TODO: DIRECT support
         // TODD: DiskInfo
         let mut disk = Self {
             root: root.clone(),
@@ -210,11 +373,16 @@ impl LocalDisk {
             format_info: RwLock::new(format_info),
             disk_info_cache: Arc::new(cache),
             scanning: AtomicU32::new(0),
+            healing: AtomicU32::new(0),
             rotational: Default::default(),
             fstype: Default::default(),
             minor: Default::default(),
             major: Default::default(),
             nrrequests: Default::default(),
+            direct_io: false,
+            open_files: None,
+            read_only: false,
+            write_checksum: None,
             // // format_legacy,
             // format_file_info: Mutex::new(format_meta),
             // format_data: Mutex::new(format_data),
@@ -242,16 +410,116 @@ impl LocalDisk {
 
         disk.make_meta_volumes().await?;
 
-        let (exit_tx, exit_rx) = tokio::sync::broadcast::channel(1);
-        disk.exit_signal = Some(exit_tx);
+        // Drives opened without cleanup, e.g. read-only ones, keep their tmp files and trash
+        if cleanup {
+            let (exit_tx, exit_rx) = tokio::sync::broadcast::channel(1);
+            disk.exit_signal = Some(exit_tx);
 
-        let root = disk.root.clone();
-        tokio::spawn(Self::cleanup_deleted_objects_loop(root, exit_rx));
+            let root = disk.root.clone();
+            tokio::spawn(Self::cleanup_deleted_objects_loop(root, exit_rx));
+        }
         debug!("LocalDisk created: {:?}", disk);
         Ok(disk)
     }
 
+    /// Enables `O_DIRECT` writes in `create_file` if the drive supports them.
+    pub fn with_direct_io(mut self, enabled: bool) -> Self {
+        #[cfg(target_os = "linux")]
+        {
+            self.direct_io = enabled && super::direct_io::supports_direct_io(&self.root);
+            if enabled && !self.direct_io {
+                warn!("drive {:?} does not support O_DIRECT, using buffered writes", self.root);
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = enabled;
+        }
+
+        self
+    }
+
+    /// Limits how many files the disk keeps open at once, 0 for no limit.
+    /// Opens beyond the limit wait for an earlier reader or writer to be dropped.
+    pub fn with_max_open_files(mut self, max: usize) -> Self {
+        self.open_files = (max > 0).then(|| Arc::new(Semaphore::new(max)));
+        self
+    }
+
+    /// Rejects every call that would modify the drive, reads keep working. This also stops the
+    /// background cleanup of tmp files and trash.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        if read_only && let Some(exit_signal) = self.exit_signal.take() {
+            let _ = exit_signal.send(());
+        }
+        self
+    }
+
+    /// Makes `create_file` checksum the data as it is written, see `LocalFileWriter::with_checksum`.
+    pub fn with_write_checksum(mut self, algo: Option<HashAlgorithm>) -> Self {
+        self.write_checksum = algo;
+        self
+    }
+
+    fn new_file_writer<W>(&self, inner: W, path: PathBuf, file_size: i64) -> LocalFileWriter<W> {
+        let writer = LocalFileWriter::new(inner, path, file_size);
+        match &self.write_checksum {
+            Some(algo) => writer.with_checksum(algo.clone()),
+            None => writer,
+        }
+    }
+
+    /// Drops the checksum `create_file` recorded for `file_path`, once the file is gone or about to change.
+    async fn forget_write_checksum(&self, file_path: &Path) -> Result<()> {
+        match remove(write_checksum_path(file_path)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(to_file_error(e).into()),
+        }
+    }
+
+    /// Returns the checksum `create_file` recorded for the file at `file_path`, if there is one.
+    async fn read_write_checksum(&self, file_path: &Path) -> Result<Option<(HashAlgorithm, String)>> {
+        let buf = match fs::read(write_checksum_path(file_path)).await {
+            Ok(buf) => buf,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(to_file_error(e).into()),
+        };
+
+        Ok(recorded_write_checksum(&ObjectPartInfo::unmarshal(&buf)?))
+    }
+
+    fn check_writable(&self) -> Result<()> {
+        if self.read_only {
+            return Err(DiskError::DiskAccessDenied);
+        }
+        Ok(())
+    }
+
+    async fn acquire_open_file(&self) -> Option<OwnedSemaphorePermit> {
+        match &self.open_files {
+            Some(sem) => sem.clone().acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Removes what interrupted writes left in tmp once, then purges expired trash and unused
+    /// blobs every five minutes until the disk is dropped.
     async fn cleanup_deleted_objects_loop(root: PathBuf, mut exit_rx: tokio::sync::broadcast::Receiver<()>) {
+        // Writes interrupted by a crash leave their staged files behind. The trash inside the
+        // tmp bucket has its own retention.
+        let tmp = path_join(&[root.clone(), super::RUSTFS_META_TMP_BUCKET.into()]);
+        tokio::select! {
+            biased;
+            _ = exit_rx.recv() => return,
+            res = Self::purge_old_entries(tmp, TMP_RETENTION, &[".trash"]) => match res {
+                Ok(0) => {}
+                Ok(n) => info!("removed {} stale tmp entries from {:?}", n, root),
+                Err(err) => warn!("failed to clean up tmp entries of {:?}: {:?}", root, err),
+            },
+        }
+
         let mut interval = interval(Duration::from_secs(60 * 5));
         loop {
             tokio::select! {
@@ -269,55 +537,83 @@ impl LocalDisk {
     }
 
     async fn cleanup_deleted_objects(root: PathBuf) -> Result<()> {
-        let trash = path_join(&[root, RUSTFS_META_TMP_DELETED_BUCKET.into()]);
-        let mut entries = fs::read_dir(&trash).await?;
-        while let Some(entry) = entries.next_entry().await? {
-            let name = entry.file_name().to_string_lossy().to_string();
-            if name.is_empty() || name == "." || name == ".." {
-                continue;
-            }
+        let trash = path_join(&[root.clone(), RUSTFS_META_TMP_DELETED_BUCKET.into()]);
+        Self::purge_old_entries(trash, TRASH_RETENTION, &[]).await?;
+        let dedup = path_join(&[root, RUSTFS_META_DEDUP_BUCKET.into()]);
+        Self::purge_unlinked_blobs(dedup).await?;
+        Ok(())
+    }
 
-            let file_type = entry.file_type().await?;
+    /// Returns the metadata of the directory a root entry names, following symlinks so that a
+    /// symlinked volume is listed like any other. Non-directories, dangling or looping symlinks,
+    /// and symlinks back to the root or one of its ancestors yield `None`.
+    async fn volume_dir_metadata(&self, entry: &fs::DirEntry) -> Option<Metadata> {
+        let file_type = entry.file_type().await.ok()?;
+        if file_type.is_dir() {
+            return entry.metadata().await.ok();
+        }
+        if !file_type.is_symlink() {
+            return None;
+        }
 
-            let path = path_join(&[trash.clone(), name.into()]);
+        let path = entry.path();
+        // Symlink loops fail here with ELOOP
+        let meta = fs::metadata(&path).await.ok()?;
+        if !meta.is_dir() {
+            return None;
+        }
 
-            if file_type.is_dir() {
-                if let Err(e) = tokio::fs::remove_dir_all(path).await
-                    && e.kind() != ErrorKind::NotFound
-                {
-                    return Err(e.into());
-                }
-            } else if let Err(e) = tokio::fs::remove_file(path).await
-                && e.kind() != ErrorKind::NotFound
-            {
-                return Err(e.into());
-            }
+        let target = fs::canonicalize(&path).await.ok()?;
+        let root = fs::canonicalize(&self.root).await.ok()?;
+        if root.starts_with(&target) {
+            warn!("list_volumes: skipping {:?}, it links back to the disk root {:?}", path, target);
+            return None;
         }
 
-        Ok(())
+        Some(meta)
     }
 
+    /// Volume names start with a component of 3 to 63 bytes that doesn't start with a dot, or with
+    /// `.rustfs.sys`. Healing makes `bucket/object` volumes, so further components are allowed as
+    /// long as they stay inside the volume.
     fn is_valid_volname(volname: &str) -> bool {
-        if volname.len() < 3 {
+        let (volname, rest) = match volname.split_once(SLASH_SEPARATOR_STR) {
+            Some((first, rest)) => (first, Some(rest.strip_suffix(SLASH_SEPARATOR_STR).unwrap_or(rest))),
+            None => (volname, None),
+        };
+
+        if let Some(rest) = rest
+            && !rest
+                .split(SLASH_SEPARATOR_STR)
+                .all(|part| !part.is_empty() && part != "." && part != ".." && !part.contains('\\'))
+        {
+            return false;
+        }
+
+        if volname == RUSTFS_META_BUCKET {
+            return true;
+        }
+
+        if volname.len() < 3 || volname.len() > 63 || volname.starts_with('.') || volname.contains('\\') {
             return false;
         }
 
         if cfg!(target_os = "windows") {
             // Windows volume names must not include reserved characters.
-            // This regular expression matches disallowed characters.
-            if volname.contains('|')
-                || volname.contains('<')
-                || volname.contains('>')
-                || volname.contains('?')
-                || volname.contains('*')
-                || volname.contains(':')
-                || volname.contains('"')
-                || volname.contains('\\')
-            {
+            if volname.contains(['|', '<', '>', '?', '*', ':', '"']) {
+                return false;
+            }
+
+            // Nor be a reserved device name, with or without an extension
+            const RESERVED: [&str; 4] = ["CON", "PRN", "AUX", "NUL"];
+            let stem = volname.split('.').next().unwrap_or_default().to_ascii_uppercase();
+            let is_port = (stem.starts_with("COM") || stem.starts_with("LPT"))
+                && stem.len() == 4
+                && stem.as_bytes()[3].is_ascii_digit()
+                && stem.as_bytes()[3] != b'0';
+            if RESERVED.contains(&stem.as_str()) || is_port {
                 return false;
             }
-        } else {
-            // Non-Windows systems may require additional validation rules.
         }
 
         true
@@ -367,6 +663,9 @@ impl LocalDisk {
 
         // Normalize path components to avoid filesystem calls
         let normalized = normalize_path_components(abs_path.as_path());
+        if !normalized.starts_with(&self.root) {
+            return Err(DiskError::FileAccessDenied);
+        }
 
         // Cache the result
         {
@@ -389,33 +688,51 @@ impl LocalDisk {
 
     // Get the absolute path of an object
     pub fn get_object_path(&self, bucket: &str, key: &str) -> Result<PathBuf> {
-        // For high-frequency paths, use faster string concatenation
-        let cache_key = if key.is_empty() {
-            bucket.to_string()
-        } else {
-            path_join_buf(&[bucket, key])
-        };
-
-        let path = self.root.join(cache_key);
-        self.check_valid_path(&path)?;
-        Ok(path)
+        self.safe_join(bucket, key)
     }
 
     // Get the absolute path of a bucket
     pub fn get_bucket_path(&self, bucket: &str) -> Result<PathBuf> {
-        let bucket_path = self.root.join(bucket);
-        self.check_valid_path(&bucket_path)?;
-        Ok(bucket_path)
+        self.safe_join(bucket, "")
     }
 
-    // Check if a path is valid
-    fn check_valid_path<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let path = normalize_path_components(path);
-        if path.starts_with(&self.root) {
-            Ok(())
-        } else {
-            Err(DiskError::InvalidPath)
+    /// Joins `volume` and `path` onto the disk root without touching the filesystem.
+    ///
+    /// `.` and `..` are resolved lexically. Absolute components, and `..` that would climb out
+    /// of the root or, for `path`, out of `volume`, are rejected with `FileAccessDenied`.
+    /// A trailing slash on the last non-empty argument is kept, since it marks directory objects.
+    pub fn safe_join(&self, volume: &str, path: &str) -> Result<PathBuf> {
+        let mut joined = self.root.clone();
+        let mut depth = 0usize;
+        let mut floor = 0usize;
+
+        for part in [volume, path] {
+            for component in Path::new(part).components() {
+                match component {
+                    Component::Normal(name) => {
+                        joined.push(name);
+                        depth += 1;
+                    }
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        if depth == floor {
+                            return Err(DiskError::FileAccessDenied);
+                        }
+                        joined.pop();
+                        depth -= 1;
+                    }
+                    Component::RootDir | Component::Prefix(_) => return Err(DiskError::FileAccessDenied),
+                }
+            }
+            floor = depth;
         }
+
+        let last = if path.is_empty() { volume } else { path };
+        if last.ends_with(SLASH_SEPARATOR_STR) && depth > 0 {
+            joined.as_mut_os_string().push(SLASH_SEPARATOR_STR);
+        }
+
+        Ok(joined)
     }
 
     // Batch path generation with single lock acquisition
@@ -439,8 +756,8 @@ impl LocalDisk {
         // Handle cache misses
         if !cache_misses.is_empty() {
             let mut new_entries = Vec::new();
-            for (i, _bucket, _key, cache_key) in cache_misses {
-                let path = self.root.join(&cache_key);
+            for (i, bucket, key, cache_key) in cache_misses {
+                let path = self.safe_join(bucket, key)?;
                 results.push((i, path.clone()));
                 new_entries.push((cache_key, path));
             }
@@ -490,7 +807,7 @@ impl LocalDisk {
             version_id,
             FileInfoOpts {
                 data: opts.read_data,
-                include_free_versions: false,
+                include_free_versions: opts.incl_free_versions,
             },
         )
         .map_err(|_e| DiskError::Unexpected)
@@ -526,60 +843,316 @@ impl LocalDisk {
     //     })
     // }
 
+    /// Returns a fresh trash location for `delete_path`. Each deletion gets its own directory, whose
+    /// modification time records when it was trashed, holding the entry under its original name.
+    fn trash_path_for(&self, delete_path: &Path) -> Result<PathBuf> {
+        let name = delete_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "entry".to_string());
+        self.get_object_path(RUSTFS_META_TMP_DELETED_BUCKET, format!("{}/{name}", Uuid::new_v4()).as_str())
+    }
+
+    /// Moves `delete_path` into the trash, or unlinks it right away when `immediate_purge` is set.
     async fn move_to_trash(&self, delete_path: &PathBuf, recursive: bool, immediate_purge: bool) -> Result<()> {
-        // if recursive {
-        //     remove_all_std(delete_path).map_err(to_volume_error)?;
-        // } else {
-        //     remove_std(delete_path).map_err(to_file_error)?;
-        // }
+        if immediate_purge {
+            let res = if recursive {
+                remove_all(delete_path).await
+            } else {
+                remove(delete_path).await
+            };
+            if delete_path.to_string_lossy().ends_with(SLASH_SEPARATOR_STR) {
+                let _ = remove_all(encode_dir_object(delete_path.to_string_lossy().as_ref())).await;
+            }
 
-        // return Ok(());
+            return match res {
+                Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+                Err(e) if recursive => Err(to_volume_error(e).into()),
+                Err(e) => Err(to_file_error(e).into()),
+                Ok(()) => Ok(()),
+            };
+        }
 
         // TODO: async notifications for disk space checks and trash cleanup
 
-        let trash_path = self.get_object_path(RUSTFS_META_TMP_DELETED_BUCKET, Uuid::new_v4().to_string().as_str())?;
-        // if let Some(parent) = trash_path.parent() {
-        //     if !parent.exists() {
-        //         fs::create_dir_all(parent).await?;
-        //     }
-        // }
+        let trash_dir = self.get_bucket_path(RUSTFS_META_TMP_DELETED_BUCKET)?;
+        let err = rename_all(delete_path, self.trash_path_for(delete_path)?, &trash_dir)
+            .await
+            .err();
 
-        let err = if recursive {
-            rename_all(delete_path, trash_path, self.get_bucket_path(RUSTFS_META_TMP_DELETED_BUCKET)?)
-                .await
-                .err()
-        } else {
-            rename(&delete_path, &trash_path)
-                .await
-                .map_err(|e| to_file_error(e).into())
-                .err()
+        if delete_path.to_string_lossy().ends_with(SLASH_SEPARATOR_STR) {
+            let dir_object = PathBuf::from(encode_dir_object(delete_path.to_string_lossy().as_ref()));
+            let _ = rename_all(&dir_object, self.trash_path_for(&dir_object)?, &trash_dir).await;
+        }
+
+        if let Some(err) = err
+            && err == Error::DiskFull
+        {
+            if recursive {
+                remove_all_std(delete_path).map_err(to_volume_error)?;
+            } else {
+                remove_std(delete_path).map_err(to_file_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Permanently removes trash entries that were moved there at least `older_than` ago.
+    /// Returns the number of entries removed.
+    pub async fn purge_trash(&self, older_than: Duration) -> Result<usize> {
+        Self::purge_old_entries(self.get_bucket_path(RUSTFS_META_TMP_DELETED_BUCKET)?, older_than, &[]).await
+    }
+
+    /// Removes the entries of `dir` last modified at least `older_than` ago, except those named
+    /// in `keep`. Returns the number of entries removed.
+    async fn purge_old_entries(dir: PathBuf, older_than: Duration, keep: &[&str]) -> Result<usize> {
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
         };
 
-        if immediate_purge || delete_path.to_string_lossy().ends_with(SLASH_SEPARATOR_STR) {
-            let trash_path2 = self.get_object_path(RUSTFS_META_TMP_DELETED_BUCKET, Uuid::new_v4().to_string().as_str())?;
-            let _ = rename_all(
-                encode_dir_object(delete_path.to_string_lossy().as_ref()),
-                trash_path2,
-                self.get_bucket_path(RUSTFS_META_TMP_DELETED_BUCKET)?,
-            )
-            .await;
+        let mut purged = 0;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.is_empty() || name == "." || name == ".." || keep.contains(&name.as_str()) {
+                continue;
+            }
+
+            let meta = match entry.metadata().await {
+                Ok(meta) => meta,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let age = meta.modified().ok().and_then(|t| t.elapsed().ok()).unwrap_or_default();
+            if age < older_than {
+                continue;
+            }
+
+            let res = if meta.is_dir() {
+                fs::remove_dir_all(entry.path()).await
+            } else {
+                fs::remove_file(entry.path()).await
+            };
+            match res {
+                Ok(()) => purged += 1,
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
         }
 
-        if let Some(err) = err {
-            if err == Error::DiskFull {
-                if recursive {
-                    remove_all_std(delete_path).map_err(to_volume_error)?;
-                } else {
-                    remove_std(delete_path).map_err(to_file_error)?;
+        Ok(purged)
+    }
+
+    /// Walks `volume` and sums the size of its regular files, counting one object per `xl.meta`
+    /// and the versions it records. Directories named `.rustfs.sys` are skipped, so an empty
+    /// `volume` reports the usage of every bucket on the drive.
+    ///
+    /// The walk yields to the runtime before each directory, so dropping the future (for
+    /// example from a `select!` on a cancellation token) stops it promptly.
+    pub async fn volume_usage(&self, volume: &str) -> Result<VolumeUsage> {
+        let volume_dir = self.get_bucket_path(volume)?;
+        if let Err(e) = access(&volume_dir).await {
+            return Err(to_access_error(e, DiskError::VolumeAccessDenied).into());
+        }
+
+        let mut usage = VolumeUsage::default();
+        let mut dirs = vec![volume_dir];
+
+        while let Some(dir) = dirs.pop() {
+            tokio::task::yield_now().await;
+
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(to_file_error(e).into()),
+            };
+
+            while let Some(entry) = entries.next_entry().await? {
+                let name = entry.file_name();
+                if name == RUSTFS_META_BUCKET {
+                    continue;
+                }
+
+                // Entries may vanish while the walk is running
+                let meta = match entry.metadata().await {
+                    Ok(meta) => meta,
+                    Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                    Err(e) => return Err(to_file_error(e).into()),
+                };
+
+                if meta.is_dir() {
+                    dirs.push(entry.path());
+                    continue;
+                }
+                if !meta.is_file() {
+                    continue;
+                }
+
+                usage.bytes += meta.len();
+
+                if name == STORAGE_FORMAT_FILE {
+                    usage.objects += 1;
+                    match self.read_metadata_with_dmtime(entry.path()).await {
+                        Ok((buf, _)) => match FileMeta::load(&buf) {
+                            Ok(fm) => usage.versions += fm.versions.len() as u64,
+                            Err(e) => warn!("volume_usage: unreadable {:?}: {:?}", entry.path(), e),
+                        },
+                        Err(DiskError::FileNotFound) => {}
+                        Err(e) => return Err(e),
+                    }
                 }
             }
+        }
 
-            return Ok(());
+        Ok(usage)
+    }
+
+    /// Scans the volume named by `cache.info.name` and returns a fresh usage cache with one
+    /// entry per top-level prefix, each a child of the volume root. The flattened root is sent
+    /// over `updates` after every prefix.
+    ///
+    /// Unless `cache.info.skip_healing` is set or the mode is `HealScanMode::Unknown`, the latest
+    /// version of every object is checked with `check_parts`, or bitrot-verified with
+    /// `verify_file` in `HealScanMode::Deep`, and objects that need healing are logged.
+    pub async fn ns_scanner(
+        &self,
+        cache: &DataUsageCache,
+        updates: Option<Sender<DataUsageEntry>>,
+        scan_mode: HealScanMode,
+        we_sleep: ShouldSleepFn,
+    ) -> Result<DataUsageCache> {
+        let volume = cache.info.name.clone();
+        let volume_dir = self.get_bucket_path(&volume)?;
+        let mut entries = fs::read_dir(&volume_dir).await.map_err(to_volume_error)?;
+
+        let mut names = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        names.sort();
+
+        let heal_mode = (!cache.info.skip_healing && scan_mode != HealScanMode::Unknown).then_some(scan_mode);
+
+        let mut new_cache = DataUsageCache {
+            info: cache.info.clone(),
+            ..Default::default()
+        };
+        new_cache.info.last_update = Some(SystemTime::now());
+        new_cache.replace(&volume, "", DataUsageEntry::default());
+
+        for name in names {
+            let mut entry = DataUsageEntry::default();
+            self.scan_usage(&volume, &name, &mut entry, heal_mode, &we_sleep).await?;
+            new_cache.replace(&format!("{volume}/{name}"), &volume, entry);
+
+            if let Some(tx) = &updates
+                && let Some(root) = new_cache.root()
+            {
+                let _ = tx.send(new_cache.flatten(&root)).await;
+            }
+        }
+
+        Ok(new_cache)
+    }
+
+    #[async_recursion::async_recursion]
+    async fn scan_usage(
+        &self,
+        volume: &str,
+        path: &str,
+        entry: &mut DataUsageEntry,
+        heal_mode: Option<HealScanMode>,
+        we_sleep: &ShouldSleepFn,
+    ) -> Result<()> {
+        let dir = self.get_object_path(volume, path)?;
+
+        match fs::read(dir.join(STORAGE_FORMAT_FILE)).await {
+            Ok(buf) => {
+                if let Some(should_sleep) = we_sleep
+                    && should_sleep()
+                {
+                    tokio::time::sleep(SCANNER_SLEEP).await;
+                }
+
+                self.scan_object(volume, path, &buf, entry, heal_mode).await;
+                return Ok(());
+            }
+            Err(e) if matches!(e.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => {}
+            Err(e) => return Err(to_file_error(e).into()),
+        }
+
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if matches!(e.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => return Ok(()),
+            Err(e) => return Err(to_file_error(e).into()),
+        };
+
+        while let Some(child) = entries.next_entry().await? {
+            if child.file_type().await?.is_dir() {
+                let name = child.file_name().to_string_lossy().to_string();
+                self.scan_usage(volume, &format!("{path}/{name}"), entry, heal_mode, we_sleep)
+                    .await?;
+            }
         }
 
         Ok(())
     }
 
+    async fn scan_object(
+        &self,
+        volume: &str,
+        path: &str,
+        buf: &[u8],
+        entry: &mut DataUsageEntry,
+        heal_mode: Option<HealScanMode>,
+    ) {
+        let meta = match FileMeta::load(buf) {
+            Ok(meta) => meta,
+            Err(e) => {
+                warn!("ns_scanner: skipping {}/{}: {}", volume, path, e);
+                return;
+            }
+        };
+
+        let mut summary = SizeSummary::default();
+        for version in meta.versions.iter() {
+            let Ok(ver) = FileMetaVersion::try_from(version.clone()) else {
+                continue;
+            };
+            if let Some(obj) = ver.object {
+                summary.total_size += obj.size.max(0) as usize;
+                summary.versions += 1;
+            } else if ver.delete_marker.is_some() {
+                summary.delete_markers += 1;
+            }
+        }
+
+        entry.add_sizes(&summary);
+        entry.objects += 1;
+
+        let Some(mode) = heal_mode else {
+            return;
+        };
+
+        let fi = match meta.into_fileinfo(volume, path, "", false, false, true) {
+            Ok(fi) if !fi.deleted && !fi.inline_data() => fi,
+            _ => return,
+        };
+
+        let res = if mode == HealScanMode::Deep {
+            self.verify_file(volume, path, &fi).await
+        } else {
+            self.check_parts(volume, path, &fi).await
+        };
+        match res {
+            Ok(resp) if resp.results.iter().all(|&r| r == CHECK_PART_SUCCESS) => {}
+            Ok(resp) => warn!("ns_scanner: {}/{} needs healing, part results {:?}", volume, path, resp.results),
+            Err(e) => warn!("ns_scanner: failed to check {}/{}: {}", volume, path, e),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     #[async_recursion::async_recursion]
     async fn delete_file(
@@ -601,7 +1174,7 @@ impl LocalDisk {
             return Ok(());
         }
 
-        if recursive {
+        if recursive || (!immediate_purge && delete_path.is_file()) {
             self.move_to_trash(delete_path, recursive, immediate_purge).await?;
         } else if delete_path.is_dir() {
             // debug!("delete_file remove_dir {:?}", &delete_path);
@@ -642,7 +1215,38 @@ impl LocalDisk {
         Ok(())
     }
 
-    /// read xl.meta raw data
+    /// Rolls back a write of the object at `file_path`: the data dirs the failed write added are
+    /// trashed and the xl.meta that rename_data preserved in `old_data_dir` is put back.
+    async fn undo_write(&self, volume: &str, volume_dir: &Path, file_path: &Path, old_data_dir: Uuid) -> Result<()> {
+        let xl_path = file_path.join(STORAGE_FORMAT_FILE);
+        let backup_path = file_path.join(old_data_dir.to_string()).join(STORAGE_FORMAT_FILE_BACKUP);
+
+        let backup = FileMeta::load(&self.read_all_data(volume, volume_dir, &backup_path).await?)?;
+        let keep = backup.get_data_dirs()?;
+
+        let current = match self.read_all_data(volume, volume_dir, &xl_path).await {
+            Ok(buf) => FileMeta::load(&buf)?.get_data_dirs()?,
+            Err(DiskError::FileNotFound) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+
+        for data_dir in current.into_iter().flatten() {
+            if data_dir == old_data_dir || keep.contains(&Some(data_dir)) {
+                continue;
+            }
+
+            let data_path = file_path.join(data_dir.to_string());
+            if let Err(err) = self.move_to_trash(&data_path, true, false).await
+                && err != DiskError::FileNotFound
+            {
+                return Err(err);
+            }
+        }
+
+        rename_all(&backup_path, &xl_path, file_path).await
+    }
+
+    /// read xl.meta raw data
     #[tracing::instrument(level = "debug", skip(self, volume_dir, file_path))]
     async fn read_raw(
         &self,
@@ -727,6 +1331,34 @@ impl LocalDisk {
         Ok((data, modtime))
     }
 
+    /// Lists the files under `bucket/prefix` whose path relative to the prefix matches `pattern`,
+    /// in lexical order. A missing prefix yields no files.
+    async fn expand_read_multiple_pattern(&self, bucket: &str, prefix: &str, pattern: &str) -> Result<Vec<String>> {
+        let root = self.get_object_path(bucket, prefix)?;
+        let mut files = Vec::new();
+        let mut dirs = vec![String::new()];
+
+        while let Some(dir) = dirs.pop() {
+            let entries = match os::read_dir(root.join(&dir), -1).await {
+                Ok(entries) => entries,
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => return Err(to_file_error(err).into()),
+            };
+
+            for entry in entries {
+                let name = format!("{dir}{entry}");
+                if name.ends_with(SLASH_SEPARATOR_STR) {
+                    dirs.push(name);
+                } else if match_simple(pattern, &name) {
+                    files.push(name);
+                }
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
     async fn read_all_data(&self, volume: &str, volume_dir: impl AsRef<Path>, file_path: impl AsRef<Path>) -> Result<Vec<u8>> {
         // TODO: timeout support
         let (data, _) = self.read_all_data_with_dmtime(volume, volume_dir, file_path).await?;
@@ -818,6 +1450,8 @@ impl LocalDisk {
 
         // Remove xl.meta when no versions remain
         if fm.versions.is_empty() {
+            let backup_path = self.get_object_path(volume, format!("{path}/{STORAGE_FORMAT_FILE_BACKUP}").as_str())?;
+            self.delete_file(&volume_dir, &backup_path, false, false).await?;
             self.delete_file(&volume_dir, &xlpath, true, false).await?;
             return Ok(());
         }
@@ -825,51 +1459,352 @@ impl LocalDisk {
         // Update xl.meta
         let buf = fm.marshal_msg()?;
 
-        let volume_dir = self.get_bucket_path(volume)?;
-
-        self.write_all_private(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str(), buf.into(), true, &volume_dir)
+        self.write_all_meta(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str(), &buf, true)
             .await?;
 
         Ok(())
     }
 
+    /// Returns a new, uniquely named path in the meta tmp bucket to stage a write in.
+    ///
+    /// The tmp bucket is on the same drive as every volume, so a staged file can be renamed
+    /// into place atomically. Leftovers from interrupted writes are removed when the disk opens.
+    fn tmp_file_path(&self) -> Result<PathBuf> {
+        self.get_object_path(super::RUSTFS_META_TMP_BUCKET, Uuid::new_v4().to_string().as_str())
+    }
+
+    /// Writes `buf` to a tmp file and renames it over `volume`/`path`, so readers see either
+    /// the old or the new content.
     async fn write_all_meta(&self, volume: &str, path: &str, buf: &[u8], sync: bool) -> Result<()> {
         let volume_dir = self.get_bucket_path(volume)?;
         let file_path = self.get_object_path(volume, path)?;
         check_path_length(file_path.to_string_lossy().as_ref())?;
 
         let tmp_volume_dir = self.get_bucket_path(super::RUSTFS_META_TMP_BUCKET)?;
-        let tmp_file_path = self.get_object_path(super::RUSTFS_META_TMP_BUCKET, Uuid::new_v4().to_string().as_str())?;
+        let tmp_file_path = self.tmp_file_path()?;
 
-        self.write_all_internal(&tmp_file_path, InternalBuf::Ref(buf), sync, &tmp_volume_dir)
-            .await?;
+        if let Err(err) = self
+            .write_all_internal(&tmp_file_path, InternalBuf::Ref(buf), sync, &tmp_volume_dir)
+            .await
+        {
+            let _ = remove(&tmp_file_path).await;
+            return Err(err);
+        }
+
+        if let Err(err) = rename_all(&tmp_file_path, file_path, volume_dir).await {
+            let _ = remove(&tmp_file_path).await;
+            return Err(err);
+        }
 
-        rename_all(tmp_file_path, file_path, volume_dir).await
+        Ok(())
     }
 
-    // write_all_public for trail
-    async fn write_all_public(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+    /// Writes each `(path, data)` entry under `volume`, then fsyncs every directory that received
+    /// a file once, instead of paying for a sync per directory entry. Meant for many small files
+    /// such as metadata.
+    ///
+    /// Every file is staged in tmp, synced and renamed into place, so a crash leaves either the old
+    /// or the new content. Returns one result per entry, in order. When a directory fails to sync,
+    /// every entry written into it fails with that error.
+    pub async fn write_all_batch(&self, volume: &str, entries: Vec<(String, Bytes)>) -> Result<Vec<Result<()>>> {
+        self.check_writable()?;
+        let volume_dir = self.get_bucket_path(volume)?;
+
+        let mut results = Vec::with_capacity(entries.len());
+        let mut dirs: HashMap<PathBuf, Vec<usize>> = HashMap::new();
+        for (idx, (path, data)) in entries.into_iter().enumerate() {
+            match self.write_all_staged(volume, &volume_dir, &path, data).await {
+                Ok(file_path) => {
+                    if let Some(parent) = file_path.parent() {
+                        dirs.entry(parent.to_path_buf()).or_default().push(idx);
+                    }
+                    results.push(Ok(()));
+                }
+                Err(e) => results.push(Err(e)),
+            }
+        }
+
+        for (dir, written) in dirs {
+            if let Err(e) = sync_dir(&dir).await {
+                let err: DiskError = to_file_error(e).into();
+                for idx in written {
+                    results[idx] = Err(err.clone());
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Writes `data` to a tmp file, syncs its data and renames it over `volume`/`path`, leaving
+    /// the sync of the target directory to the caller. Returns the path written.
+    async fn write_all_staged(&self, volume: &str, volume_dir: &Path, path: &str, data: Bytes) -> Result<PathBuf> {
+        let file_path = self.get_object_path(volume, path)?;
+        check_path_length(file_path.to_string_lossy().as_ref())?;
+
+        let tmp_volume_dir = self.get_bucket_path(super::RUSTFS_META_TMP_BUCKET)?;
+        let tmp_file_path = self.tmp_file_path()?;
+        let staged = async {
+            let mut f = self
+                .open_file(&tmp_file_path, O_CREATE | O_WRONLY | O_TRUNC, &tmp_volume_dir)
+                .await?;
+            f.write_all(&data).await.map_err(to_file_error)?;
+            f.sync_data().await.map_err(to_file_error)?;
+            rename_all(&tmp_file_path, &file_path, volume_dir).await
+        };
+        if let Err(err) = staged.await {
+            let _ = remove(&tmp_file_path).await;
+            return Err(err);
+        }
+
         if volume == RUSTFS_META_BUCKET && path == super::FORMAT_CONFIG_FILE {
-            let mut format_info = self.format_info.write().await;
-            format_info.data.clone_from(&data);
+            self.format_info.write().await.data = data;
         }
 
+        Ok(file_path)
+    }
+
+    /// Hard-links `volume`/`path` to the blob stored for `content_key`, a digest from
+    /// [`rustfs_utils::content_key`], so identical content is kept once on this disk.
+    ///
+    /// Returns false and leaves `path` alone when no blob is known for the key, or when it sits on
+    /// another filesystem. The caller then writes the data itself and can `remember_content` it.
+    pub async fn link_if_exists(&self, volume: &str, path: &str, content_key: &str) -> Result<bool> {
+        self.check_writable()?;
+        let blob_path = self.content_blob_path(content_key)?;
         let volume_dir = self.get_bucket_path(volume)?;
+        let file_path = self.get_object_path(volume, path)?;
+        check_path_length(file_path.to_string_lossy().as_ref())?;
 
-        self.write_all_private(volume, path, data, true, &volume_dir).await?;
+        // Link into tmp first and rename over the target, so `path` never appears half done
+        let tmp_file_path = self.tmp_file_path()?;
+        match fs::hard_link(&blob_path, &tmp_file_path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::NotFound || e.kind() == ErrorKind::CrossesDevices => return Ok(false),
+            Err(e) => return Err(to_file_error(e).into()),
+        }
 
-        Ok(())
+        if let Err(e) = rename_all(&tmp_file_path, &file_path, &volume_dir).await {
+            let _ = fs::remove_file(&tmp_file_path).await;
+            return Err(e);
+        }
+
+        Ok(true)
     }
 
-    // write_all_private with check_path_length
-    #[tracing::instrument(level = "debug", skip_all)]
-    async fn write_all_private(&self, volume: &str, path: &str, buf: Bytes, sync: bool, skip_parent: &Path) -> Result<()> {
+    /// Records the file at `volume`/`path` as the blob for `content_key`, for `link_if_exists`.
+    ///
+    /// The blob is another hard link to the file, so it outlives the object. Blobs whose link
+    /// count dropped to one are only referenced by the index and are removed by `purge_unused_blobs`,
+    /// which the background cleanup runs. Writes that change a linked file in place unlink it first.
+    pub async fn remember_content(&self, volume: &str, path: &str, content_key: &str) -> Result<()> {
+        self.check_writable()?;
+        let blob_path = self.content_blob_path(content_key)?;
         let file_path = self.get_object_path(volume, path)?;
-        check_path_length(file_path.to_string_lossy().as_ref())?;
 
-        self.write_all_internal(&file_path, InternalBuf::Owned(buf), sync, skip_parent)
-            .await
+        if let Some(parent) = blob_path.parent() {
+            fs::create_dir_all(parent).await.map_err(to_file_error)?;
+        }
+
+        match fs::hard_link(&file_path, &blob_path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists || e.kind() == ErrorKind::CrossesDevices => Ok(()),
+            Err(e) => Err(to_file_error(e).into()),
+        }
+    }
+
+    /// Creates every volume in `volumes` and returns one result per volume, in order. Volumes that
+    /// already exist count as made. Nothing is created when a name is invalid.
+    pub async fn make_volumes_each(&self, volumes: Vec<&str>) -> Result<Vec<Result<()>>> {
+        self.check_writable()?;
+
+        if !volumes.iter().all(|vol| Self::is_valid_volname(vol)) {
+            return Err(DiskError::VolumeAccessDenied);
+        }
+
+        Ok(stream::iter(volumes)
+            .map(|vol| async move {
+                match self.make_volume_strict(vol).await {
+                    Err(DiskError::VolumeExists) => Ok(()),
+                    res => res,
+                }
+            })
+            .buffered(MAKE_VOLUMES_CONCURRENCY)
+            .collect()
+            .await)
+    }
+
+    /// Creates `volume`, failing with `VolumeExists` when it is already there.
+    ///
+    /// The directory is created with a single `mkdir`, so of several concurrent calls exactly one
+    /// succeeds. This is what `make_volume` does, as make_bucket relies on `VolumeExists`; use
+    /// `make_volumes` to create volumes that may already exist.
+    pub async fn make_volume_strict(&self, volume: &str) -> Result<()> {
+        self.check_writable()?;
+
+        if !Self::is_valid_volname(volume) {
+            return Err(DiskError::VolumeAccessDenied);
+        }
+
+        let volume_dir = self.get_bucket_path(volume)?;
+        if let Some(parent) = volume_dir.parent()
+            && parent != self.root
+        {
+            os::make_dir_all(parent, self.root.as_path()).await?;
+        }
+
+        match fs::create_dir(&volume_dir).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => Err(DiskError::VolumeExists),
+            Err(e) => {
+                error!("local disk make volume failed: {e}");
+                Err(to_volume_error(e).into())
+            }
+        }
+    }
+
+    /// Primes the page cache with the start of each `(volume, path)` file, e.g. `format.json` and
+    /// the xl.meta of hot objects, so the first requests on a cold node do not all hit the drive.
+    ///
+    /// This is best effort: files that are missing or unreadable are skipped. Returns how many
+    /// files were read, or `DiskNotFound` when the drive itself is gone.
+    pub async fn warm_up(&self, paths: &[(&str, &str)]) -> Result<usize> {
+        access(&self.root).await.map_err(|_| DiskError::DiskNotFound)?;
+
+        // Each open counts against the open file limit like any other read
+        let mut results = stream::iter(paths)
+            .map(|(volume, path)| async move {
+                let res = async {
+                    let file_path = self.get_object_path(volume, path)?;
+                    let _permit = self.acquire_open_file().await;
+                    let f = super::fs::open_file(&file_path, O_RDONLY).await.map_err(to_file_error)?;
+                    let mut buf = Vec::new();
+                    f.take(WARM_UP_READ_SIZE).read_to_end(&mut buf).await?;
+                    Ok::<_, DiskError>(())
+                }
+                .await;
+                (res, volume, path)
+            })
+            .buffer_unordered(WARM_UP_CONCURRENCY);
+
+        let mut warmed = 0;
+        while let Some((res, volume, path)) = results.next().await {
+            match res {
+                Ok(()) => warmed += 1,
+                Err(err) => debug!("warm_up skipped {}/{}: {:?}", volume, path, err),
+            }
+        }
+
+        Ok(warmed)
+    }
+
+    fn content_blob_path(&self, content_key: &str) -> Result<PathBuf> {
+        if content_key.len() < 2 || !content_key.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(DiskError::InvalidPath);
+        }
+
+        self.get_object_path(RUSTFS_META_DEDUP_BUCKET, &format!("{}/{}", &content_key[..2], content_key))
+    }
+
+    /// Detaches `file_path` from the inode it shares with deduplicated copies and their blob (see
+    /// `link_if_exists`) before it is changed in place, so the other links keep their content.
+    ///
+    /// With `keep_data` the file is replaced by a private copy made in tmp, otherwise it is just
+    /// unlinked because the caller rewrites it from scratch. Files without other links are left alone.
+    async fn unshare_inode(&self, file_path: &Path, keep_data: bool) -> Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let meta = match fs::symlink_metadata(file_path).await {
+                Ok(meta) => meta,
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+                Err(e) => return Err(to_file_error(e).into()),
+            };
+            if !meta.is_file() || meta.nlink() <= 1 {
+                return Ok(());
+            }
+
+            if !keep_data {
+                return match fs::remove_file(file_path).await {
+                    Ok(()) => Ok(()),
+                    Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+                    Err(e) => Err(to_file_error(e).into()),
+                };
+            }
+
+            let tmp_file_path = self.tmp_file_path()?;
+            let res = match fs::copy(file_path, &tmp_file_path).await {
+                Ok(_) => fs::rename(&tmp_file_path, file_path).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = res {
+                let _ = fs::remove_file(&tmp_file_path).await;
+                return Err(to_file_error(e).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the dedup blobs no object links to anymore, those whose only link is the blob
+    /// itself. Returns the number of blobs removed.
+    pub async fn purge_unused_blobs(&self) -> Result<usize> {
+        self.check_writable()?;
+        Self::purge_unlinked_blobs(self.get_bucket_path(RUSTFS_META_DEDUP_BUCKET)?).await
+    }
+
+    async fn purge_unlinked_blobs(dedup_dir: PathBuf) -> Result<usize> {
+        let mut purged = 0;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let mut prefixes = match fs::read_dir(&dedup_dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+                Err(e) => return Err(e.into()),
+            };
+            while let Some(prefix) = prefixes.next_entry().await? {
+                let mut blobs = match fs::read_dir(prefix.path()).await {
+                    Ok(entries) => entries,
+                    Err(e) if e.kind() == ErrorKind::NotFound || e.kind() == ErrorKind::NotADirectory => continue,
+                    Err(e) => return Err(e.into()),
+                };
+                while let Some(blob) = blobs.next_entry().await? {
+                    let meta = match fs::symlink_metadata(blob.path()).await {
+                        Ok(meta) => meta,
+                        Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                        Err(e) => return Err(e.into()),
+                    };
+                    // A blob linked again after this check keeps its content in the new link
+                    if !meta.is_file() || meta.nlink() > 1 {
+                        continue;
+                    }
+                    match fs::remove_file(blob.path()).await {
+                        Ok(()) => purged += 1,
+                        Err(e) if e.kind() == ErrorKind::NotFound => {}
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = dedup_dir;
+
+        Ok(purged)
+    }
+
+    // write_all_public for trail
+    async fn write_all_public(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+        if volume == RUSTFS_META_BUCKET && path == super::FORMAT_CONFIG_FILE {
+            let mut format_info = self.format_info.write().await;
+            format_info.data.clone_from(&data);
+        }
+
+        self.write_all_meta(volume, path, &data, true).await
     }
+
     // write_all_internal do write file
     async fn write_all_internal(&self, file_path: &Path, data: InternalBuf<'_>, sync: bool, skip_parent: &Path) -> Result<()> {
         let flags = O_CREATE | O_WRONLY | O_TRUNC;
@@ -980,7 +1915,7 @@ impl LocalDisk {
             // }
         };
 
-        if opts.limit > 0 && *objs_returned >= opts.limit {
+        if opts.is_cancelled() || (opts.limit > 0 && *objs_returned >= opts.limit) || opts.skips(&current) {
             return Ok(());
         }
 
@@ -1024,6 +1959,11 @@ impl LocalDisk {
                 continue;
             }
 
+            if opts.skips(&path_join_buf(&[current.as_str(), entry.as_str()])) {
+                *item = "".to_owned();
+                continue;
+            }
+
             if let Some(forward) = &forward
                 && &entry < forward
             {
@@ -1088,7 +2028,7 @@ impl LocalDisk {
         prefix = "".to_owned();
 
         for entry in entries.iter() {
-            if opts.limit > 0 && *objs_returned >= opts.limit {
+            if opts.is_cancelled() || (opts.limit > 0 && *objs_returned >= opts.limit) {
                 return Ok(());
             }
 
@@ -1164,7 +2104,7 @@ impl LocalDisk {
         }
 
         while let Some(dir) = dir_stack.pop() {
-            if opts.limit > 0 && *objs_returned >= opts.limit {
+            if opts.is_cancelled() || (opts.limit > 0 && *objs_returned >= opts.limit) {
                 return Ok(());
             }
 
@@ -1189,6 +2129,11 @@ fn is_root_path(path: impl AsRef<Path>) -> bool {
     path.as_ref().components().count() == 1 && path.as_ref().has_root()
 }
 
+// Birth time when the filesystem reports it, otherwise the last modification time
+fn volume_created(meta: &Metadata) -> Option<OffsetDateTime> {
+    meta.created().or_else(|_| meta.modified()).ok().map(OffsetDateTime::from)
+}
+
 // Filter std::io::ErrorKind::NotFound
 async fn read_file_exists(path: impl AsRef<Path>) -> Result<(Bytes, Option<Metadata>)> {
     let p = path.as_ref();
@@ -1226,6 +2171,18 @@ async fn read_file_metadata(p: impl AsRef<Path>) -> Result<Metadata> {
     Ok(meta)
 }
 
+/// Tmp and multipart leftovers are never user data, so deleting them skips the trash.
+fn skip_trash(volume: &str) -> bool {
+    [super::RUSTFS_META_TMP_BUCKET, super::RUSTFS_META_MULTIPART_BUCKET]
+        .iter()
+        .any(|v| {
+            volume == *v
+                || volume
+                    .strip_prefix(v)
+                    .is_some_and(|rest| rest.starts_with(SLASH_SEPARATOR_STR))
+        })
+}
+
 fn skip_access_checks(p: impl AsRef<str>) -> bool {
     let vols = [
         RUSTFS_META_TMP_DELETED_BUCKET,
@@ -1300,6 +2257,14 @@ impl DiskAPI for LocalDisk {
         Ok(())
     }
 
+    fn set_scanning(&self, scanning: bool) {
+        self.scanning.store(scanning as u32, Ordering::SeqCst);
+    }
+
+    fn set_healing(&self, healing: bool) {
+        self.healing.store(healing as u32, Ordering::SeqCst);
+    }
+
     #[tracing::instrument(skip(self))]
     fn path(&self) -> PathBuf {
         self.root.clone()
@@ -1399,8 +2364,9 @@ impl DiskAPI for LocalDisk {
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
+        let _timer = SpanTimer::start();
         if volume == RUSTFS_META_BUCKET && path == super::FORMAT_CONFIG_FILE {
             let format_info = self.format_info.read().await;
             if !format_info.data.is_empty() {
@@ -1415,13 +2381,49 @@ impl DiskAPI for LocalDisk {
         Ok(data)
     }
 
-    #[tracing::instrument(level = "debug", skip_all)]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes> {
+        let _timer = SpanTimer::start();
+        let volume_dir = self.get_bucket_path(volume)?;
+        if !skip_access_checks(volume) {
+            access(&volume_dir)
+                .await
+                .map_err(|e| to_access_error(e, DiskError::VolumeAccessDenied))?;
+        }
+
+        let file_path = self.get_object_path(volume, path)?;
+        check_path_length(file_path.to_string_lossy().as_ref())?;
+
+        let _permit = self.acquire_open_file().await;
+        let mut f = self.open_file(&file_path, O_RDONLY, volume_dir).await?;
+        if offset > 0 {
+            f.seek(SeekFrom::Start(offset as u64)).await?;
+        }
+
+        // Callers may ask for more than the file holds, only allocate for what is there
+        let remaining = f.metadata().await?.len().saturating_sub(offset as u64);
+        let mut buf = Vec::with_capacity(length.min(remaining as usize));
+        f.take(length as u64).read_to_end(&mut buf).await?;
+        if exact && buf.len() < length {
+            return Err(DiskError::LessData);
+        }
+
+        Ok(buf.into())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         self.write_all_public(volume, path, data).await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume)
             && let Err(e) = access(&volume_dir).await
@@ -1433,14 +2435,46 @@ impl DiskAPI for LocalDisk {
 
         check_path_length(file_path.to_string_lossy().to_string().as_str())?;
 
-        self.delete_file(&volume_dir, &file_path, opt.recursive, opt.immediate)
+        if opt.undo_write
+            && let Some(old_data_dir) = opt.old_data_dir
+        {
+            return self.undo_write(volume, &volume_dir, &file_path, old_data_dir).await;
+        }
+
+        self.delete_file(&volume_dir, &file_path, opt.recursive, opt.immediate || skip_trash(volume))
             .await?;
+        self.forget_write_checksum(&file_path).await?;
 
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
+        let volume_dir = self.get_bucket_path(volume)?;
+        if !skip_access_checks(volume)
+            && let Err(e) = access(&volume_dir).await
+        {
+            return Err(to_access_error(e, DiskError::VolumeAccessDenied).into());
+        }
+
+        let file_path = self.get_object_path(volume, path)?;
+
+        check_path_length(file_path.to_string_lossy().to_string().as_str())?;
+
+        self.unshare_inode(&file_path, true).await?;
+        let f = super::fs::open_file(&file_path, O_WRONLY).await.map_err(to_file_error)?;
+        self.forget_write_checksum(&file_path).await?;
+        f.set_len(size).await.map_err(to_file_error)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        let _timer = SpanTimer::start();
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume)
             && let Err(e) = access(&volume_dir).await
@@ -1555,8 +2589,9 @@ impl DiskAPI for LocalDisk {
 
         Ok(ret)
     }
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn check_parts(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        let _timer = SpanTimer::start();
         let volume_dir = self.get_bucket_path(volume)?;
         let file_path = self.get_object_path(volume, path)?;
         check_path_length(file_path.to_string_lossy().as_ref())?;
@@ -1611,8 +2646,11 @@ impl DiskAPI for LocalDisk {
         Ok(resp)
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         let src_volume_dir = self.get_bucket_path(src_volume)?;
         let dst_volume_dir = self.get_bucket_path(dst_volume)?;
         if !skip_access_checks(src_volume) {
@@ -1661,11 +2699,41 @@ impl DiskAPI for LocalDisk {
             }
 
             remove_std(&dst_file_path).map_err(to_file_error)?;
+        } else if let Err(e) = lstat_std(&src_file_path) {
+            return Err(to_file_error(e).into());
+        }
+
+        // Stage the part metadata first so a truncated write never leaves a partial sidecar
+        let meta_file_path = self.get_object_path(dst_volume, format!("{dst_path}.meta").as_str())?;
+        check_path_length(meta_file_path.to_string_lossy().as_ref())?;
+
+        let tmp_volume_dir = self.get_bucket_path(super::RUSTFS_META_TMP_BUCKET)?;
+        let tmp_meta_path = self.tmp_file_path()?;
+        self.write_all_internal(&tmp_meta_path, InternalBuf::Ref(&meta), true, &tmp_volume_dir)
+            .await?;
+
+        let written = lstat(&tmp_meta_path).await.map_err(to_file_error)?.len();
+        if written != meta.len() as u64 {
+            let _ = remove(&tmp_meta_path).await;
+            return Err(DiskError::ShortWrite);
+        }
+
+        if let Err(e) = rename_all(&src_file_path, &dst_file_path, &dst_volume_dir).await {
+            let _ = remove(&tmp_meta_path).await;
+            return Err(e);
         }
 
-        rename_all(&src_file_path, &dst_file_path, &dst_volume_dir).await?;
+        rename_all(&tmp_meta_path, &meta_file_path, &dst_volume_dir).await?;
 
-        self.write_all(dst_volume, format!("{dst_path}.meta").as_str(), meta).await?;
+        // The recorded checksum follows the part, a part written without one must not keep a stale one
+        if !src_is_dir {
+            let src_sum_path = write_checksum_path(&src_file_path);
+            if lstat(&src_sum_path).await.is_ok() {
+                rename_all(&src_sum_path, &write_checksum_path(&dst_file_path), &dst_volume_dir).await?;
+            } else {
+                self.forget_write_checksum(&dst_file_path).await?;
+            }
+        }
 
         if let Some(parent) = src_file_path.parent() {
             self.delete_file(&src_volume_dir, &parent.to_path_buf(), false, false).await?;
@@ -1674,8 +2742,11 @@ impl DiskAPI for LocalDisk {
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         let src_volume_dir = self.get_bucket_path(src_volume)?;
         let dst_volume_dir = self.get_bucket_path(dst_volume)?;
         if !skip_access_checks(src_volume) {
@@ -1714,16 +2785,22 @@ impl DiskAPI for LocalDisk {
                 }
             };
 
-            if let Some(meta) = meta_op
+            if let Some(meta) = &meta_op
                 && !meta.is_dir()
             {
                 return Err(DiskError::FileAccessDenied);
             }
 
             remove(&dst_file_path).await.map_err(to_file_error)?;
+
+            // A missing source directory has nothing to move
+            if meta_op.is_none() {
+                return Ok(());
+            }
         }
 
-        rename_all(&src_file_path, &dst_file_path, &dst_volume_dir).await?;
+        // Creates missing destination parents and copies across devices
+        rename_all_or_copy(&src_file_path, &dst_file_path, &dst_volume_dir).await?;
 
         if let Some(parent) = src_file_path.parent() {
             let _ = self.delete_file(&src_volume_dir, &parent.to_path_buf(), false, false).await;
@@ -1732,8 +2809,11 @@ impl DiskAPI for LocalDisk {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    async fn create_file(&self, origvolume: &str, volume: &str, path: &str, _file_size: i64) -> Result<FileWriter> {
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn create_file(&self, origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<FileWriter> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         if !origvolume.is_empty() {
             let origvolume_dir = self.get_bucket_path(origvolume)?;
             if !skip_access_checks(origvolume) {
@@ -1752,18 +2832,43 @@ impl DiskAPI for LocalDisk {
         if let Some(parent) = file_path.parent() {
             os::make_dir_all(parent, &volume_dir).await?;
         }
+        self.unshare_inode(&file_path, false).await?;
+        self.forget_write_checksum(&file_path).await?;
+
+        let permit = self.acquire_open_file().await;
+
+        // Small files gain nothing from bypassing the page cache, unknown sizes are treated as large
+        #[cfg(target_os = "linux")]
+        if self.direct_io && (file_size < 0 || file_size >= DIRECT_IO_MIN_FILE_SIZE) {
+            let f = super::direct_io::DirectFileWriter::create(&file_path)
+                .await
+                .map_err(to_file_error)?;
+            return Ok(Box::new(OpenFileGuard::new(self.new_file_writer(f, file_path, file_size), permit)));
+        }
+
         let f = super::fs::open_file(&file_path, O_CREATE | O_WRONLY)
             .await
             .map_err(to_file_error)?;
 
-        Ok(Box::new(f))
+        // Reserve space up front so a full drive fails here instead of mid-write
+        #[cfg(target_os = "linux")]
+        if file_size > 0 {
+            match rustfs_utils::os::fallocate(&f, file_size as u64) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::StorageFull => return Err(DiskError::DiskFull),
+                Err(e) if e.kind() == ErrorKind::Unsupported => {}
+                Err(e) => warn!("fallocate {:?} failed: {}", file_path, e),
+            }
+        }
 
-        // Ok(())
+        Ok(Box::new(OpenFileGuard::new(self.new_file_writer(f, file_path, file_size), permit)))
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
     // async fn append_file(&self, volume: &str, path: &str, mut r: DuplexStream) -> Result<File> {
     async fn append_file(&self, volume: &str, path: &str) -> Result<FileWriter> {
+        self.check_writable()?;
+
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume) {
             access(&volume_dir)
@@ -1774,14 +2879,18 @@ impl DiskAPI for LocalDisk {
         let file_path = self.get_object_path(volume, path)?;
         check_path_length(file_path.to_string_lossy().as_ref())?;
 
+        self.unshare_inode(&file_path, true).await?;
+        self.forget_write_checksum(&file_path).await?;
+        let permit = self.acquire_open_file().await;
         let f = self.open_file(file_path, O_CREATE | O_APPEND | O_WRONLY, volume_dir).await?;
 
-        Ok(Box::new(f))
+        Ok(Box::new(OpenFileGuard::new(f, permit)))
     }
 
     // TODO: io verifier
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_file(&self, volume: &str, path: &str) -> Result<FileReader> {
+        let _timer = SpanTimer::start();
         // warn!("disk read_file: volume: {}, path: {}", volume, path);
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume) {
@@ -1793,13 +2902,22 @@ impl DiskAPI for LocalDisk {
         let file_path = self.get_object_path(volume, path)?;
         check_path_length(file_path.to_string_lossy().as_ref())?;
 
+        let permit = self.acquire_open_file().await;
         let f = self.open_file(file_path, O_RDONLY, volume_dir).await?;
 
-        Ok(Box::new(f))
+        Ok(Box::new(OpenFileGuard::new(f, permit)))
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<FileReader> {
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<FileReader> {
+        let _timer = SpanTimer::start();
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume) {
             access(&volume_dir)
@@ -1810,7 +2928,8 @@ impl DiskAPI for LocalDisk {
         let file_path = self.get_object_path(volume, path)?;
         check_path_length(file_path.to_string_lossy().as_ref())?;
 
-        let mut f = self.open_file(file_path, O_RDONLY, volume_dir).await?;
+        let permit = self.acquire_open_file().await;
+        let mut f = self.open_file(&file_path, O_RDONLY, volume_dir).await?;
 
         let meta = f.metadata().await?;
         if meta.len() < (offset + length) as u64 {
@@ -1823,11 +2942,31 @@ impl DiskAPI for LocalDisk {
             return Err(DiskError::FileCorrupt);
         }
 
+        if opts.verify {
+            match self.read_write_checksum(&file_path).await? {
+                Some((algo, sum)) => {
+                    let length = if length == 0 {
+                        meta.len() - offset as u64
+                    } else {
+                        length as u64
+                    };
+                    let reader = VerifyingReader::new(OpenFileGuard::new(f, permit), &algo, sum, offset as u64, length);
+                    return Ok(Box::new(reader));
+                }
+                // Serving unverified bytes would defeat the caller asking for verification
+                None => {
+                    return Err(DiskError::other(format!(
+                        "read_file_stream: no recorded checksum to verify {file_path:?} against"
+                    )));
+                }
+            }
+        }
+
         if offset > 0 {
             f.seek(SeekFrom::Start(offset as u64)).await?;
         }
 
-        Ok(Box::new(f))
+        Ok(Box::new(OpenFileGuard::new(f, permit)))
     }
     #[tracing::instrument(level = "debug", skip(self))]
     async fn list_dir(&self, origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>> {
@@ -1843,7 +2982,9 @@ impl DiskAPI for LocalDisk {
         let volume_dir = self.get_bucket_path(volume)?;
         let dir_path_abs = self.get_object_path(volume, dir_path.trim_start_matches(SLASH_SEPARATOR_STR))?;
 
-        let entries = match os::read_dir(&dir_path_abs, count).await {
+        // readdir order is arbitrary, so the whole directory is read and sorted before the
+        // first count entries are kept. Callers can then page through it by name.
+        let mut entries = match os::read_dir(&dir_path_abs, -1).await {
             Ok(res) => res,
             Err(e) => {
                 if e.kind() == ErrorKind::NotFound
@@ -1857,12 +2998,18 @@ impl DiskAPI for LocalDisk {
             }
         };
 
+        entries.sort_unstable();
+        if count > 0 {
+            entries.truncate(count as usize);
+        }
+
         Ok(entries)
     }
 
-    // FIXME: TODO: io.writer TODO cancel
-    #[tracing::instrument(level = "debug", skip(self, wr))]
+    // FIXME: TODO: io.writer
+    #[tracing::instrument(level = "debug", skip(self, wr), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn walk_dir<W: AsyncWrite + Unpin + Send>(&self, opts: WalkDirOptions, wr: &mut W) -> Result<()> {
+        let _timer = SpanTimer::start();
         let volume_dir = self.get_bucket_path(&opts.bucket)?;
 
         if !skip_access_checks(&opts.bucket)
@@ -1916,10 +3063,17 @@ impl DiskAPI for LocalDisk {
         )
         .await?;
 
-        Ok(())
+        // A cancelled walk is incomplete, leave the stream unterminated
+        if opts.is_cancelled() {
+            return Ok(());
+        }
+
+        out.close().await?;
+
+        Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, fi))]
+    #[tracing::instrument(level = "debug", skip(self, fi), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_data(
         &self,
         src_volume: &str,
@@ -1928,6 +3082,9 @@ impl DiskAPI for LocalDisk {
         dst_volume: &str,
         dst_path: &str,
     ) -> Result<RenameDataResp> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         let src_volume_dir = self.get_bucket_path(src_volume)?;
         if !skip_access_checks(src_volume)
             && let Err(e) = super::fs::access_std(&src_volume_dir)
@@ -2039,7 +3196,7 @@ impl DiskAPI for LocalDisk {
         if let Some((src_data_path, dst_data_path)) = has_data_dir_path.as_ref() {
             let no_inline = fi.data.is_none() && fi.size > 0;
             if no_inline && let Err(err) = rename_all(&src_data_path, &dst_data_path, &skip_parent).await {
-                let _ = self.delete_file(&dst_volume_dir, dst_data_path, false, false).await;
+                let _ = self.delete_file(&dst_volume_dir, dst_data_path, false, true).await;
                 info!(
                     "rename all failed src_data_path: {:?}, dst_data_path: {:?}, err: {:?}",
                     src_data_path, dst_data_path, err
@@ -2049,26 +3206,25 @@ impl DiskAPI for LocalDisk {
         }
 
         if let Some(old_data_dir) = has_old_data_dir {
-            // preserve current xl.meta inside the oldDataDir.
+            // preserve current xl.meta inside the oldDataDir, delete_version restores it on undo_write.
             if let Some(dst_buf) = has_dst_buf
                 && let Err(err) = self
-                    .write_all_private(
+                    .write_all_meta(
                         dst_volume,
-                        format!("{}/{}/{}", &dst_path, &old_data_dir.to_string(), STORAGE_FORMAT_FILE).as_str(),
-                        dst_buf.into(),
+                        format!("{}/{}/{}", &dst_path, &old_data_dir.to_string(), STORAGE_FORMAT_FILE_BACKUP).as_str(),
+                        &dst_buf,
                         true,
-                        &skip_parent,
                     )
                     .await
             {
-                info!("write_all_private failed err: {:?}", err);
+                info!("write_all_meta failed err: {:?}", err);
                 return Err(err);
             }
         }
 
         if let Err(err) = rename_all(&src_file_path, &dst_file_path, &skip_parent).await {
             if let Some((_, dst_data_path)) = has_data_dir_path.as_ref() {
-                let _ = self.delete_file(&dst_volume_dir, dst_data_path, false, false).await;
+                let _ = self.delete_file(&dst_volume_dir, dst_data_path, false, true).await;
             }
             info!("rename all failed err: {:?}", err);
             return Err(err);
@@ -2079,7 +3235,7 @@ impl DiskAPI for LocalDisk {
                 let _ = remove_std(src_file_path_parent);
             } else {
                 let _ = self
-                    .delete_file(&dst_volume_dir, &src_file_path_parent.to_path_buf(), true, false)
+                    .delete_file(&dst_volume_dir, &src_file_path_parent.to_path_buf(), true, true)
                     .await;
             }
         }
@@ -2092,10 +3248,9 @@ impl DiskAPI for LocalDisk {
 
     #[tracing::instrument(skip(self))]
     async fn make_volumes(&self, volumes: Vec<&str>) -> Result<()> {
-        for vol in volumes {
-            if let Err(e) = self.make_volume(vol).await
-                && e != DiskError::VolumeExists
-            {
+        // Every volume is attempted; the first failure in input order is reported
+        for res in self.make_volumes_each(volumes).await? {
+            if let Err(e) = res {
                 error!("local disk make volumes failed: {e}");
                 return Err(e);
             }
@@ -2106,38 +3261,28 @@ impl DiskAPI for LocalDisk {
 
     #[tracing::instrument(skip(self))]
     async fn make_volume(&self, volume: &str) -> Result<()> {
-        if !Self::is_valid_volname(volume) {
-            return Err(Error::other("Invalid arguments specified"));
-        }
-
-        let volume_dir = self.get_bucket_path(volume)?;
-
-        if let Err(e) = access(&volume_dir).await {
-            if e.kind() == ErrorKind::NotFound {
-                os::make_dir_all(&volume_dir, self.root.as_path()).await?;
-                return Ok(());
-            }
-            error!("local disk make volume failed: {e}");
-            return Err(to_volume_error(e).into());
-        }
-
-        Err(DiskError::VolumeExists)
+        self.make_volume_strict(volume).await
     }
 
     #[tracing::instrument(skip(self))]
     async fn list_volumes(&self) -> Result<Vec<VolumeInfo>> {
         let mut volumes = Vec::new();
 
-        let entries = os::read_dir(&self.root, -1).await.map_err(to_volume_error)?;
+        let mut entries = fs::read_dir(&self.root).await.map_err(to_volume_error)?;
 
-        for entry in entries {
-            if !has_suffix(&entry, SLASH_SEPARATOR_STR) || !Self::is_valid_volname(clean(&entry).as_str()) {
+        while let Some(entry) = entries.next_entry().await.map_err(to_volume_error)? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !Self::is_valid_volname(&name) {
                 continue;
             }
 
+            let Some(meta) = self.volume_dir_metadata(&entry).await else {
+                continue;
+            };
+
             volumes.push(VolumeInfo {
-                name: clean(&entry),
-                created: None,
+                name,
+                created: volume_created(&meta),
             });
         }
 
@@ -2147,21 +3292,19 @@ impl DiskAPI for LocalDisk {
     #[tracing::instrument(skip(self))]
     async fn stat_volume(&self, volume: &str) -> Result<VolumeInfo> {
         let volume_dir = self.get_bucket_path(volume)?;
-        let meta = lstat(&volume_dir).await.map_err(to_volume_error)?;
-
-        let modtime = match meta.modified() {
-            Ok(md) => Some(OffsetDateTime::from(md)),
-            Err(_) => None,
-        };
+        // A volume we may not look into exists, it is not reported as missing
+        let meta = fs::metadata(&volume_dir).await.map_err(to_volume_error)?;
 
         Ok(VolumeInfo {
             name: volume.to_string(),
-            created: modtime,
+            created: volume_created(&meta),
         })
     }
 
     #[tracing::instrument(skip(self))]
     async fn delete_paths(&self, volume: &str, paths: &[String]) -> Result<()> {
+        self.check_writable()?;
+
         let volume_dir = self.get_bucket_path(volume)?;
         if !skip_access_checks(volume) {
             access(&volume_dir)
@@ -2174,14 +3317,18 @@ impl DiskAPI for LocalDisk {
 
             check_path_length(file_path.to_string_lossy().as_ref())?;
 
-            self.move_to_trash(&file_path, false, false).await?;
+            self.move_to_trash(&file_path, false, skip_trash(volume)).await?;
+            self.forget_write_checksum(&file_path).await?;
         }
 
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn update_metadata(&self, volume: &str, path: &str, fi: FileInfo, opts: &UpdateMetadataOpts) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         if !fi.metadata.is_empty() {
             let file_path = self.get_object_path(volume, path)?;
 
@@ -2208,25 +3355,49 @@ impl DiskAPI for LocalDisk {
 
             let wbuf = xl_meta.marshal_msg()?;
 
-            return self
-                .write_all_meta(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str(), &wbuf, !opts.no_persistence)
-                .await;
+            let xl_path = format!("{path}/{STORAGE_FORMAT_FILE}");
+            let cache_path = self.get_object_path(volume, xl_path.as_str())?;
+
+            // Only the cached copy changes, xl.meta on disk is left as is
+            if opts.no_persistence {
+                get_global_file_cache().put_metadata(cache_path, xl_meta, wbuf.into()).await;
+                return Ok(());
+            }
+
+            self.write_all_meta(volume, xl_path.as_str(), &wbuf, true).await?;
+            get_global_file_cache().invalidate(&cache_path).await;
+
+            return Ok(());
         }
 
         Err(Error::other("Invalid Argument"))
     }
 
-    #[tracing::instrument(skip(self))]
-    async fn write_metadata(&self, _org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn write_metadata(&self, org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
+        if !org_volume.is_empty() {
+            let org_volume_path = self.get_bucket_path(org_volume)?;
+            if !skip_access_checks(org_volume) {
+                access(&org_volume_path)
+                    .await
+                    .map_err(|e| to_access_error(e, DiskError::VolumeAccessDenied))?;
+            }
+        }
+
         let p = self.get_object_path(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str())?;
 
         let mut meta = FileMeta::new();
+        let mut backup = None;
         if !fi.fresh {
             let (buf, _) = read_file_exists(&p).await?;
             if !buf.is_empty() {
-                let _ = meta.unmarshal_msg(&buf).map_err(|_| {
-                    meta = FileMeta::new();
-                });
+                match meta.unmarshal_msg(&buf) {
+                    Ok(_) => backup = Some(buf),
+                    Err(_) => meta = FileMeta::new(),
+                }
             }
         }
 
@@ -2234,13 +3405,19 @@ impl DiskAPI for LocalDisk {
 
         let fm_data = meta.marshal_msg()?;
 
-        self.write_all(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str(), fm_data.into())
+        // Keep the previous metadata around until the new one is in place
+        if let Some(buf) = backup {
+            self.write_all_meta(volume, format!("{path}/{STORAGE_FORMAT_FILE_BACKUP}").as_str(), &buf, true)
+                .await?;
+        }
+
+        self.write_all_meta(volume, format!("{path}/{STORAGE_FORMAT_FILE}").as_str(), &fm_data, true)
             .await?;
 
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_version(
         &self,
         org_volume: &str,
@@ -2249,6 +3426,7 @@ impl DiskAPI for LocalDisk {
         version_id: &str,
         opts: &ReadOptions,
     ) -> Result<FileInfo> {
+        let _timer = SpanTimer::start();
         if !org_volume.is_empty() {
             let org_volume_path = self.get_bucket_path(org_volume)?;
             if !skip_access_checks(org_volume) {
@@ -2263,7 +3441,8 @@ impl DiskAPI for LocalDisk {
 
         check_path_length(file_path.to_string_lossy().as_ref())?;
 
-        let read_data = opts.read_data;
+        // Data left out by the projection is not read at all
+        let read_data = opts.read_data && opts.projection.includes_data();
 
         let (data, _) = self
             .read_raw(volume, volume_dir.clone(), file_path, read_data)
@@ -2287,28 +3466,33 @@ impl DiskAPI for LocalDisk {
             },
         )?;
 
-        if opts.read_data {
+        if read_data {
             if fi.data.as_ref().is_some_and(|d| !d.is_empty()) || fi.size == 0 {
                 if fi.inline_data() {
-                    return Ok(fi);
+                    return Ok(opts.projection.apply(fi));
                 }
 
                 if fi.size == 0 || fi.version_id.is_none_or(|v| v.is_nil()) {
                     fi.set_inline_data();
-                    return Ok(fi);
+                    return Ok(opts.projection.apply(fi));
                 };
-                if let Some(part) = fi.parts.first() {
-                    let part_path = format!("part.{}", part.number);
-                    let part_path = path_join_buf(&[
-                        path,
-                        fi.data_dir.map_or("".to_string(), |dir| dir.to_string()).as_str(),
-                        part_path.as_str(),
-                    ]);
-                    let part_path = self.get_object_path(volume, part_path.as_str())?;
-                    if lstat(&part_path).await.is_err() {
-                        fi.set_inline_data();
-                        return Ok(fi);
+
+                // The data in xl.meta is the object unless a part file on disk says otherwise
+                let part_on_disk = match fi.parts.first() {
+                    Some(part) => {
+                        let part_path = format!("part.{}", part.number);
+                        let part_path = path_join_buf(&[
+                            path,
+                            fi.data_dir.map_or("".to_string(), |dir| dir.to_string()).as_str(),
+                            part_path.as_str(),
+                        ]);
+                        lstat(&self.get_object_path(volume, part_path.as_str())?).await.is_ok()
                     }
+                    None => false,
+                };
+                if !part_on_disk {
+                    fi.set_inline_data();
+                    return Ok(opts.projection.apply(fi));
                 }
 
                 fi.data = None;
@@ -2331,20 +3515,60 @@ impl DiskAPI for LocalDisk {
             }
         }
 
-        Ok(fi)
+        Ok(opts.projection.apply(fi))
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_xl(&self, volume: &str, path: &str, read_data: bool) -> Result<RawFileInfo> {
+        let _timer = SpanTimer::start();
         let file_path = self.get_object_path(volume, path)?;
         let file_dir = self.get_bucket_path(volume)?;
 
-        let (buf, _) = self.read_raw(volume, file_dir, file_path, read_data).await?;
+        let (err, mtime) = match self.read_raw(volume, &file_dir, &file_path, read_data).await {
+            Ok((buf, _)) if FileMeta::is_xl2_v1_format(&buf) => return Ok(RawFileInfo { buf }),
+            Ok((_, mtime)) => (DiskError::FileCorrupt, mtime),
+            Err(err) => (err, None),
+        };
+
+        if err != DiskError::FileCorrupt && !matches!(err, DiskError::Io(_)) {
+            return Err(err);
+        }
+
+        let mtime = match mtime {
+            Some(mtime) => Some(mtime),
+            None => lstat(path_join(&[file_path.as_path(), Path::new(STORAGE_FORMAT_FILE)]))
+                .await
+                .ok()
+                .and_then(|meta| meta.modified().ok())
+                .map(OffsetDateTime::from),
+        };
+
+        // xl.meta is unreadable, fall back to the copy kept by write_metadata. That copy is taken
+        // before each overwrite, so it is only trusted when xl.meta was not written after it,
+        // serving an older one would roll the object's versions back.
+        let backup_path = path_join(&[file_path.as_path(), Path::new(STORAGE_FORMAT_FILE_BACKUP)]);
+        let backup = if read_data {
+            self.read_all_data_with_dmtime(volume, &file_dir, &backup_path).await
+        } else {
+            self.read_metadata_with_dmtime(&backup_path).await
+        };
 
-        Ok(RawFileInfo { buf })
+        match backup {
+            Ok((buf, Some(backup_mtime)))
+                if FileMeta::is_xl2_v1_format(&buf) && mtime.is_some_and(|mtime| backup_mtime >= mtime) =>
+            {
+                warn!("read_xl: {:?} is corrupt ({}), using {}", &file_path, err, STORAGE_FORMAT_FILE_BACKUP);
+                Ok(RawFileInfo { buf })
+            }
+            Ok((buf, _)) if FileMeta::is_xl2_v1_format(&buf) => {
+                warn!("read_xl: {:?} is corrupt ({}), {} is older", &file_path, err, STORAGE_FORMAT_FILE_BACKUP);
+                Err(DiskError::OutdatedXLMeta)
+            }
+            _ => Err(DiskError::OutdatedXLMeta),
+        }
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete_version(
         &self,
         volume: &str,
@@ -2353,6 +3577,9 @@ impl DiskAPI for LocalDisk {
         force_del_marker: bool,
         opts: DeleteOptions,
     ) -> Result<()> {
+        let _timer = SpanTimer::start();
+        self.check_writable()?;
+
         if path.starts_with(SLASH_SEPARATOR_STR) {
             return self
                 .delete(
@@ -2418,7 +3645,7 @@ impl DiskAPI for LocalDisk {
                 .await;
         }
 
-        // opts.undo_write && opts.old_data_dir.is_some_and(f)
+        // Undoing a write restores the xl.meta that rename_data preserved in the old data dir
         if let Some(old_data_dir) = opts.old_data_dir
             && opts.undo_write
         {
@@ -2426,39 +3653,46 @@ impl DiskAPI for LocalDisk {
                 file_path.as_path(),
                 Path::new(format!("{old_data_dir}{SLASH_SEPARATOR_STR}{STORAGE_FORMAT_FILE_BACKUP}").as_str()),
             ]);
-            let dst_path = path_join(&[
-                file_path.as_path(),
-                Path::new(format!("{path}{SLASH_SEPARATOR_STR}{STORAGE_FORMAT_FILE}").as_str()),
-            ]);
-            return rename_all(src_path, dst_path, file_path).await;
+            return rename_all(src_path, &xl_path, file_path).await;
         }
 
+        let backup_path = path_join(&[file_path.as_path(), Path::new(STORAGE_FORMAT_FILE_BACKUP)]);
+        self.delete_file(&volume_dir, &backup_path, false, false).await?;
+
         self.delete_file(&volume_dir, &xl_path, true, false).await
     }
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete_versions(&self, volume: &str, versions: Vec<FileInfoVersions>, _opts: DeleteOptions) -> Vec<Option<Error>> {
-        let mut errs = Vec::with_capacity(versions.len());
-        for _ in 0..versions.len() {
-            errs.push(None);
+        let _timer = SpanTimer::start();
+        if let Err(err) = self.check_writable() {
+            return vec![Some(err); versions.len()];
         }
 
-        for (i, ver) in versions.iter().enumerate() {
-            if let Err(e) = self.delete_versions_internal(volume, ver.name.as_str(), &ver.versions).await {
-                errs[i] = Some(e);
-            } else {
-                errs[i] = None;
-            }
+        // One result per object, in input order; a failing object does not stop the others
+        let mut errs = Vec::with_capacity(versions.len());
+        for ver in versions.iter() {
+            errs.push(
+                self.delete_versions_internal(volume, ver.name.as_str(), &ver.versions)
+                    .await
+                    .err(),
+            );
         }
 
         errs
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>> {
+        let _timer = SpanTimer::start();
+        let files = match req.pattern.as_deref() {
+            Some(pattern) if req.files.is_empty() => self.expand_read_multiple_pattern(&req.bucket, &req.prefix, pattern).await?,
+            _ => req.files.clone(),
+        };
+
         let mut results = Vec::new();
         let mut found = 0;
 
-        for v in req.files.iter() {
+        for v in files.iter() {
             let fpath = self.get_object_path(&req.bucket, format!("{}/{}", &req.prefix, v).as_str())?;
             let mut res = ReadMultipleResp {
                 bucket: req.bucket.clone(),
@@ -2467,27 +3701,35 @@ impl DiskAPI for LocalDisk {
                 ..Default::default()
             };
 
-            // if req.metadata_only {}
-            match read_file_all(&fpath).await {
-                Ok((data, meta)) => {
-                    found += 1;
+            let read = if req.metadata_only {
+                self.read_metadata_with_dmtime(&fpath).await
+            } else {
+                read_file_all(&fpath).await.map(|(data, meta)| {
+                    let mod_time = match meta.modified() {
+                        Ok(md) => Some(OffsetDateTime::from(md)),
+                        Err(_) => {
+                            warn!("Not supported modified on this platform");
+                            None
+                        }
+                    };
+                    (data.into(), mod_time)
+                })
+            };
 
+            match read {
+                Ok((data, mod_time)) => {
+                    // Oversized files are reported but do not stop the remaining reads
                     if req.max_size > 0 && data.len() > req.max_size {
                         res.exists = true;
                         res.error = format!("max size ({}) exceeded: {}", req.max_size, data.len());
                         results.push(res);
-                        break;
+                        continue;
                     }
 
+                    found += 1;
                     res.exists = true;
-                    res.data = data.into();
-                    res.mod_time = match meta.modified() {
-                        Ok(md) => Some(OffsetDateTime::from(md)),
-                        Err(_) => {
-                            warn!("Not supported modified on this platform");
-                            None
-                        }
-                    };
+                    res.data = data;
+                    res.mod_time = mod_time;
                     results.push(res);
 
                     if req.max_results > 0 && found >= req.max_results {
@@ -2513,8 +3755,46 @@ impl DiskAPI for LocalDisk {
         Ok(results)
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>> {
+        let volume_dir = self.get_bucket_path(volume)?;
+        if !skip_access_checks(volume)
+            && let Err(e) = access(&volume_dir).await
+        {
+            return Err(to_access_error(e, DiskError::VolumeAccessDenied).into());
+        }
+
+        let mut ret = Vec::with_capacity(paths.len());
+        for path in paths.iter() {
+            let meta = match lstat(self.get_object_path(volume, path)?).await {
+                Ok(meta) if meta.is_file() => meta,
+                Ok(_) => {
+                    ret.push(None);
+                    continue;
+                }
+                Err(err) if matches!(err.kind(), ErrorKind::NotFound | ErrorKind::NotADirectory) => {
+                    ret.push(None);
+                    continue;
+                }
+                Err(err) => return Err(to_file_error(err).into()),
+            };
+
+            ret.push(Some(FileInfo {
+                volume: volume.to_string(),
+                name: path.clone(),
+                size: meta.len() as i64,
+                mod_time: meta.modified().ok().map(OffsetDateTime::from),
+                ..Default::default()
+            }));
+        }
+
+        Ok(ret)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn delete_volume(&self, volume: &str) -> Result<()> {
+        self.check_writable()?;
+
         let p = self.get_bucket_path(volume)?;
 
         // TODO: avoid recursive deletion; return errVolumeNotEmpty when files remain
@@ -2529,8 +3809,19 @@ impl DiskAPI for LocalDisk {
         Ok(())
     }
 
-    #[tracing::instrument(skip(self))]
-    async fn disk_info(&self, _: &DiskInfoOptions) -> Result<DiskInfo> {
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn disk_info(&self, opts: &DiskInfoOptions) -> Result<DiskInfo> {
+        let _timer = SpanTimer::start();
+        if opts.noop {
+            // Only confirming the disk is alive and still the same one, skip statting the filesystem
+            return Ok(DiskInfo {
+                id: self.get_disk_id().await.unwrap_or(None),
+                endpoint: self.endpoint.to_string(),
+                mount_path: self.path().to_string_lossy().to_string(),
+                ..Default::default()
+            });
+        }
+
         let mut info = Cache::get(self.disk_info_cache.clone()).await?;
         // TODO: nr_requests, rotational
         info.nr_requests = self.nrrequests;
@@ -2538,6 +3829,7 @@ impl DiskAPI for LocalDisk {
         info.mount_path = self.path().to_str().unwrap().to_string();
         info.endpoint = self.endpoint.to_string();
         info.scanning = self.scanning.load(Ordering::SeqCst) == 1;
+        info.healing = self.healing.load(Ordering::SeqCst) == 1;
 
         if info.id.is_none() {
             info.id = self.get_disk_id().await.unwrap_or(None);
@@ -2675,6 +3967,82 @@ mod test {
         let _ = fs::remove_dir_all(&test_dir).await;
     }
 
+    #[tokio::test]
+    async fn test_local_disk_new_migrates_format() {
+        let test_dir = "./test_local_disk_new_migrates_format";
+        let meta_dir = Path::new(test_dir).join(RUSTFS_META_BUCKET);
+        fs::create_dir_all(&meta_dir).await.unwrap();
+
+        let disks = [Uuid::new_v4(), Uuid::new_v4()];
+        let v1 = format!(
+            r#"{{"version":"1","format":"xl","id":"{}","xl":{{"version":"1","disk":"{}","jbod":["{}","{}"]}}}}"#,
+            Uuid::new_v4(),
+            disks[1],
+            disks[0],
+            disks[1]
+        );
+        fs::write(meta_dir.join(super::super::FORMAT_CONFIG_FILE), &v1).await.unwrap();
+
+        let mut endpoint = Endpoint::try_from(test_dir).unwrap();
+        endpoint.set_set_index(0);
+        endpoint.set_disk_index(1);
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        assert_eq!(disk.get_disk_id().await.unwrap(), Some(disks[1]));
+
+        // The upgraded document replaced the old one on disk
+        let stored = fs::read(meta_dir.join(super::super::FORMAT_CONFIG_FILE)).await.unwrap();
+        let fm = FormatV3::load(&stored).unwrap();
+        assert_eq!(fm.erasure.this, disks[1]);
+        assert_eq!(fm.erasure.sets, vec![disks.to_vec()]);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_safe_join() {
+        let test_dir = "./test_local_disk_safe_join";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        let root = disk.root.clone();
+
+        // Nested and self-referencing paths stay inside the volume
+        assert_eq!(disk.safe_join("bucket", "a/b/c").unwrap(), root.join("bucket/a/b/c"));
+        assert_eq!(disk.safe_join("bucket", "a/./b/../c").unwrap(), root.join("bucket/a/c"));
+        assert_eq!(disk.safe_join("bucket", "").unwrap(), root.join("bucket"));
+        assert_eq!(disk.safe_join(".rustfs.sys/tmp", "x").unwrap(), root.join(".rustfs.sys/tmp/x"));
+        assert!(disk.safe_join("bucket", "dir/").unwrap().to_string_lossy().ends_with("dir/"));
+        assert!(disk.safe_join("bucket", "a..b/..c").is_ok());
+
+        // Escaping the root, the volume, or using absolute paths is denied
+        for (volume, path) in [
+            ("bucket", "../../etc/passwd"),
+            ("bucket", "a/../../other/key"),
+            ("bucket", ".."),
+            ("..", "etc"),
+            ("bucket", "/etc/passwd"),
+            ("/etc", "passwd"),
+        ] {
+            assert_eq!(
+                disk.safe_join(volume, path).unwrap_err(),
+                DiskError::FileAccessDenied,
+                "{volume:?} {path:?}"
+            );
+        }
+        assert_eq!(
+            disk.get_object_path("bucket", "../../etc/passwd").unwrap_err(),
+            DiskError::FileAccessDenied
+        );
+        assert_eq!(disk.get_bucket_path("../outside").unwrap_err(), DiskError::FileAccessDenied);
+        assert_eq!(
+            disk.read_all("bucket", "../../../etc/passwd").await.unwrap_err(),
+            DiskError::FileAccessDenied
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
     #[tokio::test]
     async fn test_local_disk_file_operations() {
         let test_dir = "./test_local_disk_file_ops";
@@ -2709,6 +4077,134 @@ mod test {
         let _ = fs::remove_dir_all(&test_dir).await;
     }
 
+    #[tokio::test]
+    async fn test_local_disk_delete_to_trash() {
+        let test_dir = "./test_local_disk_delete_to_trash";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("trash-vol").await.unwrap();
+        let trash = disk.get_bucket_path(RUSTFS_META_TMP_DELETED_BUCKET).unwrap();
+
+        async fn trash_entries(trash: &Path) -> Vec<PathBuf> {
+            let mut paths = Vec::new();
+            let mut entries = fs::read_dir(trash).await.unwrap();
+            while let Some(entry) = entries.next_entry().await.unwrap() {
+                paths.push(entry.path());
+            }
+            paths
+        }
+
+        disk.write_all("trash-vol", "file.txt", Bytes::from_static(b"keep me"))
+            .await
+            .unwrap();
+        disk.write_all("trash-vol", "dir/a", Bytes::from_static(b"a")).await.unwrap();
+        disk.write_all("trash-vol", "gone.txt", Bytes::from_static(b"gone"))
+            .await
+            .unwrap();
+
+        // Non-immediate deletes move the data into the trash
+        let opts = DeleteOptions::default();
+        disk.delete("trash-vol", "file.txt", opts.clone()).await.unwrap();
+        disk.delete("trash-vol", "dir", DeleteOptions { recursive: true, ..opts })
+            .await
+            .unwrap();
+        assert!(!disk.get_object_path("trash-vol", "file.txt").unwrap().exists());
+        assert!(!disk.get_object_path("trash-vol", "dir").unwrap().exists());
+
+        let entries = trash_entries(&trash).await;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            fs::read(entries.iter().find(|p| p.join("file.txt").exists()).unwrap().join("file.txt"))
+                .await
+                .unwrap(),
+            b"keep me"
+        );
+        assert_eq!(
+            fs::read(entries.iter().find(|p| p.join("dir").exists()).unwrap().join("dir/a"))
+                .await
+                .unwrap(),
+            b"a"
+        );
+
+        // Immediate deletes unlink without going through the trash
+        disk.delete(
+            "trash-vol",
+            "gone.txt",
+            DeleteOptions {
+                immediate: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(!disk.get_object_path("trash-vol", "gone.txt").unwrap().exists());
+        assert_eq!(trash_entries(&trash).await.len(), 2);
+
+        // Tmp leftovers are unlinked even without the immediate flag
+        disk.write_all(crate::disk::RUSTFS_META_TMP_BUCKET, "upload/part.1", Bytes::from_static(b"tmp"))
+            .await
+            .unwrap();
+        disk.delete(
+            crate::disk::RUSTFS_META_TMP_BUCKET,
+            "upload",
+            DeleteOptions {
+                recursive: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(
+            !disk
+                .get_object_path(crate::disk::RUSTFS_META_TMP_BUCKET, "upload")
+                .unwrap()
+                .exists()
+        );
+        assert_eq!(trash_entries(&trash).await.len(), 2);
+
+        // Recent entries survive a purge with a longer retention, older ones are removed
+        assert_eq!(disk.purge_trash(Duration::from_secs(3600)).await.unwrap(), 0);
+        assert_eq!(trash_entries(&trash).await.len(), 2);
+        assert_eq!(disk.purge_trash(Duration::ZERO).await.unwrap(), 2);
+        assert!(trash_entries(&trash).await.is_empty());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_local_disk_list_volumes_follows_symlinks() {
+        let test_dir = "./test_local_disk_list_volumes_symlinks";
+        let target_dir = "./test_local_disk_list_volumes_symlinks_target";
+        fs::create_dir_all(&test_dir).await.unwrap();
+        fs::create_dir_all(&target_dir).await.unwrap();
+        fs::write(format!("{target_dir}/file"), b"data").await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("plain-vol").await.unwrap();
+
+        let root = Path::new(test_dir);
+        let target = fs::canonicalize(target_dir).await.unwrap();
+        std::os::unix::fs::symlink(&target, root.join("linked-vol")).unwrap();
+        std::os::unix::fs::symlink(target.join("file"), root.join("linked-file")).unwrap();
+        std::os::unix::fs::symlink(root.join("loop-b"), root.join("loop-a")).unwrap();
+        std::os::unix::fs::symlink(root.join("loop-a"), root.join("loop-b")).unwrap();
+        std::os::unix::fs::symlink(fs::canonicalize(test_dir).await.unwrap(), root.join("self-vol")).unwrap();
+
+        let mut names: Vec<_> = disk.list_volumes().await.unwrap().into_iter().map(|v| v.name).collect();
+        names.sort();
+        assert_eq!(names, [RUSTFS_META_BUCKET, "linked-vol", "plain-vol"]);
+
+        let linked = disk.stat_volume("linked-vol").await.unwrap();
+        assert!(linked.created.is_some());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+        let _ = fs::remove_dir_all(&target_dir).await;
+    }
+
     #[tokio::test]
     async fn test_local_disk_volume_operations() {
         let test_dir = "./test_local_disk_volumes";
@@ -2724,11 +4220,16 @@ mod test {
         // Test listing volumes
         let volume_list = disk.list_volumes().await.unwrap();
         assert!(!volume_list.is_empty());
+        assert!(volume_list.iter().all(|v| v.created.is_some()));
 
         // Test volume stats
+        let now = OffsetDateTime::now_utc();
         for vol in &volumes {
             let vol_info = disk.stat_volume(vol).await.unwrap();
             assert_eq!(vol_info.name, *vol);
+
+            let created = vol_info.created.unwrap();
+            assert!((now - created).abs() < time::Duration::minutes(1));
         }
 
         // Test deleting volumes
@@ -2741,52 +4242,1032 @@ mod test {
     }
 
     #[tokio::test]
-    async fn test_local_disk_disk_info() {
-        let test_dir = "./test_local_disk_info";
+    async fn test_local_disk_list_dir() {
+        let test_dir = tempfile::TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        disk.make_volume("list-vol").await.unwrap();
+        disk.write_all("list-vol", "prefix/a.txt", Bytes::from("a")).await.unwrap();
+        disk.write_all("list-vol", "prefix/b.txt", Bytes::from("b")).await.unwrap();
+        disk.write_all("list-vol", "prefix/sub/c.txt", Bytes::from("c"))
+            .await
+            .unwrap();
+        fs::create_dir_all(disk.get_object_path("list-vol", "prefix/empty").unwrap())
+            .await
+            .unwrap();
+
+        // Directories keep a trailing slash, files do not, and entries are sorted
+        let entries = disk.list_dir("", "list-vol", "prefix", -1).await.unwrap();
+        assert_eq!(entries, vec!["a.txt", "b.txt", "empty/", "sub/"]);
+        assert_eq!(disk.list_dir("", "list-vol", "prefix/sub", -1).await.unwrap(), vec!["c.txt"]);
+
+        // A non-positive count is unlimited
+        assert_eq!(disk.list_dir("", "list-vol", "prefix", 0).await.unwrap().len(), 4);
+
+        // A positive count returns the lexically first entries, whatever order readdir uses
+        let mut names: Vec<String> = (0..32).map(|i| format!("obj-{:02}", (i * 7) % 32)).collect();
+        for name in &names {
+            disk.write_all("list-vol", &format!("wide/{name}"), Bytes::from("x"))
+                .await
+                .unwrap();
+        }
+        names.sort();
+        assert_eq!(disk.list_dir("", "list-vol", "wide", 5).await.unwrap(), names[..5]);
+        assert_eq!(disk.list_dir("", "list-vol", "wide", 40).await.unwrap(), names);
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_multiple() {
+        let test_dir = "./test_local_disk_read_multiple";
         fs::create_dir_all(&test_dir).await.unwrap();
 
         let endpoint = Endpoint::try_from(test_dir).unwrap();
         let disk = LocalDisk::new(&endpoint, false).await.unwrap();
 
-        let disk_info_opts = DiskInfoOptions {
-            disk_id: "test-disk".to_string(),
-            metrics: true,
-            noop: false,
-        };
+        disk.make_volume("multi-vol").await.unwrap();
+        disk.write_all("multi-vol", "prefix/a", Bytes::from("aaa")).await.unwrap();
+        disk.write_all("multi-vol", "prefix/c", Bytes::from("ccc")).await.unwrap();
 
-        let disk_info = disk.disk_info(&disk_info_opts).await.unwrap();
+        let req = ReadMultipleReq {
+            bucket: "multi-vol".to_string(),
+            prefix: "prefix".to_string(),
+            files: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            ..Default::default()
+        };
 
-        // Basic checks on disk info
-        assert!(!disk_info.fs_type.is_empty());
-        assert!(disk_info.total > 0);
+        // Missing files are reported without an error
+        let resps = disk.read_multiple(req.clone()).await.unwrap();
+        assert_eq!(resps.len(), 3);
+        assert_eq!(resps[0].data, b"aaa");
+        assert!(resps[0].mod_time.is_some());
+        assert!(!resps[1].exists);
+        assert!(resps[1].error.is_empty());
+        assert!(resps[2].exists);
+
+        // abort404 stops at the first missing file
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                abort404: true,
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(resps.len(), 2);
+        assert!(!resps[1].exists);
+
+        // max_results caps the number of files found
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                max_results: 1,
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(resps.len(), 1);
+        assert_eq!(resps[0].file, "a");
+
+        // Files over max_size are flagged without data and the rest are still read
+        disk.write_all("multi-vol", "prefix/a", Bytes::from("aaaaaa")).await.unwrap();
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                max_size: 4,
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(resps.len(), 3);
+        assert!(resps[0].exists);
+        assert!(resps[0].data.is_empty());
+        assert!(resps[0].error.contains("max size"));
+        assert_eq!(resps[2].data, b"ccc");
 
-        // Clean up the test directory
         let _ = fs::remove_dir_all(&test_dir).await;
     }
 
-    #[test]
-    fn test_is_valid_volname() {
-        // Valid volume names (length >= 3)
-        assert!(LocalDisk::is_valid_volname("valid-name"));
-        assert!(LocalDisk::is_valid_volname("test123"));
-        assert!(LocalDisk::is_valid_volname("my-bucket"));
+    #[tokio::test]
+    async fn test_local_disk_volume_usage() {
+        let test_dir = "./test_local_disk_volume_usage";
+        fs::create_dir_all(&test_dir).await.unwrap();
 
-        // Test minimum length requirement
-        assert!(!LocalDisk::is_valid_volname(""));
-        assert!(!LocalDisk::is_valid_volname("a"));
-        assert!(!LocalDisk::is_valid_volname("ab"));
-        assert!(LocalDisk::is_valid_volname("abc"));
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
 
-        // Note: The current implementation doesn't check for system volume names
-        // It only checks length and platform-specific special characters
-        // System volume names are valid according to the current implementation
-        assert!(LocalDisk::is_valid_volname(RUSTFS_META_BUCKET));
-        assert!(LocalDisk::is_valid_volname(super::super::RUSTFS_META_TMP_BUCKET));
+        disk.make_volume("usage-vol").await.unwrap();
 
-        // Testing platform-specific behavior for special characters
-        #[cfg(windows)]
-        {
-            // On Windows systems, these should be invalid
+        let mut expected_bytes = 0;
+        for (obj, versions) in [("a", 2), ("dir/b", 1), ("dir/c", 3)] {
+            let mut meta = FileMeta::new();
+            for _ in 0..versions {
+                let mut fi = FileInfo::new(obj, 2, 2);
+                fi.version_id = Some(Uuid::new_v4());
+                fi.mod_time = Some(OffsetDateTime::now_utc());
+                meta.add_version(fi).unwrap();
+            }
+            let buf = meta.marshal_msg().unwrap();
+            expected_bytes += buf.len() as u64;
+            disk.write_all("usage-vol", &format!("{obj}/{STORAGE_FORMAT_FILE}"), buf.into())
+                .await
+                .unwrap();
+        }
+
+        // Part files count towards bytes but not objects
+        disk.write_all("usage-vol", "a/data-dir/part.1", Bytes::from(vec![0u8; 1000]))
+            .await
+            .unwrap();
+        expected_bytes += 1000;
+
+        let usage = disk.volume_usage("usage-vol").await.unwrap();
+        assert_eq!(
+            usage,
+            VolumeUsage {
+                bytes: expected_bytes,
+                objects: 3,
+                versions: 6,
+            }
+        );
+
+        // The drive root only adds the reserved .rustfs.sys internals, which are skipped
+        assert_eq!(disk.volume_usage("").await.unwrap(), usage);
+
+        disk.make_volume("empty-vol").await.unwrap();
+        assert_eq!(disk.volume_usage("empty-vol").await.unwrap(), VolumeUsage::default());
+        assert_eq!(disk.volume_usage("missing-vol").await.unwrap_err(), DiskError::VolumeNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_stat_paths() {
+        let test_dir = "./test_local_disk_stat_paths";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        disk.make_volume("stat-vol").await.unwrap();
+        disk.write_all("stat-vol", "dir/a", Bytes::from("aaa")).await.unwrap();
+        disk.write_all("stat-vol", "b", Bytes::from("bbbbb")).await.unwrap();
+
+        let paths = ["dir/a", "missing", "b", "dir", "b/under-file"].map(String::from);
+        let stats = disk.stat_paths("stat-vol", &paths).await.unwrap();
+        assert_eq!(stats.len(), paths.len());
+
+        let a = stats[0].as_ref().unwrap();
+        assert_eq!(a.name, "dir/a");
+        assert_eq!(a.size, 3);
+        assert!(a.mod_time.is_some());
+        assert!(stats[1].is_none());
+        assert_eq!(stats[2].as_ref().unwrap().size, 5);
+        // Directories and paths through a regular file are not stat-able files
+        assert!(stats[3].is_none());
+        assert!(stats[4].is_none());
+
+        assert_eq!(disk.stat_paths("missing-vol", &paths).await.unwrap_err(), DiskError::VolumeNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_multiple_pattern() {
+        let test_dir = "./test_local_disk_read_multiple_pattern";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        disk.make_volume("multi-vol").await.unwrap();
+        for file in ["log-1.json", "log-2.json", "log-10.json", "other.json", "nested/log-3.json"] {
+            disk.write_all("multi-vol", &format!("prefix/{file}"), Bytes::from(file))
+                .await
+                .unwrap();
+        }
+
+        let req = ReadMultipleReq {
+            bucket: "multi-vol".to_string(),
+            prefix: "prefix".to_string(),
+            pattern: Some("log-?.json".to_string()),
+            ..Default::default()
+        };
+
+        // Only direct matches of the glob are read, in lexical order
+        let resps = disk.read_multiple(req.clone()).await.unwrap();
+        let files: Vec<_> = resps.iter().map(|r| r.file.as_str()).collect();
+        assert_eq!(files, ["log-1.json", "log-2.json"]);
+        assert!(resps.iter().all(|r| r.exists && r.data == r.file.as_bytes()));
+
+        // `*` also matches across directories
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                pattern: Some("*log-*".to_string()),
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(resps.len(), 4);
+        assert!(resps.iter().any(|r| r.file == "nested/log-3.json"));
+
+        // max_results still bounds the expanded list
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                pattern: Some("*.json".to_string()),
+                max_results: 2,
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        let files: Vec<_> = resps.iter().map(|r| r.file.as_str()).collect();
+        assert_eq!(files, ["log-1.json", "log-10.json"]);
+
+        // An explicit file list takes precedence over the pattern
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                files: vec!["other.json".to_string()],
+                ..req.clone()
+            })
+            .await
+            .unwrap();
+        assert_eq!(resps.len(), 1);
+        assert_eq!(resps[0].data, b"other.json");
+
+        // A missing prefix expands to nothing
+        let resps = disk
+            .read_multiple(ReadMultipleReq {
+                prefix: "missing".to_string(),
+                ..req
+            })
+            .await
+            .unwrap();
+        assert!(resps.is_empty());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_write_metadata() {
+        let test_dir = "./test_local_disk_write_metadata";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("meta-vol").await.unwrap();
+
+        let file_info = |version_id| FileInfo {
+            volume: "meta-vol".to_string(),
+            name: "object".to_string(),
+            version_id: Some(version_id),
+            mod_time: Some(OffsetDateTime::now_utc()),
+            ..Default::default()
+        };
+
+        let (v1, v2) = (Uuid::new_v4(), Uuid::new_v4());
+        disk.write_metadata("", "meta-vol", "object", file_info(v1)).await.unwrap();
+
+        let fi = disk
+            .read_version("", "meta-vol", "object", &v1.to_string(), &ReadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(fi.version_id, Some(v1));
+        assert!(
+            disk.read_all("meta-vol", &format!("object/{STORAGE_FORMAT_FILE_BACKUP}"))
+                .await
+                .is_err()
+        );
+
+        // Overwriting keeps the previous xl.meta as a backup
+        disk.write_metadata("", "meta-vol", "object", file_info(v2)).await.unwrap();
+
+        let current = disk
+            .read_all("meta-vol", &format!("object/{STORAGE_FORMAT_FILE}"))
+            .await
+            .unwrap();
+        assert_eq!(FileMeta::load(&current).unwrap().versions.len(), 2);
+        let backup = disk
+            .read_all("meta-vol", &format!("object/{STORAGE_FORMAT_FILE_BACKUP}"))
+            .await
+            .unwrap();
+        assert_eq!(FileMeta::load(&backup).unwrap().versions.len(), 1);
+
+        // An unknown original volume is rejected
+        let err = disk
+            .write_metadata("missing-vol", "meta-vol", "object", file_info(Uuid::new_v4()))
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::VolumeNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_version_inline_data() {
+        let test_dir = tempfile::TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("inline-vol").await.unwrap();
+
+        let payload = Bytes::from("small object");
+        let version_id = Uuid::new_v4();
+        let mut fi = FileInfo {
+            volume: "inline-vol".to_string(),
+            name: "small".to_string(),
+            version_id: Some(version_id),
+            data_dir: Some(Uuid::new_v4()),
+            mod_time: Some(OffsetDateTime::now_utc()),
+            size: payload.len() as i64,
+            data: Some(payload.clone()),
+            ..Default::default()
+        };
+        fi.add_object_part(1, String::new(), payload.len(), fi.mod_time, payload.len() as i64, None, None);
+        disk.write_metadata("", "inline-vol", "small", fi.clone()).await.unwrap();
+
+        let read = |read_data| ReadOptions {
+            read_data,
+            ..Default::default()
+        };
+
+        let got = disk
+            .read_version("", "inline-vol", "small", &version_id.to_string(), &read(true))
+            .await
+            .unwrap();
+        assert_eq!(got.data, Some(payload.clone()));
+        assert!(got.inline_data());
+
+        let got = disk
+            .read_version("", "inline-vol", "small", &version_id.to_string(), &read(false))
+            .await
+            .unwrap();
+        assert!(got.data.is_none());
+
+        // A user metadata projection leaves the data out even when it is asked for
+        let user_only = ReadOptions {
+            read_data: true,
+            projection: crate::disk::MetadataProjection::UserOnly,
+            ..Default::default()
+        };
+        let got = disk
+            .read_version("", "inline-vol", "small", &version_id.to_string(), &user_only)
+            .await
+            .unwrap();
+        assert!(got.data.is_none());
+        assert_eq!(got.version_id, Some(version_id));
+
+        // Inline data without parts is still the object's data
+        let version_id = Uuid::new_v4();
+        fi.name = "no-parts".to_string();
+        fi.version_id = Some(version_id);
+        fi.parts.clear();
+        disk.write_metadata("", "inline-vol", "no-parts", fi).await.unwrap();
+        let got = disk
+            .read_version("", "inline-vol", "no-parts", &version_id.to_string(), &read(true))
+            .await
+            .unwrap();
+        assert_eq!(got.data, Some(payload));
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_version_free_versions() {
+        use rustfs_filemeta::{FREE_VERSION, FileMetaVersion, MetaDeleteMarker, VersionType};
+        use rustfs_utils::http::headers::RESERVED_METADATA_PREFIX_LOWER;
+
+        let test_dir = "./test_local_disk_read_version_free_versions";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("free-vol").await.unwrap();
+
+        // Deleting a tiered object leaves a free version behind until its remote copy is gone
+        let free_vid = Uuid::new_v4();
+        let mut fm = FileMeta::new();
+        fm.add_version_filemata(FileMetaVersion {
+            version_type: VersionType::Delete,
+            delete_marker: Some(MetaDeleteMarker {
+                version_id: Some(free_vid),
+                mod_time: Some(OffsetDateTime::now_utc()),
+                meta_sys: HashMap::from([(format!("{RESERVED_METADATA_PREFIX_LOWER}{FREE_VERSION}"), vec![])]),
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+        disk.write_all("free-vol", &format!("object/{STORAGE_FORMAT_FILE}"), fm.marshal_msg().unwrap().into())
+            .await
+            .unwrap();
+
+        let read = |incl_free_versions| ReadOptions {
+            incl_free_versions,
+            ..Default::default()
+        };
+
+        let err = disk
+            .read_version("", "free-vol", "object", &free_vid.to_string(), &read(false))
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::FileVersionNotFound);
+        assert!(disk.read_version("", "free-vol", "object", "", &read(false)).await.is_err());
+
+        let fi = disk
+            .read_version("", "free-vol", "object", &free_vid.to_string(), &read(true))
+            .await
+            .unwrap();
+        assert_eq!(fi.version_id, Some(free_vid));
+        assert!(fi.tier_free_version());
+        let fi = disk.read_version("", "free-vol", "object", "", &read(true)).await.unwrap();
+        assert_eq!(fi.version_id, Some(free_vid));
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_xl() {
+        let test_dir = "./test_local_disk_read_xl";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("xl-vol").await.unwrap();
+
+        let payload = Bytes::from("inline payload");
+        let fi = FileInfo {
+            volume: "xl-vol".to_string(),
+            name: "object".to_string(),
+            version_id: Some(Uuid::new_v4()),
+            mod_time: Some(OffsetDateTime::now_utc()),
+            data: Some(payload.clone()),
+            ..Default::default()
+        };
+        disk.write_metadata("", "xl-vol", "object", fi.clone()).await.unwrap();
+
+        // Metadata only reads leave the inline data out
+        let full = disk.read_xl("xl-vol", "object", true).await.unwrap();
+        let meta_only = disk.read_xl("xl-vol", "object", false).await.unwrap();
+        assert!(meta_only.buf.len() < full.buf.len());
+        assert!(full.buf.windows(payload.len()).any(|w| w == payload.as_ref()));
+        assert!(!meta_only.buf.windows(payload.len()).any(|w| w == payload.as_ref()));
+        assert_eq!(FileMeta::load(&meta_only.buf).unwrap().versions.len(), 1);
+
+        assert_eq!(disk.read_xl("xl-vol", "missing", false).await.unwrap_err(), DiskError::FileNotFound);
+
+        // A corrupt xl.meta falls back to the backup
+        disk.write_metadata(
+            "",
+            "xl-vol",
+            "object",
+            FileInfo {
+                version_id: Some(Uuid::new_v4()),
+                mod_time: Some(OffsetDateTime::now_utc()),
+                data: None,
+                ..fi
+            },
+        )
+        .await
+        .unwrap();
+        let xl_path = disk
+            .get_object_path("xl-vol", &format!("object/{STORAGE_FORMAT_FILE}"))
+            .unwrap();
+        fs::write(&xl_path, b"garbage").await.unwrap();
+
+        // The backup predates the last write, so it is not served
+        for read_data in [true, false] {
+            assert_eq!(disk.read_xl("xl-vol", "object", read_data).await.unwrap_err(), DiskError::OutdatedXLMeta);
+        }
+
+        // A corrupt xl.meta no newer than the backup falls back to it
+        let backup_path = disk
+            .get_object_path("xl-vol", &format!("object/{STORAGE_FORMAT_FILE_BACKUP}"))
+            .unwrap();
+        let backup_mtime = fs::metadata(&backup_path).await.unwrap().modified().unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&xl_path)
+            .unwrap()
+            .set_modified(backup_mtime)
+            .unwrap();
+
+        for read_data in [true, false] {
+            let raw = disk.read_xl("xl-vol", "object", read_data).await.unwrap();
+            assert_eq!(FileMeta::load(&raw.buf).unwrap().versions.len(), 1);
+        }
+
+        fs::write(&backup_path, b"garbage").await.unwrap();
+        assert_eq!(disk.read_xl("xl-vol", "object", true).await.unwrap_err(), DiskError::OutdatedXLMeta);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_update_metadata() {
+        let test_dir = "./test_local_disk_update_metadata";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("update-vol").await.unwrap();
+
+        let mut fi = FileInfo {
+            volume: "update-vol".to_string(),
+            name: "object".to_string(),
+            version_id: Some(Uuid::new_v4()),
+            mod_time: Some(OffsetDateTime::now_utc()),
+            ..Default::default()
+        };
+        disk.write_metadata("", "update-vol", "object", fi.clone()).await.unwrap();
+
+        let read_meta = |fi: &FileInfo| {
+            let version_id = fi.version_id.unwrap().to_string();
+            let disk = &disk;
+            async move {
+                disk.read_version("", "update-vol", "object", &version_id, &ReadOptions::default())
+                    .await
+                    .unwrap()
+                    .metadata
+            }
+        };
+
+        fi.metadata.insert("x-amz-meta-color".to_string(), "blue".to_string());
+        disk.update_metadata("update-vol", "object", fi.clone(), &UpdateMetadataOpts::default())
+            .await
+            .unwrap();
+        assert_eq!(read_meta(&fi).await.get("x-amz-meta-color").map(String::as_str), Some("blue"));
+
+        // no_persistence leaves xl.meta on disk untouched
+        let xl_path = disk
+            .get_object_path("update-vol", &format!("object/{STORAGE_FORMAT_FILE}"))
+            .unwrap();
+        let before = fs::read(&xl_path).await.unwrap();
+        fi.metadata.insert("x-amz-meta-color".to_string(), "red".to_string());
+        disk.update_metadata("update-vol", "object", fi.clone(), &UpdateMetadataOpts { no_persistence: true })
+            .await
+            .unwrap();
+        assert_eq!(fs::read(&xl_path).await.unwrap(), before);
+        assert_eq!(read_meta(&fi).await.get("x-amz-meta-color").map(String::as_str), Some("blue"));
+
+        let cached = get_global_file_cache().get_metadata(xl_path.clone()).await.unwrap();
+        let (_, version) = cached.find_version(fi.version_id).unwrap();
+        assert_eq!(version.object.unwrap().meta_user.get("x-amz-meta-color").map(String::as_str), Some("red"));
+
+        // Unknown versions are reported as missing
+        let err = disk
+            .update_metadata(
+                "update-vol",
+                "object",
+                FileInfo {
+                    version_id: Some(Uuid::new_v4()),
+                    ..fi
+                },
+                &UpdateMetadataOpts::default(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::FileVersionNotFound);
+
+        get_global_file_cache().invalidate(&xl_path).await;
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_delete_version() {
+        let test_dir = "./test_local_disk_delete_version";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("del-vol").await.unwrap();
+
+        let mut versions = Vec::new();
+        for _ in 0..2 {
+            let fi = FileInfo {
+                volume: "del-vol".to_string(),
+                name: "object".to_string(),
+                version_id: Some(Uuid::new_v4()),
+                data_dir: Some(Uuid::new_v4()),
+                mod_time: Some(OffsetDateTime::now_utc()),
+                size: 3,
+                ..Default::default()
+            };
+            disk.write_metadata("", "del-vol", "object", fi.clone()).await.unwrap();
+            disk.write_all("del-vol", &format!("object/{}/part.1", fi.data_dir.unwrap()), Bytes::from("abc"))
+                .await
+                .unwrap();
+            versions.push(fi);
+        }
+
+        let data_dir_exists = |fi: &FileInfo| {
+            disk.get_object_path("del-vol", &format!("object/{}", fi.data_dir.unwrap()))
+                .unwrap()
+                .exists()
+        };
+
+        // Deleting one of several versions rewrites xl.meta and drops only its data dir
+        disk.delete_version("del-vol", "object", versions[0].clone(), false, DeleteOptions::default())
+            .await
+            .unwrap();
+        let raw = disk.read_xl("del-vol", "object", false).await.unwrap();
+        let meta = FileMeta::load(&raw.buf).unwrap();
+        assert_eq!(meta.versions.len(), 1);
+        assert!(meta.find_version(versions[1].version_id).is_ok());
+        assert!(!data_dir_exists(&versions[0]));
+        assert!(data_dir_exists(&versions[1]));
+
+        // Deleting the last version removes xl.meta and the data dir
+        disk.delete_version("del-vol", "object", versions[1].clone(), false, DeleteOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(disk.read_xl("del-vol", "object", false).await.unwrap_err(), DiskError::FileNotFound);
+        assert!(!data_dir_exists(&versions[1]));
+
+        // Missing versions are reported, unless a delete marker is forced
+        let marker = FileInfo {
+            version_id: Some(Uuid::new_v4()),
+            deleted: true,
+            mod_time: Some(OffsetDateTime::now_utc()),
+            data_dir: None,
+            size: 0,
+            ..versions[0].clone()
+        };
+        assert_eq!(
+            disk.delete_version("del-vol", "object", marker.clone(), false, DeleteOptions::default())
+                .await
+                .unwrap_err(),
+            DiskError::FileVersionNotFound
+        );
+        disk.delete_version("del-vol", "object", marker.clone(), true, DeleteOptions::default())
+            .await
+            .unwrap();
+        let raw = disk.read_xl("del-vol", "object", false).await.unwrap();
+        assert!(FileMeta::load(&raw.buf).unwrap().find_version(marker.version_id).is_ok());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_delete_versions() {
+        let test_dir = "./test_local_disk_delete_versions";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("dels-vol").await.unwrap();
+
+        let file_info = |name: &str| FileInfo {
+            volume: "dels-vol".to_string(),
+            name: name.to_string(),
+            version_id: Some(Uuid::new_v4()),
+            mod_time: Some(OffsetDateTime::now_utc()),
+            ..Default::default()
+        };
+
+        let (a1, a2, b1) = (file_info("a"), file_info("a"), file_info("b"));
+        for fi in [&a1, &a2, &b1] {
+            disk.write_metadata("", "dels-vol", &fi.name, fi.clone()).await.unwrap();
+        }
+
+        let versions = vec![
+            FileInfoVersions {
+                name: "a".to_string(),
+                versions: vec![a1.clone()],
+                ..Default::default()
+            },
+            FileInfoVersions {
+                name: "missing".to_string(),
+                versions: vec![file_info("missing")],
+                ..Default::default()
+            },
+            FileInfoVersions {
+                name: "b".to_string(),
+                versions: vec![b1.clone(), file_info("b")],
+                ..Default::default()
+            },
+        ];
+
+        let errs = disk.delete_versions("dels-vol", versions, DeleteOptions::default()).await;
+        assert_eq!(errs.len(), 3);
+        assert!(errs[0].is_none());
+        assert_eq!(errs[1], Some(DiskError::FileNotFound));
+        // Versions that are already gone are skipped
+        assert!(errs[2].is_none());
+
+        let raw = disk.read_xl("dels-vol", "a", false).await.unwrap();
+        let meta = FileMeta::load(&raw.buf).unwrap();
+        assert_eq!(meta.versions.len(), 1);
+        assert!(meta.find_version(a2.version_id).is_ok());
+        assert_eq!(disk.read_xl("dels-vol", "b", false).await.unwrap_err(), DiskError::FileNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_rename_data() {
+        let test_dir = "./test_local_disk_rename_data";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volumes(vec!["src-vol", "dst-vol"]).await.unwrap();
+
+        let upload = |tmp: &'static str| {
+            let disk = &disk;
+            async move {
+                let fi = FileInfo {
+                    volume: "dst-vol".to_string(),
+                    name: "object".to_string(),
+                    data_dir: Some(Uuid::new_v4()),
+                    mod_time: Some(OffsetDateTime::now_utc()),
+                    size: 3,
+                    ..Default::default()
+                };
+                disk.write_all("src-vol", &format!("{tmp}/{}/part.1", fi.data_dir.unwrap()), Bytes::from("abc"))
+                    .await
+                    .unwrap();
+                fi
+            }
+        };
+        let object_path = |p: String| disk.get_object_path("dst-vol", &p).unwrap();
+
+        // Fresh destination
+        let fi1 = upload("tmp-1").await;
+        let d1 = fi1.data_dir.unwrap();
+        let resp = disk
+            .rename_data("src-vol", "tmp-1", fi1.clone(), "dst-vol", "object")
+            .await
+            .unwrap();
+        assert!(resp.old_data_dir.is_none());
+        assert!(object_path(format!("object/{d1}/part.1")).exists());
+        assert!(!disk.get_object_path("src-vol", "tmp-1").unwrap().exists());
+
+        // Overwriting the same version hands back the previous data dir
+        let fi2 = upload("tmp-2").await;
+        let d2 = fi2.data_dir.unwrap();
+        let resp = disk
+            .rename_data("src-vol", "tmp-2", fi2.clone(), "dst-vol", "object")
+            .await
+            .unwrap();
+        assert_eq!(resp.old_data_dir, Some(d1));
+        assert!(object_path(format!("object/{d2}/part.1")).exists());
+        assert!(object_path(format!("object/{d1}/{STORAGE_FORMAT_FILE_BACKUP}")).exists());
+
+        let fi = disk
+            .read_version("", "dst-vol", "object", "", &ReadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(fi.data_dir, Some(d2));
+
+        // Undoing the write restores the preserved xl.meta
+        disk.delete_version(
+            "dst-vol",
+            "object",
+            fi2,
+            false,
+            DeleteOptions {
+                undo_write: true,
+                old_data_dir: Some(d1),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let fi = disk
+            .read_version("", "dst-vol", "object", "", &ReadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(fi.data_dir, Some(d1));
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_delete_undo_write() {
+        let test_dir = "./test_local_disk_delete_undo_write";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volumes(vec!["src-vol", "dst-vol"]).await.unwrap();
+
+        let mut data_dirs = Vec::new();
+        for (tmp, body) in [("tmp-1", "old"), ("tmp-2", "new")] {
+            let fi = FileInfo {
+                volume: "dst-vol".to_string(),
+                name: "object".to_string(),
+                data_dir: Some(Uuid::new_v4()),
+                mod_time: Some(OffsetDateTime::now_utc()),
+                size: 3,
+                ..Default::default()
+            };
+            disk.write_all("src-vol", &format!("{tmp}/{}/part.1", fi.data_dir.unwrap()), Bytes::from(body))
+                .await
+                .unwrap();
+            disk.rename_data("src-vol", tmp, fi.clone(), "dst-vol", "object")
+                .await
+                .unwrap();
+            data_dirs.push(fi.data_dir.unwrap());
+        }
+        let (d1, d2) = (data_dirs[0], data_dirs[1]);
+
+        disk.delete(
+            "dst-vol",
+            "object",
+            DeleteOptions {
+                undo_write: true,
+                old_data_dir: Some(d1),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let fi = disk
+            .read_version("", "dst-vol", "object", "", &ReadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(fi.data_dir, Some(d1));
+        assert_eq!(disk.read_all("dst-vol", &format!("object/{d1}/part.1")).await.unwrap(), "old");
+        assert!(!disk.get_object_path("dst-vol", &format!("object/{d2}")).unwrap().exists());
+        assert!(
+            !disk
+                .get_object_path("dst-vol", &format!("object/{d1}/{STORAGE_FORMAT_FILE_BACKUP}"))
+                .unwrap()
+                .exists()
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_verify_file() {
+        use crate::erasure_coding::BitrotWriter;
+        use rustfs_filemeta::{ChecksumInfo, ErasureInfo};
+        use std::io::Cursor;
+
+        let test_dir = "./test_local_disk_verify_file";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("verify-vol").await.unwrap();
+
+        let data_dir = Uuid::new_v4();
+        let data = b"0123456789abcdefghijklmn";
+        let algos = [HashAlgorithm::HighwayHash256S, HashAlgorithm::Blake3, HashAlgorithm::Crc32c];
+
+        let mut fi = FileInfo {
+            data_dir: Some(data_dir),
+            erasure: ErasureInfo {
+                data_blocks: 1,
+                block_size: 8,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for (i, algo) in algos.iter().enumerate() {
+            let number = i + 1;
+            let mut writer = BitrotWriter::new(Cursor::new(Vec::new()), fi.erasure.shard_size(), algo.clone());
+            for chunk in data.chunks(fi.erasure.shard_size()) {
+                writer.write(chunk).await.unwrap();
+            }
+            let buf = writer.into_inner().into_inner();
+            disk.write_all("verify-vol", &format!("object/{data_dir}/part.{number}"), buf.into())
+                .await
+                .unwrap();
+
+            fi.parts.push(ObjectPartInfo {
+                number,
+                size: data.len(),
+                ..Default::default()
+            });
+            fi.erasure.checksums.push(ChecksumInfo {
+                part_number: number,
+                algorithm: algo.clone(),
+                ..Default::default()
+            });
+        }
+
+        let resp = disk.verify_file("verify-vol", "object", &fi).await.unwrap();
+        assert_eq!(resp.results, vec![CHECK_PART_SUCCESS; 3]);
+
+        // Flip a data byte in part 2 and drop part 3
+        let part2 = disk
+            .get_object_path("verify-vol", &format!("object/{data_dir}/part.2"))
+            .unwrap();
+        let mut buf = fs::read(&part2).await.unwrap();
+        let last = buf.len() - 1;
+        buf[last] ^= 0xff;
+        fs::write(&part2, buf).await.unwrap();
+        fs::remove_file(
+            disk.get_object_path("verify-vol", &format!("object/{data_dir}/part.3"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let resp = disk.verify_file("verify-vol", "object", &fi).await.unwrap();
+        assert_eq!(resp.results, vec![CHECK_PART_SUCCESS, CHECK_PART_FILE_CORRUPT, CHECK_PART_FILE_NOT_FOUND]);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_local_disk_create_file_direct_io() {
+        let test_dir = "./test_local_disk_create_file_direct_io";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap().with_direct_io(true);
+        if !disk.direct_io {
+            let _ = fs::remove_dir_all(&test_dir).await;
+            return;
+        }
+        disk.make_volume("direct-vol").await.unwrap();
+
+        let small = vec![1u8; 1000];
+        let large: Vec<u8> = (0..DIRECT_IO_MIN_FILE_SIZE as usize * 3 + 777).map(|i| i as u8).collect();
+        for (name, data, size) in [
+            ("small", &small, small.len() as i64),
+            ("large", &large, large.len() as i64),
+            ("unknown", &large, -1),
+        ] {
+            let mut w = disk.create_file("", "direct-vol", name, size).await.unwrap();
+            w.write_all(data).await.unwrap();
+            w.shutdown().await.unwrap();
+            drop(w);
+
+            let got = disk.read_all("direct-vol", name).await.unwrap();
+            assert_eq!(got.as_ref(), data.as_slice(), "{name}");
+        }
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_disk_info() {
+        let test_dir = "./test_local_disk_info";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        let disk_info_opts = DiskInfoOptions {
+            disk_id: "test-disk".to_string(),
+            metrics: true,
+            noop: false,
+        };
+
+        let disk_info = disk.disk_info(&disk_info_opts).await.unwrap();
+
+        // Basic checks on disk info
+        assert!(!disk_info.fs_type.is_empty());
+        assert!(disk_info.total > 0);
+
+        // Clean up the test directory
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[test]
+    fn test_is_valid_volname() {
+        // Valid volume names (length >= 3)
+        assert!(LocalDisk::is_valid_volname("valid-name"));
+        assert!(LocalDisk::is_valid_volname("test123"));
+        assert!(LocalDisk::is_valid_volname("my-bucket"));
+
+        // Test minimum length requirement
+        assert!(!LocalDisk::is_valid_volname(""));
+        assert!(!LocalDisk::is_valid_volname("a"));
+        assert!(!LocalDisk::is_valid_volname("ab"));
+        assert!(LocalDisk::is_valid_volname("abc"));
+
+        // Test maximum length requirement
+        assert!(LocalDisk::is_valid_volname(&"a".repeat(63)));
+        assert!(!LocalDisk::is_valid_volname(&"a".repeat(64)));
+
+        // Traversal, backslashes and hidden names are rejected
+        assert!(!LocalDisk::is_valid_volname("invalid\\name"));
+        assert!(!LocalDisk::is_valid_volname(".."));
+        assert!(!LocalDisk::is_valid_volname("../etc"));
+        assert!(!LocalDisk::is_valid_volname(".hidden"));
+
+        // Heal makes bucket/object volumes, their path must stay inside the bucket
+        assert!(LocalDisk::is_valid_volname("bucket/object"));
+        assert!(LocalDisk::is_valid_volname("bucket/dir/object/"));
+        assert!(!LocalDisk::is_valid_volname("bucket/../etc"));
+        assert!(!LocalDisk::is_valid_volname("bucket//object"));
+        assert!(!LocalDisk::is_valid_volname("bucket/"));
+        assert!(!LocalDisk::is_valid_volname("ab/object"));
+        assert!(!LocalDisk::is_valid_volname(".hidden/object"));
+
+        // The reserved system volume and volumes nested under it are the exception
+        assert!(LocalDisk::is_valid_volname(RUSTFS_META_BUCKET));
+        assert!(LocalDisk::is_valid_volname(super::super::RUSTFS_META_TMP_BUCKET));
+        assert!(LocalDisk::is_valid_volname(RUSTFS_META_TMP_DELETED_BUCKET));
+        assert!(!LocalDisk::is_valid_volname(".rustfs.sys/../etc"));
+        assert!(!LocalDisk::is_valid_volname(".rustfs.sys//tmp"));
+        assert!(!LocalDisk::is_valid_volname(".rustfs.system"));
+
+        // Testing platform-specific behavior for special characters
+        #[cfg(windows)]
+        {
+            // On Windows systems, these should be invalid
             assert!(!LocalDisk::is_valid_volname("invalid\\name"));
             assert!(!LocalDisk::is_valid_volname("invalid:name"));
             assert!(!LocalDisk::is_valid_volname("invalid|name"));
@@ -2795,15 +5276,998 @@ mod test {
             assert!(!LocalDisk::is_valid_volname("invalid?name"));
             assert!(!LocalDisk::is_valid_volname("invalid*name"));
             assert!(!LocalDisk::is_valid_volname("invalid\"name"));
+
+            // Reserved device names
+            assert!(!LocalDisk::is_valid_volname("con"));
+            assert!(!LocalDisk::is_valid_volname("NUL.txt"));
+            assert!(!LocalDisk::is_valid_volname("com1"));
+            assert!(!LocalDisk::is_valid_volname("LPT9"));
+            assert!(LocalDisk::is_valid_volname("com10"));
+            assert!(LocalDisk::is_valid_volname("console"));
+        }
+
+        #[cfg(not(windows))]
+        {
+            // On non-Windows systems special characters other than separators are allowed
+            assert!(LocalDisk::is_valid_volname("valid:name"));
+            assert!(LocalDisk::is_valid_volname("con"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_make_volume_invalid_names() {
+        let test_dir = "./test_local_disk_make_volume_invalid";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        for name in ["..", "a/b", "../escape", ".hidden", "ab"] {
+            assert_eq!(disk.make_volume(name).await.unwrap_err(), DiskError::VolumeAccessDenied, "{name}");
+        }
+
+        // A bad name fails the whole batch before anything is created
+        assert_eq!(
+            disk.make_volumes(vec!["good-vol", "bad/vol"]).await.unwrap_err(),
+            DiskError::VolumeAccessDenied
+        );
+        assert!(!disk.get_bucket_path("good-vol").unwrap().exists());
+
+        disk.make_volumes(vec!["good-vol", RUSTFS_META_TMP_DELETED_BUCKET])
+            .await
+            .unwrap();
+        assert!(disk.get_bucket_path("good-vol").unwrap().exists());
+        assert_eq!(disk.make_volume("good-vol").await.unwrap_err(), DiskError::VolumeExists);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_make_volume_strict() {
+        let test_dir = "./test_local_disk_make_volume_strict";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        // Of several racing creators only one wins
+        let results = futures::future::join_all((0..8).map(|_| disk.make_volume_strict("race-vol"))).await;
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert!(
+            results
+                .iter()
+                .filter_map(|r| r.as_ref().err())
+                .all(|e| *e == DiskError::VolumeExists)
+        );
+
+        // make_volumes tolerates volumes that already exist
+        disk.make_volumes(vec!["race-vol", "other-vol"]).await.unwrap();
+        assert_eq!(disk.make_volume_strict("other-vol").await.unwrap_err(), DiskError::VolumeExists);
+
+        // A deleted volume can be made again
+        disk.delete_volume("other-vol").await.unwrap();
+        disk.make_volume_strict("other-vol").await.unwrap();
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_make_volumes_concurrent() {
+        let test_dir = "./test_local_disk_make_volumes_concurrent";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        let names: Vec<String> = (0..64).map(|i| format!("volume-{i:02}")).collect();
+        disk.make_volume("volume-07").await.unwrap();
+
+        // Existing volumes are tolerated, the rest are all created
+        disk.make_volumes(names.iter().map(String::as_str).collect()).await.unwrap();
+        for name in &names {
+            assert!(disk.get_bucket_path(name).unwrap().is_dir(), "{name}");
+        }
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_rename_file() {
+        let test_dir = "./test_local_disk_rename_file";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volumes(vec!["src-vol", "dst-vol"]).await.unwrap();
+
+        disk.write_all("src-vol", "obj/part.1", Bytes::from_static(b"part data"))
+            .await
+            .unwrap();
+
+        // Missing destination parents are created
+        disk.rename_file("src-vol", "obj/part.1", "dst-vol", "a/b/c/part.1")
+            .await
+            .unwrap();
+        assert!(!disk.get_object_path("src-vol", "obj/part.1").unwrap().exists());
+        assert_eq!(
+            fs::read(disk.get_object_path("dst-vol", "a/b/c/part.1").unwrap())
+                .await
+                .unwrap(),
+            b"part data"
+        );
+
+        assert_eq!(
+            disk.rename_file("src-vol", "obj/part.1", "dst-vol", "other")
+                .await
+                .unwrap_err(),
+            DiskError::FileNotFound
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_rename_part_writes_meta() {
+        let test_dir = "./test_local_disk_rename_part_meta";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("part-vol").await.unwrap();
+
+        let part = ObjectPartInfo {
+            etag: "etag-1".to_string(),
+            number: 1,
+            size: 9,
+            actual_size: 9,
+            ..Default::default()
+        };
+        disk.write_all("part-vol", "upload/part.1", Bytes::from_static(b"part data"))
+            .await
+            .unwrap();
+        disk.rename_part(
+            "part-vol",
+            "upload/part.1",
+            "part-vol",
+            "obj/uploadid/part.1",
+            Bytes::from(part.marshal_msg().unwrap()),
+        )
+        .await
+        .unwrap();
+
+        let parts = disk
+            .read_parts("part-vol", &["obj/uploadid/part.1.meta".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(parts[0].error, None);
+        assert_eq!(parts[0].number, 1);
+        assert_eq!(parts[0].etag, "etag-1");
+        assert_eq!(
+            fs::read(disk.get_object_path("part-vol", "obj/uploadid/part.1").unwrap())
+                .await
+                .unwrap(),
+            b"part data"
+        );
+
+        // A missing part leaves no metadata behind
+        assert_eq!(
+            disk.rename_part("part-vol", "upload/part.2", "part-vol", "obj/uploadid/part.2", Bytes::new())
+                .await
+                .unwrap_err(),
+            DiskError::FileNotFound
+        );
+        assert!(!disk.get_object_path("part-vol", "obj/uploadid/part.2.meta").unwrap().exists());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_append_file_creates() {
+        let test_dir = "./test_local_disk_append_file_creates";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("append-vol").await.unwrap();
+
+        for chunk in [&b"first"[..], b" second", b" third"] {
+            let mut w = disk.append_file("append-vol", "new/dir/part.1").await.unwrap();
+            w.write_all(chunk).await.unwrap();
+            w.shutdown().await.unwrap();
+        }
+
+        assert_eq!(
+            fs::read(disk.get_object_path("append-vol", "new/dir/part.1").unwrap())
+                .await
+                .unwrap(),
+            b"first second third"
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_local_disk_create_file_preallocates() {
+        use std::os::unix::fs::MetadataExt;
+
+        let test_dir = "./test_local_disk_create_file_preallocates";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("alloc-vol").await.unwrap();
+
+        let probe = std::fs::File::create(Path::new(test_dir).join("probe")).unwrap();
+        if rustfs_utils::os::fallocate(&probe, 4096).is_err() {
+            let _ = fs::remove_dir_all(&test_dir).await;
+            return;
+        }
+
+        let size = 4 * 1024 * 1024;
+        let mut w = disk.create_file("", "alloc-vol", "obj/part.1", size).await.unwrap();
+        let path = disk.get_object_path("alloc-vol", "obj/part.1").unwrap();
+
+        // Space is reserved while the apparent size stays at what has been written
+        let meta = std::fs::metadata(&path).unwrap();
+        assert_eq!(meta.len(), 0);
+        assert!(meta.blocks() * 512 >= size as u64, "allocated {}", meta.blocks() * 512);
+
+        w.write_all(&vec![7u8; size as usize]).await.unwrap();
+        w.shutdown().await.unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), size as u64);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_max_open_files() {
+        let test_dir = "./test_local_disk_max_open_files";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap().with_max_open_files(2);
+        disk.make_volume("limit-vol").await.unwrap();
+        disk.write_all("limit-vol", "file", Bytes::from_static(b"content"))
+            .await
+            .unwrap();
+
+        let first = disk.read_file("limit-vol", "file").await.unwrap();
+        let _second = disk.append_file("limit-vol", "other").await.unwrap();
+
+        // A third open waits for a permit instead of failing
+        let third = tokio::time::timeout(Duration::from_millis(100), disk.read_file("limit-vol", "file")).await;
+        assert!(third.is_err());
+
+        drop(first);
+        let mut third = tokio::time::timeout(Duration::from_secs(5), disk.read_file("limit-vol", "file"))
+            .await
+            .unwrap()
+            .unwrap();
+        let mut buf = Vec::new();
+        third.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, b"content");
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_only() {
+        let test_dir = "./test_local_disk_read_only";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+        disk.write_all("test-volume", "object", Bytes::from_static(b"data"))
+            .await
+            .unwrap();
+
+        let disk = disk.with_read_only(true);
+
+        assert_eq!(
+            disk.write_all("test-volume", "other", Bytes::from_static(b"data")).await,
+            Err(DiskError::DiskAccessDenied)
+        );
+        assert_eq!(disk.make_volume("other-volume").await, Err(DiskError::DiskAccessDenied));
+        assert_eq!(
+            disk.delete("test-volume", "object", DeleteOptions::default()).await,
+            Err(DiskError::DiskAccessDenied)
+        );
+        assert!(matches!(
+            disk.create_file("", "test-volume", "new", 4).await,
+            Err(DiskError::DiskAccessDenied)
+        ));
+
+        assert_eq!(disk.read_all("test-volume", "object").await.unwrap(), Bytes::from_static(b"data"));
+        assert!(disk.stat_volume("other-volume").await.is_err());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_create_file_write_checksum() {
+        let test_dir = "./test_local_disk_create_file_write_checksum";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+
+        for (algo, expected) in [
+            (HashAlgorithm::Crc32c, rustfs_utils::hash::sum_crc32c_hex(&data)),
+            (HashAlgorithm::Blake3, rustfs_utils::hash::sum_blake3_hex(&data)),
+        ] {
+            let disk = LocalDisk::new(&endpoint, false)
+                .await
+                .unwrap()
+                .with_write_checksum(Some(algo.clone()));
+            disk.make_volumes(vec!["src-volume", "dst-volume"]).await.unwrap();
+
+            let mut w = disk
+                .create_file("", "src-volume", "upload/part.1", data.len() as i64)
+                .await
+                .unwrap();
+            for chunk in data.chunks(4096) {
+                w.write_all(chunk).await.unwrap();
+            }
+            w.shutdown().await.unwrap();
+            drop(w);
+
+            let meta = ObjectPartInfo {
+                number: 1,
+                etag: "etag".to_string(),
+                ..Default::default()
+            };
+            disk.rename_part(
+                "src-volume",
+                "upload/part.1",
+                "dst-volume",
+                "object/part.1",
+                meta.marshal_msg().unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+            // The part metadata is stored as given and the checksum moves along with the part
+            let stored = disk.read_all("dst-volume", "object/part.1.meta").await.unwrap();
+            let part = ObjectPartInfo::unmarshal(&stored).unwrap();
+            assert_eq!(part.etag, "etag");
+            assert!(part.checksums.is_none());
+            let dst_path = disk.get_object_path("dst-volume", "object/part.1").unwrap();
+            assert_eq!(disk.read_write_checksum(&dst_path).await.unwrap(), Some((algo.clone(), expected)));
+            let src_path = disk.get_object_path("src-volume", "upload/part.1").unwrap();
+            assert!(!write_checksum_path(&src_path).exists());
+
+            // Rewriting the part without a checksum does not leave the old one behind
+            let plain = LocalDisk::new(&endpoint, false).await.unwrap();
+            let mut w = plain
+                .create_file("", "src-volume", "upload/part.1", data.len() as i64)
+                .await
+                .unwrap();
+            w.write_all(&data).await.unwrap();
+            w.shutdown().await.unwrap();
+            drop(w);
+            plain
+                .rename_part(
+                    "src-volume",
+                    "upload/part.1",
+                    "dst-volume",
+                    "object/part.1",
+                    meta.marshal_msg().unwrap().into(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(plain.read_write_checksum(&dst_path).await.unwrap(), None);
+
+            let _ = fs::remove_dir_all(&test_dir).await;
+            fs::create_dir_all(&test_dir).await.unwrap();
+        }
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_file_stream_verify() {
+        let test_dir = "./test_local_disk_read_file_stream_verify";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false)
+            .await
+            .unwrap()
+            .with_write_checksum(Some(HashAlgorithm::HighwayHash256S));
+        disk.make_volume("test-volume").await.unwrap();
+
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let mut w = disk
+            .create_file("", "test-volume", "object/part.1", data.len() as i64)
+            .await
+            .unwrap();
+        w.write_all(&data).await.unwrap();
+        w.shutdown().await.unwrap();
+        drop(w);
+        let file_path = format!("{test_dir}/test-volume/object/part.1");
+        let meta_path = write_checksum_path(Path::new(&file_path));
+        assert!(meta_path.exists());
+
+        let opts = ReadOptions {
+            verify: true,
+            ..Default::default()
+        };
+
+        // An intact file reads back, including a range in the middle of it
+        let mut buf = Vec::new();
+        let mut r = disk
+            .read_file_stream("test-volume", "object/part.1", 0, 0, &opts)
+            .await
+            .unwrap();
+        r.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, data);
+
+        let mut buf = Vec::new();
+        let mut r = disk
+            .read_file_stream("test-volume", "object/part.1", 70_000, 1000, &opts)
+            .await
+            .unwrap();
+        r.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, &data[70_000..71_000]);
+
+        // Flip a byte outside the requested range
+        let mut corrupted = data.clone();
+        corrupted[150_000] ^= 0xff;
+        fs::write(&file_path, &corrupted).await.unwrap();
+
+        let mut buf = Vec::new();
+        let mut r = disk
+            .read_file_stream("test-volume", "object/part.1", 70_000, 1000, &opts)
+            .await
+            .unwrap();
+        let err = r.read_to_end(&mut buf).await.unwrap_err();
+        assert_eq!(DiskError::from(err), DiskError::FileCorrupt);
+        assert_eq!(buf, &data[70_000..71_000]);
+
+        // Without verify the corruption goes unnoticed
+        let mut buf = Vec::new();
+        let mut r = disk
+            .read_file_stream("test-volume", "object/part.1", 0, 0, &ReadOptions::default())
+            .await
+            .unwrap();
+        r.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, corrupted);
+
+        // Verification cannot be asked for once the checksum is gone
+        fs::remove_file(&meta_path).await.unwrap();
+        assert!(
+            disk.read_file_stream("test-volume", "object/part.1", 0, 0, &opts)
+                .await
+                .is_err()
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_info_noop() {
+        let test_dir = "./test_local_disk_info_noop";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+
+        let info = disk
+            .disk_info(&DiskInfoOptions {
+                noop: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(info.endpoint, endpoint.to_string());
+        assert_eq!(info.mount_path, disk.path().to_string_lossy());
+        // Nothing the filesystem stat would have filled in
+        assert_eq!(info.total, 0);
+        assert_eq!(info.free, 0);
+        assert!(info.fs_type.is_empty());
+
+        let info = disk.disk_info(&DiskInfoOptions::default()).await.unwrap();
+        assert!(info.total > 0);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_info_scanning_healing() {
+        let test_dir = "./test_local_disk_info_scanning_healing";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        let opts = DiskInfoOptions::default();
+
+        let info = disk.disk_info(&opts).await.unwrap();
+        assert!(!info.scanning);
+        assert!(!info.healing);
+
+        disk.set_scanning(true);
+        let info = disk.disk_info(&opts).await.unwrap();
+        assert!(info.scanning);
+        assert!(!info.healing);
+
+        disk.set_healing(true);
+        disk.set_scanning(false);
+        let info = disk.disk_info(&opts).await.unwrap();
+        assert!(!info.scanning);
+        assert!(info.healing);
+
+        disk.set_healing(false);
+        assert!(!disk.disk_info(&opts).await.unwrap().healing);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_write_all_batch() {
+        let test_dir = "./test_local_disk_write_all_batch";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let mut entries: Vec<(String, Bytes)> = (0..200)
+            .map(|i| (format!("dir-{}/meta-{i}", i % 4), Bytes::from(format!("data-{i}"))))
+            .collect();
+        entries.push(("../escape".to_string(), Bytes::from_static(b"nope")));
+
+        let results = disk.write_all_batch("test-volume", entries.clone()).await.unwrap();
+        assert_eq!(results.len(), entries.len());
+        assert!(results.last().unwrap().is_err());
+
+        for ((path, data), res) in entries.iter().zip(&results).take(200) {
+            assert!(res.is_ok(), "{path}: {res:?}");
+            assert_eq!(&disk.read_all("test-volume", path).await.unwrap(), data);
         }
 
-        #[cfg(not(windows))]
+        // Existing files are replaced whole, and nothing stays behind in tmp
+        let results = disk
+            .write_all_batch("test-volume", vec![("dir-0/meta-0".to_string(), Bytes::from_static(b"new"))])
+            .await
+            .unwrap();
+        assert!(results[0].is_ok());
+        assert_eq!(disk.read_all("test-volume", "dir-0/meta-0").await.unwrap(), Bytes::from_static(b"new"));
+        let tmp_dir = disk.get_bucket_path(crate::disk::RUSTFS_META_TMP_BUCKET).unwrap();
+        let mut tmp_entries = fs::read_dir(&tmp_dir).await.unwrap();
+        while let Some(entry) = tmp_entries.next_entry().await.unwrap() {
+            assert!(entry.file_type().await.unwrap().is_dir(), "{:?}", entry.path());
+        }
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_walk_dir_cancel() {
+        use rustfs_filemeta::MetacacheReader;
+        use tokio_util::sync::CancellationToken;
+
+        let test_dir = "./test_local_disk_walk_dir_cancel";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+        for i in 0..200 {
+            let object = format!("obj-{i:03}");
+            let mut fi = FileInfo::new(&object, 2, 2);
+            fi.version_id = Some(Uuid::new_v4());
+            fi.mod_time = Some(OffsetDateTime::now_utc());
+            disk.write_all("test-volume", &format!("{object}/{STORAGE_FORMAT_FILE}"), fi.marshal_xl().unwrap())
+                .await
+                .unwrap();
+        }
+
+        let cancel = CancellationToken::new();
+        let opts = WalkDirOptions {
+            bucket: "test-volume".to_string(),
+            recursive: true,
+            cancel: Some(cancel.clone()),
+            ..Default::default()
+        };
+
+        // A small pipe keeps the walk from running far ahead of the reader
+        let (wr, rd) = tokio::io::duplex(256);
+        let walk = async {
+            let mut wr = wr;
+            disk.walk_dir(opts, &mut wr).await
+        };
+        let read = async {
+            let mut reader = MetacacheReader::new(rd);
+            let mut names = Vec::new();
+            while let Ok(Some(entry)) = reader.peek().await {
+                names.push(entry.name);
+                if names.len() == 5 {
+                    cancel.cancel();
+                }
+            }
+            names
+        };
+
+        let (res, names) = tokio::join!(walk, read);
+        res.unwrap();
+        assert!(names.len() >= 5 && names.len() < 20, "walk produced {} entries", names.len());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_walk_dir_skip_internal() {
+        use rustfs_filemeta::MetacacheReader;
+        use std::io::Cursor;
+
+        let test_dir = "./test_local_disk_walk_dir_skip_internal";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+        for (volume, object) in [("test-volume", "obj"), (RUSTFS_META_BUCKET, "config/obj")] {
+            let mut fi = FileInfo::new(object, 2, 2);
+            fi.version_id = Some(Uuid::new_v4());
+            fi.mod_time = Some(OffsetDateTime::now_utc());
+            disk.write_all(volume, &format!("{object}/{STORAGE_FORMAT_FILE}"), fi.marshal_xl().unwrap())
+                .await
+                .unwrap();
+        }
+
+        let disk = &disk;
+        let walk = |skip_internal| async move {
+            let opts = WalkDirOptions {
+                recursive: true,
+                skip_internal,
+                ..Default::default()
+            };
+            let mut out = Vec::new();
+            disk.walk_dir(opts, &mut out).await.unwrap();
+            let entries = MetacacheReader::new(Cursor::new(out)).read_all().await.unwrap();
+            entries.into_iter().map(|e| e.name).collect::<Vec<_>>()
+        };
+
+        let names = walk(true).await;
+        assert!(names.iter().any(|name| name == "test-volume/obj"), "{names:?}");
+        assert!(!names.iter().any(|name| name.starts_with(RUSTFS_META_BUCKET)), "{names:?}");
+
+        let names = walk(false).await;
+        assert!(names.iter().any(|name| name == ".rustfs.sys/config/obj"), "{names:?}");
+
+        // Walking the meta bucket itself is never filtered
+        let opts = WalkDirOptions {
+            bucket: RUSTFS_META_BUCKET.to_string(),
+            recursive: true,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        disk.walk_dir(opts, &mut out).await.unwrap();
+        let entries = MetacacheReader::new(Cursor::new(out)).read_all().await.unwrap();
+        assert!(entries.iter().any(|e| e.name == "config/obj"));
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_local_disk_link_if_exists() {
+        use std::os::unix::fs::MetadataExt;
+
+        let test_dir = "./test_local_disk_link_if_exists";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let data = Bytes::from(b"backup payload".repeat(1000));
+        let key = rustfs_utils::content_key(&data[..], &HashAlgorithm::SHA256).await.unwrap();
+
+        for path in ["first/part.1", "second/part.1"] {
+            if !disk.link_if_exists("test-volume", path, &key).await.unwrap() {
+                disk.write_all("test-volume", path, data.clone()).await.unwrap();
+                disk.remember_content("test-volume", path, &key).await.unwrap();
+            }
+        }
+
+        let first = fs::metadata(disk.get_object_path("test-volume", "first/part.1").unwrap())
+            .await
+            .unwrap();
+        let second = fs::metadata(disk.get_object_path("test-volume", "second/part.1").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(first.ino(), second.ino());
+        assert_eq!(disk.read_all("test-volume", "second/part.1").await.unwrap(), data);
+
+        // Unknown content is left to the caller, bad keys are refused
+        let other = rustfs_utils::content_key(&b"other"[..], &HashAlgorithm::SHA256)
+            .await
+            .unwrap();
+        assert!(!disk.link_if_exists("test-volume", "third/part.1", &other).await.unwrap());
+        assert!(disk.link_if_exists("test-volume", "third/part.1", "../x").await.is_err());
+
+        // In-place changes detach the file first, the other copy and the blob keep their content
+        let mut w = disk.append_file("test-volume", "second/part.1").await.unwrap();
+        w.write_all(b"tail").await.unwrap();
+        w.shutdown().await.unwrap();
+        drop(w);
+        disk.truncate("test-volume", "first/part.1", 6).await.unwrap();
+        assert_eq!(disk.read_all("test-volume", "first/part.1").await.unwrap(), data.slice(..6));
+        assert_eq!(disk.read_all("test-volume", "second/part.1").await.unwrap().len(), data.len() + 4);
+        assert!(disk.link_if_exists("test-volume", "third/part.1", &key).await.unwrap());
+        assert_eq!(disk.read_all("test-volume", "third/part.1").await.unwrap(), data);
+
+        // The blob goes once nothing links to it anymore
+        assert_eq!(disk.purge_unused_blobs().await.unwrap(), 0);
+        fs::remove_file(disk.get_object_path("test-volume", "third/part.1").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(disk.purge_unused_blobs().await.unwrap(), 1);
+        assert!(!disk.link_if_exists("test-volume", "fourth/part.1", &key).await.unwrap());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_span_fields() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+        use tracing_subscriber::registry::LookupSpan;
+
+        /// Collects `span name -> field -> value` for every span and later record.
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<HashMap<String, HashMap<String, String>>>>);
+
+        struct Fields<'a>(&'a mut HashMap<String, String>);
+
+        impl Visit for Fields<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.insert(field.name().to_string(), format!("{value:?}"));
+            }
+        }
+
+        impl<S> tracing_subscriber::Layer<S> for Capture
+        where
+            S: tracing::Subscriber + for<'a> LookupSpan<'a>,
         {
-            // On non-Windows systems, the current implementation doesn't check special characters
-            // So these would be considered valid
-            assert!(LocalDisk::is_valid_volname("valid/name"));
-            assert!(LocalDisk::is_valid_volname("valid:name"));
+            fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: LayerContext<'_, S>) {
+                let mut spans = self.0.lock().unwrap();
+                attrs.record(&mut Fields(spans.entry(attrs.metadata().name().to_string()).or_default()));
+            }
+
+            fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+                if let Some(span) = ctx.span(id) {
+                    let mut spans = self.0.lock().unwrap();
+                    values.record(&mut Fields(spans.entry(span.name().to_string()).or_default()));
+                }
+            }
+        }
+
+        let test_dir = "./test_local_disk_span_fields";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let capture = Capture::default();
+        {
+            let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+            disk.write_all("test-volume", "object", Bytes::from_static(b"data"))
+                .await
+                .unwrap();
+            disk.read_all("test-volume", "object").await.unwrap();
+        }
+
+        let spans = capture.0.lock().unwrap();
+        for name in ["write_all", "read_all"] {
+            let fields = spans.get(name).unwrap_or_else(|| panic!("no {name} span"));
+            assert_eq!(fields.get("endpoint"), Some(&disk.endpoint.to_string()), "{name}");
+            assert_eq!(fields.get("pool"), Some(&disk.endpoint.pool_idx.to_string()), "{name}");
+            assert!(fields.contains_key("elapsed_ms"), "{name} did not record its duration");
+        }
+        drop(spans);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_tmp_staging_and_cleanup() {
+        let test_dir = "./test_local_disk_tmp_staging_and_cleanup";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let tmp_dir = disk.get_bucket_path(crate::disk::RUSTFS_META_TMP_BUCKET).unwrap();
+        let tmp_path = disk.tmp_file_path().unwrap();
+        assert_eq!(tmp_path.parent(), Some(tmp_dir.as_path()));
+        assert_ne!(tmp_path, disk.tmp_file_path().unwrap());
+
+        let tmp_entries = || {
+            let mut names: Vec<_> = std::fs::read_dir(&tmp_dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+
+        // Writes are staged in the tmp bucket and renamed into place
+        disk.write_all("test-volume", "dir/object", Bytes::from_static(b"data"))
+            .await
+            .unwrap();
+        assert_eq!(disk.read_all("test-volume", "dir/object").await.unwrap(), Bytes::from_static(b"data"));
+        assert_eq!(tmp_entries(), vec![".trash"]);
+
+        let stale = tmp_dir.join(Uuid::new_v4().to_string());
+        std::fs::write(&stale, b"partial").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(SystemTime::now() - TMP_RETENTION - Duration::from_secs(60))
+            .unwrap();
+        let fresh = tmp_dir.join(Uuid::new_v4().to_string());
+        std::fs::write(&fresh, b"in flight").unwrap();
+
+        // Read-only drives keep them
+        drop(disk);
+        let disk = LocalDisk::new(&endpoint, true).await.unwrap().with_read_only(true);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(stale.exists());
+
+        // Opening the disk with cleanup removes stale leftovers and keeps recent ones
+        drop(disk);
+        let _disk = LocalDisk::new(&endpoint, true).await.unwrap();
+        for _ in 0..50 {
+            if !stale.exists() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
         }
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+        assert!(tmp_dir.join(".trash").exists());
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_truncate() {
+        let test_dir = "./test_local_disk_truncate";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        disk.write_all("test-volume", "object/part.1", Bytes::from(vec![7u8; 4096]))
+            .await
+            .unwrap();
+        disk.truncate("test-volume", "object/part.1", 1024).await.unwrap();
+
+        let data = disk.read_all("test-volume", "object/part.1").await.unwrap();
+        assert_eq!(data.len(), 1024);
+        assert!(data.iter().all(|b| *b == 7));
+
+        let err = disk.truncate("test-volume", "missing/part.1", 0).await.unwrap_err();
+        assert_eq!(err, DiskError::FileNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_read_range() {
+        let test_dir = "./test_local_disk_read_range";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let data: Vec<u8> = (0..=255u8).collect();
+        disk.write_all("test-volume", "object/part.1", Bytes::from(data.clone()))
+            .await
+            .unwrap();
+
+        let range = disk.read_range("test-volume", "object/part.1", 10, 20, true).await.unwrap();
+        assert_eq!(range, data[10..30]);
+
+        // A range running off the end is cut short unless it has to be exact
+        let tail = disk.read_range("test-volume", "object/part.1", 250, 20, false).await.unwrap();
+        assert_eq!(tail, data[250..]);
+        let err = disk
+            .read_range("test-volume", "object/part.1", 250, 20, true)
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::LessData);
+
+        let past_eof = disk.read_range("test-volume", "object/part.1", 1000, 8, false).await.unwrap();
+        assert!(past_eof.is_empty());
+        let err = disk
+            .read_range("test-volume", "object/part.1", 1000, 8, true)
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::LessData);
+
+        // An oversized length is not preallocated
+        let whole = disk
+            .read_range("test-volume", "object/part.1", 0, usize::MAX >> 1, false)
+            .await
+            .unwrap();
+        assert_eq!(whole, data[..]);
+
+        let err = disk
+            .read_range("missing-volume", "object/part.1", 0, 8, false)
+            .await
+            .unwrap_err();
+        assert_eq!(err, DiskError::VolumeNotFound);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_warm_up() {
+        let test_dir = "./test_local_disk_warm_up";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        let xl_meta = format!("object/{STORAGE_FORMAT_FILE}");
+        disk.write_all("test-volume", &xl_meta, Bytes::from(vec![1u8; 1024]))
+            .await
+            .unwrap();
+        disk.write_all(RUSTFS_META_BUCKET, super::super::FORMAT_CONFIG_FILE, Bytes::from_static(b"{}"))
+            .await
+            .unwrap();
+
+        let warmed = disk
+            .warm_up(&[
+                (RUSTFS_META_BUCKET, super::super::FORMAT_CONFIG_FILE),
+                ("test-volume", &xl_meta),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(warmed, 2);
+
+        // Missing files are skipped without failing the rest
+        let warmed = disk
+            .warm_up(&[("test-volume", "missing/xl.meta"), ("test-volume", &xl_meta)])
+            .await
+            .unwrap();
+        assert_eq!(warmed, 1);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_local_disk_stat_volume_errors() {
+        let test_dir = "./test_local_disk_stat_volume_errors";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("test-volume").await.unwrap();
+
+        assert_eq!(disk.stat_volume("test-volume").await.unwrap().name, "test-volume");
+        assert_eq!(disk.stat_volume("missing-volume").await.unwrap_err(), DiskError::VolumeNotFound);
+
+        // Permission bits do not apply when running as root, so check the mapping directly
+        assert_eq!(
+            DiskError::from(to_volume_error(std::io::Error::from(ErrorKind::PermissionDenied))),
+            DiskError::VolumeAccessDenied
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
     }
 
     #[tokio::test]
@@ -2941,4 +6405,63 @@ mod test {
             assert_eq!(normalize_path_components("C:\\a\\..\\b"), PathBuf::from("C:\\b"));
         }
     }
+
+    #[tokio::test]
+    async fn test_local_disk_ns_scanner() {
+        let test_dir = "./test_local_disk_ns_scanner";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("scan-vol").await.unwrap();
+
+        let objects = [("top", 50), ("a/obj1", 100), ("a/b/obj2", 200), ("c/obj3", 1000)];
+        for (object, size) in objects {
+            let mut fi = FileInfo::new(object, 2, 2);
+            fi.version_id = Some(Uuid::new_v4());
+            fi.mod_time = Some(OffsetDateTime::now_utc());
+            fi.size = size;
+            disk.write_all("scan-vol", &format!("{object}/{STORAGE_FORMAT_FILE}"), fi.marshal_xl().unwrap())
+                .await
+                .unwrap();
+        }
+        disk.write_all("scan-vol", "c/stray.txt", Bytes::from_static(b"not an object"))
+            .await
+            .unwrap();
+
+        let mut cache = DataUsageCache::default();
+        cache.info.name = "scan-vol".to_string();
+        cache.info.skip_healing = true;
+
+        let sleeps = Arc::new(AtomicU32::new(0));
+        let counter = sleeps.clone();
+        let we_sleep: ShouldSleepFn = Some(Arc::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            false
+        }));
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let result = disk
+            .ns_scanner(&cache, Some(tx), HealScanMode::Normal, we_sleep)
+            .await
+            .unwrap();
+
+        let total = result.size_recursive("scan-vol").unwrap();
+        assert_eq!(total.size, 1350);
+        assert_eq!(total.objects, 4);
+        assert_eq!(total.versions, 4);
+        assert_eq!(result.find("scan-vol/a").unwrap().size, 300);
+        assert_eq!(result.find("scan-vol/c").unwrap().objects, 1);
+        assert_eq!(sleeps.load(Ordering::Relaxed), 4);
+
+        // One update per top-level prefix, the last one covering the whole volume
+        let mut updates = Vec::new();
+        while let Some(update) = rx.recv().await {
+            updates.push(update);
+        }
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates.last().unwrap().size, 1350);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
 }
@@ -0,0 +1,894 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::disk::{
+    CheckPartsResp, DeleteOptions, DiskAPI, DiskError, DiskInfo, DiskInfoOptions, DiskLocation, Endpoint, Error,
+    FileInfoVersions, FileReader, FileWriter, ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp, Result,
+    UpdateMetadataOpts, VolumeInfo, WalkDirOptions,
+};
+use bytes::Bytes;
+use parking_lot::Mutex;
+use rustfs_common::last_minute::{AccElem, LastMinuteLatency};
+use rustfs_filemeta::{FileInfo, ObjectPartInfo, RawFileInfo};
+use rustfs_madmin::info_commands::DiskMetrics;
+use rustfs_madmin::metrics::TimedAction;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    future::Future,
+    io::ErrorKind,
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::io::AsyncWrite;
+use uuid::Uuid;
+
+/// Upper bounds of the latency histogram buckets. Slower calls land in a final overflow bucket.
+pub const LATENCY_BUCKETS: [Duration; 10] = [
+    Duration::from_micros(100),
+    Duration::from_micros(500),
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+];
+
+/// Disk operations tracked by `MeteredDisk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskOp {
+    MakeVolume,
+    MakeVolumes,
+    ListVolumes,
+    StatVolume,
+    DeleteVolume,
+    WalkDir,
+    DeleteVersion,
+    DeleteVersions,
+    DeletePaths,
+    WriteMetadata,
+    UpdateMetadata,
+    ReadVersion,
+    ReadXl,
+    RenameData,
+    ListDir,
+    ReadFile,
+    ReadFileStream,
+    AppendFile,
+    CreateFile,
+    RenameFile,
+    RenamePart,
+    Delete,
+    Truncate,
+    VerifyFile,
+    CheckParts,
+    ReadParts,
+    ReadMultiple,
+    StatPaths,
+    WriteAll,
+    ReadAll,
+    ReadRange,
+    DiskInfo,
+}
+
+impl DiskOp {
+    pub const ALL: [DiskOp; 32] = [
+        DiskOp::MakeVolume,
+        DiskOp::MakeVolumes,
+        DiskOp::ListVolumes,
+        DiskOp::StatVolume,
+        DiskOp::DeleteVolume,
+        DiskOp::WalkDir,
+        DiskOp::DeleteVersion,
+        DiskOp::DeleteVersions,
+        DiskOp::DeletePaths,
+        DiskOp::WriteMetadata,
+        DiskOp::UpdateMetadata,
+        DiskOp::ReadVersion,
+        DiskOp::ReadXl,
+        DiskOp::RenameData,
+        DiskOp::ListDir,
+        DiskOp::ReadFile,
+        DiskOp::ReadFileStream,
+        DiskOp::AppendFile,
+        DiskOp::CreateFile,
+        DiskOp::RenameFile,
+        DiskOp::RenamePart,
+        DiskOp::Delete,
+        DiskOp::Truncate,
+        DiskOp::VerifyFile,
+        DiskOp::CheckParts,
+        DiskOp::ReadParts,
+        DiskOp::ReadMultiple,
+        DiskOp::StatPaths,
+        DiskOp::WriteAll,
+        DiskOp::ReadAll,
+        DiskOp::ReadRange,
+        DiskOp::DiskInfo,
+    ];
+
+    /// Name used for the operation in `DiskMetrics::api_calls`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiskOp::MakeVolume => "make_volume",
+            DiskOp::MakeVolumes => "make_volumes",
+            DiskOp::ListVolumes => "list_volumes",
+            DiskOp::StatVolume => "stat_volume",
+            DiskOp::DeleteVolume => "delete_volume",
+            DiskOp::WalkDir => "walk_dir",
+            DiskOp::DeleteVersion => "delete_version",
+            DiskOp::DeleteVersions => "delete_versions",
+            DiskOp::DeletePaths => "delete_paths",
+            DiskOp::WriteMetadata => "write_metadata",
+            DiskOp::UpdateMetadata => "update_metadata",
+            DiskOp::ReadVersion => "read_version",
+            DiskOp::ReadXl => "read_xl",
+            DiskOp::RenameData => "rename_data",
+            DiskOp::ListDir => "list_dir",
+            DiskOp::ReadFile => "read_file",
+            DiskOp::ReadFileStream => "read_file_stream",
+            DiskOp::AppendFile => "append_file",
+            DiskOp::CreateFile => "create_file",
+            DiskOp::RenameFile => "rename_file",
+            DiskOp::RenamePart => "rename_part",
+            DiskOp::Delete => "delete",
+            DiskOp::Truncate => "truncate",
+            DiskOp::VerifyFile => "verify_file",
+            DiskOp::CheckParts => "check_parts",
+            DiskOp::ReadParts => "read_parts",
+            DiskOp::ReadMultiple => "read_multiple",
+            DiskOp::StatPaths => "stat_paths",
+            DiskOp::WriteAll => "write_all",
+            DiskOp::ReadAll => "read_all",
+            DiskOp::ReadRange => "read_range",
+            DiskOp::DiskInfo => "disk_info",
+        }
+    }
+
+    fn is_write(&self) -> bool {
+        matches!(
+            self,
+            DiskOp::MakeVolume
+                | DiskOp::MakeVolumes
+                | DiskOp::WriteMetadata
+                | DiskOp::UpdateMetadata
+                | DiskOp::RenameData
+                | DiskOp::AppendFile
+                | DiskOp::CreateFile
+                | DiskOp::RenameFile
+                | DiskOp::RenamePart
+                | DiskOp::Truncate
+                | DiskOp::WriteAll
+        )
+    }
+
+    fn is_delete(&self) -> bool {
+        matches!(
+            self,
+            DiskOp::DeleteVolume | DiskOp::DeleteVersion | DiskOp::DeleteVersions | DiskOp::DeletePaths | DiskOp::Delete
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct OpStats {
+    calls: AtomicU64,
+    errors: AtomicU64,
+    total_ns: AtomicU64,
+    buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+    last_minute: Mutex<LastMinuteLatency>,
+}
+
+impl OpStats {
+    fn record(&self, elapsed: Duration, failed: bool) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.total_ns.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+
+        let idx = LATENCY_BUCKETS
+            .iter()
+            .position(|b| elapsed <= *b)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let elem = AccElem {
+            total: elapsed.as_nanos() as u64,
+            size: 0,
+            n: 1,
+        };
+        self.last_minute.lock().add_all(now, &elem);
+    }
+
+    fn snapshot(&self) -> OpMetrics {
+        OpMetrics {
+            calls: self.calls.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            total_latency: Duration::from_nanos(self.total_ns.load(Ordering::Relaxed)),
+            latency_buckets: self.buckets.iter().map(|b| b.load(Ordering::Relaxed)).collect(),
+            last_minute: {
+                let total = self.last_minute.lock().get_total();
+                TimedAction {
+                    count: total.n,
+                    acc_time: total.total,
+                    bytes: total.size,
+                }
+            },
+        }
+    }
+}
+
+/// Counters of a single disk operation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OpMetrics {
+    pub calls: u64,
+    pub errors: u64,
+    /// Sum of the latency of all calls
+    pub total_latency: Duration,
+    /// Number of calls per `LATENCY_BUCKETS` entry, followed by the overflow bucket
+    pub latency_buckets: Vec<u64>,
+    /// Calls and their summed latency in nanoseconds over the last minute
+    pub last_minute: TimedAction,
+}
+
+/// Point in time copy of the counters of a `MeteredDisk`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DiskMetricsSnapshot {
+    /// Per operation counters, keyed by `DiskOp::as_str`
+    pub ops: BTreeMap<&'static str, OpMetrics>,
+    pub total_errors_timeout: u64,
+    pub total_errors_availability: u64,
+    /// Operations started but not finished yet
+    pub in_flight: u64,
+}
+
+impl DiskMetricsSnapshot {
+    /// Copies the counters into the `DiskMetrics` reported by `disk_info`.
+    ///
+    /// `DiskMetrics` has no place for cumulative latency, so histograms are only available from the snapshot.
+    pub fn fill_disk_metrics(&self, metrics: &mut DiskMetrics) {
+        for op in DiskOp::ALL {
+            let Some(m) = self.ops.get(op.as_str()) else {
+                continue;
+            };
+
+            if m.calls > 0 {
+                metrics.api_calls.insert(op.as_str().to_string(), m.calls);
+            }
+            if m.errors > 0 {
+                metrics.api_errors.insert(op.as_str().to_string(), m.errors);
+            }
+            if m.last_minute.count > 0 {
+                metrics.last_minute.insert(op.as_str().to_string(), m.last_minute.clone());
+            }
+            if op.is_write() {
+                metrics.total_writes += m.calls;
+            }
+            if op.is_delete() {
+                metrics.total_deletes += m.calls;
+            }
+        }
+
+        metrics.total_errors_timeout = self.total_errors_timeout;
+        metrics.total_errors_availability = self.total_errors_availability;
+    }
+}
+
+/// Call counts, error counts and latency of the operations of one disk.
+///
+/// `MeteredDisk` records into one of these, and so does `LocalDiskWrapper` for the local drives.
+#[derive(Debug)]
+pub struct DiskOpRecorder {
+    stats: Vec<OpStats>,
+    errors_timeout: AtomicU64,
+    errors_availability: AtomicU64,
+    in_flight: AtomicU64,
+}
+
+impl Default for DiskOpRecorder {
+    fn default() -> Self {
+        Self {
+            stats: DiskOp::ALL.iter().map(|_| OpStats::default()).collect(),
+            errors_timeout: AtomicU64::new(0),
+            errors_availability: AtomicU64::new(0),
+            in_flight: AtomicU64::new(0),
+        }
+    }
+}
+
+impl DiskOpRecorder {
+    /// Returns a snapshot of the counters recorded so far.
+    pub fn metrics(&self) -> DiskMetricsSnapshot {
+        DiskMetricsSnapshot {
+            ops: DiskOp::ALL
+                .iter()
+                .zip(&self.stats)
+                .map(|(op, stats)| (op.as_str(), stats.snapshot()))
+                .collect(),
+            total_errors_timeout: self.errors_timeout.load(Ordering::Relaxed),
+            total_errors_availability: self.errors_availability.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format.
+    ///
+    /// Every sample carries `pool`, `set`, `disk` and `endpoint` labels, the per operation ones also an `op`
+    /// label named after `DiskOp::as_str`. The metric names are part of the public interface:
+    ///
+    /// - `rustfs_disk_api_calls_total` (counter): calls per operation
+    /// - `rustfs_disk_api_errors_total` (counter): failed calls per operation
+    /// - `rustfs_disk_api_latency_seconds` (histogram): call latency per operation, bucketed by `LATENCY_BUCKETS`
+    /// - `rustfs_disk_errors_timeout_total` (counter): calls that timed out
+    /// - `rustfs_disk_errors_availability_total` (counter): calls rejected because the disk was faulty, missing or closed
+    /// - `rustfs_disk_ops_in_flight` (gauge): operations currently running
+    pub fn encode_prometheus(&self, ep: &Endpoint) -> String {
+        let metrics = self.metrics();
+        let labels = format!(
+            "pool=\"{}\",set=\"{}\",disk=\"{}\",endpoint=\"{}\"",
+            ep.pool_idx,
+            ep.set_idx,
+            ep.disk_idx,
+            escape_label_value(&ep.to_string())
+        );
+
+        let mut out = String::new();
+
+        write_header(&mut out, "rustfs_disk_api_calls_total", "counter", "Number of calls per disk operation.");
+        for (op, m) in &metrics.ops {
+            let _ = writeln!(out, "rustfs_disk_api_calls_total{{{labels},op=\"{op}\"}} {}", m.calls);
+        }
+
+        write_header(
+            &mut out,
+            "rustfs_disk_api_errors_total",
+            "counter",
+            "Number of failed calls per disk operation.",
+        );
+        for (op, m) in &metrics.ops {
+            let _ = writeln!(out, "rustfs_disk_api_errors_total{{{labels},op=\"{op}\"}} {}", m.errors);
+        }
+
+        write_header(
+            &mut out,
+            "rustfs_disk_api_latency_seconds",
+            "histogram",
+            "Latency of disk operations in seconds.",
+        );
+        for (op, m) in &metrics.ops {
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(&m.latency_buckets) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "rustfs_disk_api_latency_seconds_bucket{{{labels},op=\"{op}\",le=\"{}\"}} {cumulative}",
+                    bound.as_secs_f64()
+                );
+            }
+            let _ = writeln!(
+                out,
+                "rustfs_disk_api_latency_seconds_bucket{{{labels},op=\"{op}\",le=\"+Inf\"}} {}",
+                m.calls
+            );
+            let _ = writeln!(
+                out,
+                "rustfs_disk_api_latency_seconds_sum{{{labels},op=\"{op}\"}} {}",
+                m.total_latency.as_secs_f64()
+            );
+            let _ = writeln!(out, "rustfs_disk_api_latency_seconds_count{{{labels},op=\"{op}\"}} {}", m.calls);
+        }
+
+        write_header(
+            &mut out,
+            "rustfs_disk_errors_timeout_total",
+            "counter",
+            "Number of disk calls that timed out.",
+        );
+        let _ = writeln!(out, "rustfs_disk_errors_timeout_total{{{labels}}} {}", metrics.total_errors_timeout);
+
+        write_header(
+            &mut out,
+            "rustfs_disk_errors_availability_total",
+            "counter",
+            "Number of disk calls rejected because the disk was unavailable.",
+        );
+        let _ = writeln!(
+            out,
+            "rustfs_disk_errors_availability_total{{{labels}}} {}",
+            metrics.total_errors_availability
+        );
+
+        write_header(
+            &mut out,
+            "rustfs_disk_ops_in_flight",
+            "gauge",
+            "Number of disk operations currently running.",
+        );
+        let _ = writeln!(out, "rustfs_disk_ops_in_flight{{{labels}}} {}", metrics.in_flight);
+
+        out
+    }
+
+    pub fn record(&self, op: DiskOp, elapsed: Duration, err: Option<&Error>) {
+        self.stats[op as usize].record(elapsed, err.is_some());
+
+        match err {
+            Some(DiskError::Timeout) => {
+                self.errors_timeout.fetch_add(1, Ordering::Relaxed);
+            }
+            Some(DiskError::Io(e)) if e.kind() == ErrorKind::TimedOut => {
+                self.errors_timeout.fetch_add(1, Ordering::Relaxed);
+            }
+            Some(DiskError::FaultyDisk | DiskError::FaultyRemoteDisk | DiskError::DiskNotFound | DiskError::DiskClosed) => {
+                self.errors_availability.fetch_add(1, Ordering::Relaxed);
+            }
+            _ => {}
+        }
+    }
+
+    /// Runs `fut` as one call of `op`.
+    pub async fn metered<T, Fut>(&self, op: DiskOp, fut: Fut) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        let _in_flight = InFlight::start(&self.in_flight);
+        let start = Instant::now();
+        let res = fut.await;
+        self.record(op, start.elapsed(), res.as_ref().err());
+        res
+    }
+
+    /// Like `metered`, for `delete_versions` which reports an error per version.
+    pub async fn metered_delete_versions<Fut>(&self, fut: Fut) -> Vec<Option<Error>>
+    where
+        Fut: Future<Output = Vec<Option<Error>>>,
+    {
+        let _in_flight = InFlight::start(&self.in_flight);
+        let start = Instant::now();
+        let result = fut.await;
+        self.record(DiskOp::DeleteVersions, start.elapsed(), result.iter().flatten().next());
+        result
+    }
+}
+
+/// Counts an operation as in flight until dropped, so that cancelled calls are not counted forever.
+struct InFlight<'a>(&'a AtomicU64);
+
+impl<'a> InFlight<'a> {
+    fn start(counter: &'a AtomicU64) -> Self {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Self(counter)
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Disk wrapper that records call counts, error counts and latency for every disk operation.
+#[derive(Debug)]
+pub struct MeteredDisk<D> {
+    disk: D,
+    recorder: DiskOpRecorder,
+}
+
+impl<D: DiskAPI> MeteredDisk<D> {
+    pub fn new(disk: D) -> Self {
+        Self {
+            disk,
+            recorder: DiskOpRecorder::default(),
+        }
+    }
+
+    pub fn inner(&self) -> &D {
+        &self.disk
+    }
+
+    /// Returns a snapshot of the counters recorded so far.
+    pub fn metrics(&self) -> DiskMetricsSnapshot {
+        self.recorder.metrics()
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format, see `DiskOpRecorder::encode_prometheus`.
+    pub fn encode_prometheus(&self) -> String {
+        self.recorder.encode_prometheus(&self.disk.endpoint())
+    }
+
+    async fn metered<T, Fut>(&self, op: DiskOp, fut: Fut) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        self.recorder.metered(op, fut).await
+    }
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[async_trait::async_trait]
+impl<D: DiskAPI> DiskAPI for MeteredDisk<D> {
+    fn to_string(&self) -> String {
+        self.disk.to_string()
+    }
+
+    async fn is_online(&self) -> bool {
+        self.disk.is_online().await
+    }
+
+    fn is_local(&self) -> bool {
+        self.disk.is_local()
+    }
+
+    fn host_name(&self) -> String {
+        self.disk.host_name()
+    }
+
+    fn endpoint(&self) -> Endpoint {
+        self.disk.endpoint()
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.disk.close().await
+    }
+
+    async fn get_disk_id(&self) -> Result<Option<Uuid>> {
+        self.disk.get_disk_id().await
+    }
+
+    async fn set_disk_id(&self, id: Option<Uuid>) -> Result<()> {
+        self.disk.set_disk_id(id).await
+    }
+
+    fn set_scanning(&self, scanning: bool) {
+        self.disk.set_scanning(scanning)
+    }
+
+    fn set_healing(&self, healing: bool) {
+        self.disk.set_healing(healing)
+    }
+
+    fn path(&self) -> PathBuf {
+        self.disk.path()
+    }
+
+    fn get_disk_location(&self) -> DiskLocation {
+        self.disk.get_disk_location()
+    }
+
+    async fn make_volume(&self, volume: &str) -> Result<()> {
+        self.metered(DiskOp::MakeVolume, self.disk.make_volume(volume)).await
+    }
+
+    async fn make_volumes(&self, volumes: Vec<&str>) -> Result<()> {
+        self.metered(DiskOp::MakeVolumes, self.disk.make_volumes(volumes)).await
+    }
+
+    async fn list_volumes(&self) -> Result<Vec<VolumeInfo>> {
+        self.metered(DiskOp::ListVolumes, self.disk.list_volumes()).await
+    }
+
+    async fn stat_volume(&self, volume: &str) -> Result<VolumeInfo> {
+        self.metered(DiskOp::StatVolume, self.disk.stat_volume(volume)).await
+    }
+
+    async fn delete_volume(&self, volume: &str) -> Result<()> {
+        self.metered(DiskOp::DeleteVolume, self.disk.delete_volume(volume)).await
+    }
+
+    async fn walk_dir<W: AsyncWrite + Unpin + Send>(&self, opts: WalkDirOptions, wr: &mut W) -> Result<()> {
+        self.metered(DiskOp::WalkDir, self.disk.walk_dir(opts, wr)).await
+    }
+
+    async fn delete_version(
+        &self,
+        volume: &str,
+        path: &str,
+        fi: FileInfo,
+        force_del_marker: bool,
+        opts: DeleteOptions,
+    ) -> Result<()> {
+        self.metered(DiskOp::DeleteVersion, self.disk.delete_version(volume, path, fi, force_del_marker, opts))
+            .await
+    }
+
+    async fn delete_versions(&self, volume: &str, versions: Vec<FileInfoVersions>, opts: DeleteOptions) -> Vec<Option<Error>> {
+        self.recorder
+            .metered_delete_versions(self.disk.delete_versions(volume, versions, opts))
+            .await
+    }
+
+    async fn delete_paths(&self, volume: &str, paths: &[String]) -> Result<()> {
+        self.metered(DiskOp::DeletePaths, self.disk.delete_paths(volume, paths)).await
+    }
+
+    async fn write_metadata(&self, org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
+        self.metered(DiskOp::WriteMetadata, self.disk.write_metadata(org_volume, volume, path, fi))
+            .await
+    }
+
+    async fn update_metadata(&self, volume: &str, path: &str, fi: FileInfo, opts: &UpdateMetadataOpts) -> Result<()> {
+        self.metered(DiskOp::UpdateMetadata, self.disk.update_metadata(volume, path, fi, opts))
+            .await
+    }
+
+    async fn read_version(
+        &self,
+        org_volume: &str,
+        volume: &str,
+        path: &str,
+        version_id: &str,
+        opts: &ReadOptions,
+    ) -> Result<FileInfo> {
+        self.metered(DiskOp::ReadVersion, self.disk.read_version(org_volume, volume, path, version_id, opts))
+            .await
+    }
+
+    async fn read_xl(&self, volume: &str, path: &str, read_data: bool) -> Result<RawFileInfo> {
+        self.metered(DiskOp::ReadXl, self.disk.read_xl(volume, path, read_data)).await
+    }
+
+    async fn rename_data(
+        &self,
+        src_volume: &str,
+        src_path: &str,
+        file_info: FileInfo,
+        dst_volume: &str,
+        dst_path: &str,
+    ) -> Result<RenameDataResp> {
+        self.metered(
+            DiskOp::RenameData,
+            self.disk.rename_data(src_volume, src_path, file_info, dst_volume, dst_path),
+        )
+        .await
+    }
+
+    async fn list_dir(&self, origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>> {
+        self.metered(DiskOp::ListDir, self.disk.list_dir(origvolume, volume, dir_path, count))
+            .await
+    }
+
+    async fn read_file(&self, volume: &str, path: &str) -> Result<FileReader> {
+        self.metered(DiskOp::ReadFile, self.disk.read_file(volume, path)).await
+    }
+
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<FileReader> {
+        self.metered(DiskOp::ReadFileStream, self.disk.read_file_stream(volume, path, offset, length, opts))
+            .await
+    }
+
+    async fn append_file(&self, volume: &str, path: &str) -> Result<FileWriter> {
+        self.metered(DiskOp::AppendFile, self.disk.append_file(volume, path)).await
+    }
+
+    async fn create_file(&self, origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<FileWriter> {
+        self.metered(DiskOp::CreateFile, self.disk.create_file(origvolume, volume, path, file_size))
+            .await
+    }
+
+    async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()> {
+        self.metered(DiskOp::RenameFile, self.disk.rename_file(src_volume, src_path, dst_volume, dst_path))
+            .await
+    }
+
+    async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()> {
+        self.metered(
+            DiskOp::RenamePart,
+            self.disk.rename_part(src_volume, src_path, dst_volume, dst_path, meta),
+        )
+        .await
+    }
+
+    async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()> {
+        self.metered(DiskOp::Delete, self.disk.delete(volume, path, opt)).await
+    }
+
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()> {
+        self.metered(DiskOp::Truncate, self.disk.truncate(volume, path, size)).await
+    }
+
+    async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        self.metered(DiskOp::VerifyFile, self.disk.verify_file(volume, path, fi))
+            .await
+    }
+
+    async fn check_parts(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        self.metered(DiskOp::CheckParts, self.disk.check_parts(volume, path, fi))
+            .await
+    }
+
+    async fn read_parts(&self, bucket: &str, paths: &[String]) -> Result<Vec<ObjectPartInfo>> {
+        self.metered(DiskOp::ReadParts, self.disk.read_parts(bucket, paths)).await
+    }
+
+    async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>> {
+        self.metered(DiskOp::ReadMultiple, self.disk.read_multiple(req)).await
+    }
+
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>> {
+        self.metered(DiskOp::StatPaths, self.disk.stat_paths(volume, paths)).await
+    }
+
+    async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+        self.metered(DiskOp::WriteAll, self.disk.write_all(volume, path, data)).await
+    }
+
+    async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
+        self.metered(DiskOp::ReadAll, self.disk.read_all(volume, path)).await
+    }
+
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes> {
+        self.metered(DiskOp::ReadRange, self.disk.read_range(volume, path, offset, length, exact))
+            .await
+    }
+
+    async fn disk_info(&self, opts: &DiskInfoOptions) -> Result<DiskInfo> {
+        let mut info = self.metered(DiskOp::DiskInfo, self.disk.disk_info(opts)).await?;
+        if opts.metrics && !opts.noop {
+            self.metrics().fill_disk_metrics(&mut info.metrics);
+        }
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::local::LocalDisk;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_metered_disk_counts_calls() {
+        let test_dir = TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = MeteredDisk::new(LocalDisk::new(&endpoint, false).await.unwrap());
+
+        disk.make_volume("bucket").await.unwrap();
+        disk.write_all("bucket", "object", Bytes::from_static(b"data")).await.unwrap();
+        disk.read_all("bucket", "object").await.unwrap();
+        disk.read_all("bucket", "object").await.unwrap();
+        assert!(disk.read_all("bucket", "missing").await.is_err());
+
+        let metrics = disk.metrics();
+        assert_eq!(metrics.ops["make_volume"].calls, 1);
+        assert_eq!(metrics.ops["write_all"].calls, 1);
+        assert_eq!(metrics.ops["write_all"].errors, 0);
+        assert_eq!(metrics.ops["read_all"].calls, 3);
+        assert_eq!(metrics.ops["read_all"].errors, 1);
+        assert_eq!(metrics.ops["read_all"].latency_buckets.iter().sum::<u64>(), 3);
+        assert_eq!(
+            metrics.ops["delete"],
+            OpMetrics {
+                latency_buckets: vec![0; LATENCY_BUCKETS.len() + 1],
+                ..Default::default()
+            }
+        );
+
+        let info = disk
+            .disk_info(&DiskInfoOptions {
+                metrics: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(info.metrics.api_calls.get("read_all"), Some(&3));
+        assert_eq!(info.metrics.api_calls.get("disk_info"), Some(&1));
+        assert!(!info.metrics.api_calls.contains_key("delete"));
+        assert_eq!(info.metrics.total_writes, 2);
+    }
+
+    #[tokio::test]
+    async fn test_metered_disk_info_metrics_only_when_requested() {
+        let test_dir = TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = MeteredDisk::new(LocalDisk::new(&endpoint, false).await.unwrap());
+        disk.make_volume("bucket").await.unwrap();
+        disk.write_all("bucket", "object", Bytes::from_static(b"data")).await.unwrap();
+        disk.read_all("bucket", "object").await.unwrap();
+        assert!(disk.read_all("bucket", "missing").await.is_err());
+
+        let info = disk.disk_info(&DiskInfoOptions::default()).await.unwrap();
+        assert_eq!(info.metrics, DiskMetrics::default());
+
+        let info = disk
+            .disk_info(&DiskInfoOptions {
+                metrics: true,
+                noop: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(info.metrics, DiskMetrics::default());
+
+        let info = disk
+            .disk_info(&DiskInfoOptions {
+                metrics: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(info.metrics.api_calls.get("read_all"), Some(&2));
+        assert_eq!(info.metrics.api_errors.get("read_all"), Some(&1));
+        assert!(!info.metrics.api_errors.contains_key("write_all"));
+        assert_eq!(info.metrics.last_minute["read_all"].count, 2);
+        assert_eq!(info.metrics.last_minute["write_all"].count, 1);
+        assert!(!info.metrics.last_minute.contains_key("delete"));
+    }
+
+    #[tokio::test]
+    async fn test_metered_in_flight_cancelled() {
+        let recorder = DiskOpRecorder::default();
+
+        // A call dropped before it finishes no longer counts as in flight
+        let pending = recorder.metered(DiskOp::ReadAll, std::future::pending::<Result<()>>());
+        assert!(tokio::time::timeout(Duration::from_millis(10), pending).await.is_err());
+        assert_eq!(recorder.metrics().in_flight, 0);
+    }
+
+    #[tokio::test]
+    async fn test_metered_disk_encode_prometheus() {
+        let test_dir = TempDir::new().unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let disk = MeteredDisk::new(LocalDisk::new(&endpoint, false).await.unwrap());
+        disk.make_volume("bucket").await.unwrap();
+        assert!(disk.read_all("bucket", "missing").await.is_err());
+
+        let text = disk.encode_prometheus();
+
+        let types: Vec<_> = text.lines().filter(|l| l.starts_with("# TYPE ")).collect();
+        assert_eq!(types.len(), 6);
+        for line in types {
+            let fields: Vec<_> = line.split(' ').collect();
+            assert_eq!(fields.len(), 4, "{line}");
+            assert!(fields[2].starts_with("rustfs_disk_"), "{line}");
+            assert!(["counter", "gauge", "histogram"].contains(&fields[3]), "{line}");
+        }
+        assert!(text.contains("# TYPE rustfs_disk_api_latency_seconds histogram"));
+
+        let sample = |name: &str, op: &str| {
+            text.lines()
+                .find(|l| l.starts_with(&format!("{name}{{")) && l.contains(&format!("op=\"{op}\"}}")))
+                .and_then(|l| l.rsplit(' ').next())
+                .map(str::to_string)
+        };
+        assert_eq!(sample("rustfs_disk_api_calls_total", "read_all").as_deref(), Some("1"));
+        assert_eq!(sample("rustfs_disk_api_errors_total", "read_all").as_deref(), Some("1"));
+        assert_eq!(sample("rustfs_disk_api_latency_seconds_count", "make_volume").as_deref(), Some("1"));
+        assert!(text.contains(&format!("endpoint=\"{}\"", endpoint)));
+        assert!(text.contains("rustfs_disk_api_latency_seconds_bucket{") && text.contains("le=\"+Inf\"}"));
+    }
+}
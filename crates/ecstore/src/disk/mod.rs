@@ -12,38 +12,52 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod consistency;
+pub mod copy;
+#[cfg(target_os = "linux")]
+pub mod direct_io;
 pub mod disk_store;
 pub mod endpoint;
 pub mod error;
 pub mod error_conv;
 pub mod error_reduce;
+pub mod file_writer;
 pub mod format;
 pub mod fs;
 pub mod local;
+pub mod metered;
 pub mod os;
+pub mod scatter;
+pub mod throttle;
 
 pub const RUSTFS_META_BUCKET: &str = ".rustfs.sys";
 pub const RUSTFS_META_MULTIPART_BUCKET: &str = ".rustfs.sys/multipart";
 pub const RUSTFS_META_TMP_BUCKET: &str = ".rustfs.sys/tmp";
 pub const RUSTFS_META_TMP_DELETED_BUCKET: &str = ".rustfs.sys/tmp/.trash";
+pub const RUSTFS_META_DEDUP_BUCKET: &str = ".rustfs.sys/dedup";
 pub const BUCKET_META_PREFIX: &str = "buckets";
 pub const FORMAT_CONFIG_FILE: &str = "format.json";
 pub const STORAGE_FORMAT_FILE: &str = "xl.meta";
 pub const STORAGE_FORMAT_FILE_BACKUP: &str = "xl.meta.bkp";
 
-use crate::disk::disk_store::LocalDiskWrapper;
-use crate::rpc::RemoteDisk;
+use crate::disk::disk_store::{LocalDiskWrapper, get_max_timeout_duration};
+use crate::global::DISK_FILL_FRACTION;
+use crate::rpc::{Codec, RemoteDisk};
 use bytes::Bytes;
 use endpoint::Endpoint;
 use error::DiskError;
 use error::{Error, Result};
 use local::LocalDisk;
+use rand::Rng;
 use rustfs_filemeta::{FileInfo, ObjectPartInfo, RawFileInfo};
 use rustfs_madmin::info_commands::DiskMetrics;
+use rustfs_utils::HashAlgorithm;
+use rustfs_utils::http::headers::{RESERVED_METADATA_PREFIX, RESERVED_METADATA_PREFIX_LOWER};
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, path::PathBuf, sync::Arc};
+use std::{fmt::Debug, path::PathBuf, sync::Arc, time::Duration};
 use time::OffsetDateTime;
 use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 pub type DiskStore = Arc<Disk>;
@@ -57,6 +71,17 @@ pub enum Disk {
     Remote(Box<RemoteDisk>),
 }
 
+impl Disk {
+    /// Like `make_volumes`, but reports one result per volume, in order. Remote disks ask the peer
+    /// with a single call.
+    pub async fn make_volumes_each(&self, volumes: Vec<&str>) -> Result<Vec<Result<()>>> {
+        match self {
+            Disk::Local(local_disk) => local_disk.make_volumes_each(volumes).await,
+            Disk::Remote(remote_disk) => remote_disk.make_volumes_each(volumes).await,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl DiskAPI for Disk {
     #[tracing::instrument(skip(self))]
@@ -123,6 +148,22 @@ impl DiskAPI for Disk {
         }
     }
 
+    #[tracing::instrument(skip(self))]
+    fn set_scanning(&self, scanning: bool) {
+        match self {
+            Disk::Local(local_disk) => local_disk.set_scanning(scanning),
+            Disk::Remote(remote_disk) => remote_disk.set_scanning(scanning),
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn set_healing(&self, healing: bool) {
+        match self {
+            Disk::Local(local_disk) => local_disk.set_healing(healing),
+            Disk::Remote(remote_disk) => remote_disk.set_healing(healing),
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     fn path(&self) -> PathBuf {
         match self {
@@ -289,10 +330,17 @@ impl DiskAPI for Disk {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<FileReader> {
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<FileReader> {
         match self {
-            Disk::Local(local_disk) => local_disk.read_file_stream(volume, path, offset, length).await,
-            Disk::Remote(remote_disk) => remote_disk.read_file_stream(volume, path, offset, length).await,
+            Disk::Local(local_disk) => local_disk.read_file_stream(volume, path, offset, length, opts).await,
+            Disk::Remote(remote_disk) => remote_disk.read_file_stream(volume, path, offset, length, opts).await,
         }
     }
 
@@ -348,6 +396,14 @@ impl DiskAPI for Disk {
         }
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()> {
+        match self {
+            Disk::Local(local_disk) => local_disk.truncate(volume, path, size).await,
+            Disk::Remote(remote_disk) => remote_disk.truncate(volume, path, size).await,
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
         match self {
@@ -372,6 +428,14 @@ impl DiskAPI for Disk {
         }
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>> {
+        match self {
+            Disk::Local(local_disk) => local_disk.stat_paths(volume, paths).await,
+            Disk::Remote(remote_disk) => remote_disk.stat_paths(volume, paths).await,
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
         match self {
@@ -388,6 +452,14 @@ impl DiskAPI for Disk {
         }
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes> {
+        match self {
+            Disk::Local(local_disk) => local_disk.read_range(volume, path, offset, length, exact).await,
+            Disk::Remote(remote_disk) => remote_disk.read_range(volume, path, offset, length, exact).await,
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     async fn disk_info(&self, opts: &DiskInfoOptions) -> Result<DiskInfo> {
         match self {
@@ -399,7 +471,13 @@ impl DiskAPI for Disk {
 
 pub async fn new_disk(ep: &Endpoint, opt: &DiskOption) -> Result<DiskStore> {
     if ep.is_local {
-        let s = LocalDisk::new(ep, opt.cleanup).await?;
+        // Cleanup removes stale temporary data, which a read-only drive must keep
+        let s = LocalDisk::new(ep, opt.cleanup && !opt.read_only)
+            .await?
+            .with_direct_io(opt.direct_io)
+            .with_max_open_files(opt.max_open_files)
+            .with_read_only(opt.read_only)
+            .with_write_checksum(opt.write_checksum.clone());
         Ok(Arc::new(Disk::Local(Box::new(LocalDiskWrapper::new(Arc::new(s), opt.health_check)))))
     } else {
         let remote_disk = RemoteDisk::new(ep, opt).await?;
@@ -407,6 +485,36 @@ pub async fn new_disk(ep: &Endpoint, opt: &DiskOption) -> Result<DiskStore> {
     }
 }
 
+/// Flags a disk as scanning or healing for as long as the guard lives.
+///
+/// The flag is cleared on drop, so an early return, an error or a cancelled task cannot leave it set.
+pub struct DiskActivityGuard<D: DiskAPI> {
+    disk: Arc<D>,
+    healing: bool,
+}
+
+impl<D: DiskAPI> DiskActivityGuard<D> {
+    pub fn scanning(disk: Arc<D>) -> Self {
+        disk.set_scanning(true);
+        Self { disk, healing: false }
+    }
+
+    pub fn healing(disk: Arc<D>) -> Self {
+        disk.set_healing(true);
+        Self { disk, healing: true }
+    }
+}
+
+impl<D: DiskAPI> Drop for DiskActivityGuard<D> {
+    fn drop(&mut self) {
+        if self.healing {
+            self.disk.set_healing(false);
+        } else {
+            self.disk.set_scanning(false);
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait DiskAPI: Debug + Send + Sync + 'static {
     fn to_string(&self) -> String;
@@ -422,6 +530,10 @@ pub trait DiskAPI: Debug + Send + Sync + 'static {
     fn path(&self) -> PathBuf;
     fn get_disk_location(&self) -> DiskLocation;
 
+    // Mark the disk as being scanned or healed. disk_info reports the flags until they are cleared.
+    fn set_scanning(&self, scanning: bool);
+    fn set_healing(&self, healing: bool);
+
     // Healing
     // DiskInfo
     // NSScanner
@@ -433,7 +545,9 @@ pub trait DiskAPI: Debug + Send + Sync + 'static {
     async fn stat_volume(&self, volume: &str) -> Result<VolumeInfo>;
     async fn delete_volume(&self, volume: &str) -> Result<()>;
 
-    // Concurrent read/write pipeline w <- MetaCacheEntry
+    /// Writes the entries under `opts.base_dir` to `wr` as a metacache stream (see `MetacacheWriter`).
+    /// A complete stream is terminated, so a consumer can tell it apart from one cut short by an error.
+    /// Remote disks pass on the stream produced by the peer's local disk unchanged.
     async fn walk_dir<W: AsyncWrite + Unpin + Send>(&self, opts: WalkDirOptions, wr: &mut W) -> Result<()>;
 
     // Metadata operations
@@ -468,16 +582,28 @@ pub trait DiskAPI: Debug + Send + Sync + 'static {
     ) -> Result<RenameDataResp>;
 
     // File operations.
-    // Read every file and directory within the folder
+    // Read every file and directory within the folder, sorted by name. Directory names end with a
+    // slash, the same convention walk_dir and forward_to use. A positive count limits the entries.
     async fn list_dir(&self, origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>>;
     async fn read_file(&self, volume: &str, path: &str) -> Result<FileReader>;
-    async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<FileReader>;
+    // With `opts.verify` the whole file is hashed while the range is read, and the stream fails with
+    // FileCorrupt at its end when the digest differs from the checksum recorded by create_file.
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<FileReader>;
     async fn append_file(&self, volume: &str, path: &str) -> Result<FileWriter>;
     async fn create_file(&self, origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<FileWriter>;
     // ReadFileStream
     async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()>;
     async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()>;
     async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()>;
+    /// Cuts a file down to `size` bytes, e.g. to drop a partially written tail when undoing a write.
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()>;
     // VerifyFile
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp>;
     // CheckParts
@@ -485,10 +611,31 @@ pub trait DiskAPI: Debug + Send + Sync + 'static {
     // StatInfoFile
     async fn read_parts(&self, bucket: &str, paths: &[String]) -> Result<Vec<ObjectPartInfo>>;
     async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>>;
+    /// Stats each of `paths` without reading it, returning only `size` and `mod_time` in the
+    /// `FileInfo`, or `None` when the path is missing or not a regular file. Results follow the
+    /// order of `paths`.
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>>;
     // CleanAbandonedData
     async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()>;
     async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes>;
+    /// Reads `length` bytes starting at `offset`. A file that ends early yields the bytes up to
+    /// its end, or `LessData` when `exact` is set.
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes>;
     async fn disk_info(&self, opts: &DiskInfoOptions) -> Result<DiskInfo>;
+
+    /// Returns the number of bytes that can still be written before the disk reaches its
+    /// fill threshold. When `volume` is given it must exist on this disk.
+    ///
+    /// Bucket quotas are cluster wide and are not accounted for at the disk level.
+    async fn effective_free_space(&self, volume: Option<&str>) -> Result<u64> {
+        if let Some(volume) = volume {
+            self.stat_volume(volume).await?;
+        }
+
+        let info = self.disk_info(&DiskInfoOptions::default()).await?;
+
+        Ok(info.effective_free())
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -501,6 +648,7 @@ pub struct UpdateMetadataOpts {
     pub no_persistence: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct DiskLocation {
     pub pool_idx: Option<usize>,
     pub set_idx: Option<usize>,
@@ -511,6 +659,46 @@ impl DiskLocation {
     pub fn valid(&self) -> bool {
         self.pool_idx.is_some() && self.set_idx.is_some() && self.disk_idx.is_some()
     }
+
+    /// Whether this is the disk at `pool`/`set`/`disk`.
+    pub fn matches(&self, pool: usize, set: usize, disk: usize) -> bool {
+        self.pool_idx == Some(pool) && self.set_idx == Some(set) && self.disk_idx == Some(disk)
+    }
+
+    /// Position of the disk among all disks of its pool, for sets of `set_width` disks.
+    pub fn index(&self, set_width: usize) -> Option<usize> {
+        Some(self.set_idx? * set_width + self.disk_idx?)
+    }
+}
+
+impl std::fmt::Display for DiskLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let idx = |idx: Option<usize>| idx.map_or_else(|| "-".to_string(), |idx| idx.to_string());
+        write!(f, "{}/{}/{}", idx(self.pool_idx), idx(self.set_idx), idx(self.disk_idx))
+    }
+}
+
+/// Records the time until it is dropped into the `elapsed_ms` field of the span it was started in.
+///
+/// Disk operations start one first thing, so their spans report how long the call took on exit.
+pub(crate) struct SpanTimer {
+    span: tracing::Span,
+    start: std::time::Instant,
+}
+
+impl SpanTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            span: tracing::Span::current(),
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+impl Drop for SpanTimer {
+    fn drop(&mut self) {
+        self.span.record("elapsed_ms", self.start.elapsed().as_millis() as u64);
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -542,6 +730,16 @@ pub struct DiskInfo {
     pub error: String,
 }
 
+impl DiskInfo {
+    /// Free space left once the headroom reserved by `DISK_FILL_FRACTION` is set aside.
+    ///
+    /// A write smaller than this value passes the fill check in `has_space_for`.
+    pub fn effective_free(&self) -> u64 {
+        let reserved = (self.total as f64 * (1.0 - DISK_FILL_FRACTION)) as u64;
+        self.free.saturating_sub(reserved)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Info {
     pub total: u64,
@@ -585,7 +783,7 @@ impl FileInfoVersions {
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalkDirOptions {
     // Bucket to scanner
     pub bucket: String,
@@ -610,12 +808,159 @@ pub struct WalkDirOptions {
     // DiskID contains the disk ID of the disk.
     // Leave empty to not check disk ID.
     pub disk_id: String,
+
+    // Cancel stops the walk between entries. A cancelled walk returns Ok without terminating
+    // the stream. Never sent to a peer, a remote disk aborts the HTTP stream instead.
+    #[serde(skip)]
+    pub cancel: Option<CancellationToken>,
+
+    // SkipInternal leaves RUSTFS_META_BUCKET and everything below it out of the walk, unless
+    // Bucket itself is the meta bucket or one nested under it.
+    #[serde(default = "default_skip_internal")]
+    pub skip_internal: bool,
+}
+
+fn default_skip_internal() -> bool {
+    true
+}
+
+impl Default for WalkDirOptions {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            base_dir: String::new(),
+            recursive: false,
+            report_notfound: false,
+            filter_prefix: None,
+            forward_to: None,
+            limit: 0,
+            disk_id: String::new(),
+            cancel: None,
+            skip_internal: default_skip_internal(),
+        }
+    }
+}
+
+impl WalkDirOptions {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|cancel| cancel.is_cancelled())
+    }
+
+    /// Whether `path`, relative to `bucket`, is internal data that the walk must leave out.
+    pub fn skips(&self, path: &str) -> bool {
+        let in_meta_bucket = |p: &str| {
+            p.strip_prefix(RUSTFS_META_BUCKET)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        };
+
+        let bucket = self.bucket.trim_matches('/');
+        if !self.skip_internal || in_meta_bucket(bucket) {
+            return false;
+        }
+
+        let full = format!("{}/{}", bucket, path.trim_start_matches('/'));
+        in_meta_bucket(full.trim_matches('/'))
+    }
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DiskOption {
     pub cleanup: bool,
     pub health_check: bool,
+    /// Write large files to local drives with `O_DIRECT` where the drive supports it.
+    pub direct_io: bool,
+    /// Maximum files a local drive keeps open at once, 0 for no limit.
+    pub max_open_files: usize,
+    /// Mount local drives read-only, every call that would modify them is rejected.
+    pub read_only: bool,
+    /// Checksum local files while `create_file` writes them and keep the digest in the part metadata.
+    pub write_checksum: Option<HashAlgorithm>,
+    /// Buffer size for the HTTP streams of remote drives, a power of two between
+    /// `MIN_HTTP_CHUNK_SIZE` and `MAX_HTTP_CHUNK_SIZE`. 0 streams unbuffered.
+    pub chunk_size: usize,
+    /// Compress file streams to and from remote drives with this codec.
+    pub compression: Option<Codec>,
+    pub retry: RetryPolicy,
+    pub timeouts: RpcTimeouts,
+}
+
+/// Deadlines for remote disk calls, grouped by the kind of work they do.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RpcTimeouts {
+    /// Calls reading object data or metadata.
+    pub read: Duration,
+    /// Calls modifying objects.
+    pub write: Duration,
+    /// Drive and volume level calls such as `disk_info` and `stat_volume`.
+    pub metadata: Duration,
+}
+
+impl Default for RpcTimeouts {
+    fn default() -> Self {
+        let timeout = get_max_timeout_duration();
+        Self {
+            read: timeout,
+            write: timeout,
+            metadata: timeout,
+        }
+    }
+}
+
+/// Retry policy for idempotent remote disk calls that fail with transient errors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt, 0 disables retrying.
+    pub max_retries: usize,
+    /// Backoff before the first retry, doubled on every further retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for a single backoff.
+    pub max_backoff: Duration,
+    /// No retry is started once this much time has passed since the first attempt.
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            max_elapsed: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy without any retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Policy retrying immediately, mainly useful in tests.
+    pub fn no_delay(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            ..Default::default()
+        }
+    }
+
+    /// Backoff before retry number `attempt` (starting at 0), with jitter in `[backoff / 2, backoff]`.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 1u32.checked_shl(attempt as u32).unwrap_or(u32::MAX);
+        let backoff = self.initial_backoff.saturating_mul(factor).min(self.max_backoff);
+        if backoff.is_zero() {
+            return backoff;
+        }
+
+        let half = backoff / 2;
+        let jitter = rand::rng().random_range(0..=(backoff - half).as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -632,7 +977,7 @@ pub struct DeleteOptions {
     pub old_data_dir: Option<Uuid>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReadMultipleReq {
     pub bucket: String,
     pub prefix: String,
@@ -641,6 +986,9 @@ pub struct ReadMultipleReq {
     pub metadata_only: bool,
     pub abort404: bool,
     pub max_results: usize,
+    /// `*`/`?` glob matched against paths relative to `prefix`; only used when `files` is empty.
+    #[serde(default)]
+    pub pattern: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -660,11 +1008,80 @@ pub struct VolumeInfo {
     pub created: Option<OffsetDateTime>,
 }
 
+/// Space used by a volume on a single drive, as computed by `LocalDisk::volume_usage`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VolumeUsage {
+    /// Total size of all regular files, metadata included.
+    pub bytes: u64,
+    /// Number of `xl.meta` files.
+    pub objects: u64,
+    /// Number of versions recorded across those `xl.meta` files.
+    pub versions: u64,
+}
+
+/// Selects which parts of the object metadata `read_version` returns.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataProjection {
+    /// System and user metadata.
+    #[default]
+    Full,
+    /// Only system metadata (data dir, erasure info, parts); user headers are dropped.
+    SystemOnly,
+    /// Only user metadata; system fields are left at their defaults.
+    UserOnly,
+}
+
+impl MetadataProjection {
+    /// Whether the object data belongs to this projection. When it does not, `read_version`
+    /// neither loads inline data nor looks for the part files.
+    pub fn includes_data(&self) -> bool {
+        !matches!(self, MetadataProjection::UserOnly)
+    }
+
+    /// Strips the fields not covered by this projection from `fi`.
+    ///
+    /// Identity fields (volume, name, version, mod time, delete marker) are always kept.
+    pub fn apply(&self, mut fi: FileInfo) -> FileInfo {
+        match self {
+            MetadataProjection::Full => fi,
+            MetadataProjection::SystemOnly => {
+                fi.metadata.retain(|k, _| is_reserved_metadata_key(k));
+                fi
+            }
+            MetadataProjection::UserOnly => {
+                let mut metadata = std::mem::take(&mut fi.metadata);
+                metadata.retain(|k, _| !is_reserved_metadata_key(k));
+                FileInfo {
+                    volume: fi.volume,
+                    name: fi.name,
+                    version_id: fi.version_id,
+                    is_latest: fi.is_latest,
+                    deleted: fi.deleted,
+                    mod_time: fi.mod_time,
+                    num_versions: fi.num_versions,
+                    metadata,
+                    ..Default::default()
+                }
+            }
+        }
+    }
+}
+
+fn is_reserved_metadata_key(key: &str) -> bool {
+    key.starts_with(RESERVED_METADATA_PREFIX) || key.starts_with(RESERVED_METADATA_PREFIX_LOWER)
+}
+
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct ReadOptions {
     pub incl_free_versions: bool,
     pub read_data: bool,
     pub healing: bool,
+    #[serde(default)]
+    pub projection: MetadataProjection,
+    /// Make `read_file_stream` check the file against the checksum recorded when it was written.
+    /// Files without a recorded checksum fail to open instead of being served unverified.
+    #[serde(default)]
+    pub verify: bool,
 }
 
 pub const CHECK_PART_UNKNOWN: usize = 0;
@@ -703,6 +1120,31 @@ mod tests {
     use tokio::fs;
     use uuid::Uuid;
 
+    #[tokio::test]
+    async fn test_disk_activity_guard() {
+        let test_dir = "./test_disk_activity_guard";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = Arc::new(LocalDisk::new(&endpoint, false).await.unwrap());
+        let opts = DiskInfoOptions::default();
+
+        {
+            let _scanning = DiskActivityGuard::scanning(disk.clone());
+            let _healing = DiskActivityGuard::healing(disk.clone());
+            let info = disk.disk_info(&opts).await.unwrap();
+            assert!(info.scanning);
+            assert!(info.healing);
+        }
+
+        // Dropping the guards clears the flags
+        let info = disk.disk_info(&opts).await.unwrap();
+        assert!(!info.scanning);
+        assert!(!info.healing);
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
     /// Test DiskLocation validation
     #[test]
     fn test_disk_location_valid() {
@@ -728,6 +1170,56 @@ mod tests {
         assert!(!partial_valid_location.valid());
     }
 
+    #[test]
+    fn test_disk_location_display_and_eq() {
+        let location = DiskLocation {
+            pool_idx: Some(0),
+            set_idx: Some(1),
+            disk_idx: Some(2),
+        };
+        assert_eq!(location.to_string(), "0/1/2");
+        assert_eq!(
+            DiskLocation {
+                pool_idx: Some(0),
+                set_idx: None,
+                disk_idx: Some(2),
+            }
+            .to_string(),
+            "0/-/2"
+        );
+
+        assert_eq!(
+            location,
+            DiskLocation {
+                pool_idx: Some(0),
+                set_idx: Some(1),
+                disk_idx: Some(2),
+            }
+        );
+        assert_ne!(
+            location,
+            DiskLocation {
+                disk_idx: Some(3),
+                ..location
+            }
+        );
+        assert_ne!(location, DiskLocation::default());
+
+        assert!(location.matches(0, 1, 2));
+        assert!(!location.matches(0, 2, 1));
+        assert!(!DiskLocation::default().matches(0, 0, 0));
+
+        assert_eq!(location.index(4), Some(6));
+        assert_eq!(
+            DiskLocation {
+                set_idx: None,
+                ..location
+            }
+            .index(4),
+            None
+        );
+    }
+
     /// Test FileInfoVersions find_version_index
     #[test]
     fn test_file_info_versions_find_version_index() {
@@ -803,6 +1295,8 @@ mod tests {
             forward_to: Some("object/path".to_string()),
             limit: 100,
             disk_id: "disk-123".to_string(),
+            cancel: None,
+            skip_internal: true,
         };
 
         assert_eq!(opts.bucket, "test-bucket");
@@ -813,6 +1307,27 @@ mod tests {
         assert_eq!(opts.forward_to, Some("object/path".to_string()));
         assert_eq!(opts.limit, 100);
         assert_eq!(opts.disk_id, "disk-123");
+
+        // Internal data is skipped unless explicitly walked
+        assert!(WalkDirOptions::default().skip_internal);
+        let root = WalkDirOptions::default();
+        assert!(root.skips(".rustfs.sys"));
+        assert!(root.skips(".rustfs.sys/config/"));
+        assert!(!root.skips(".rustfs.system"));
+        assert!(!root.skips("bucket/object"));
+        let meta = WalkDirOptions {
+            bucket: RUSTFS_META_BUCKET.to_string(),
+            ..Default::default()
+        };
+        assert!(!meta.skips("config"));
+        let all = WalkDirOptions {
+            skip_internal: false,
+            ..Default::default()
+        };
+        assert!(!all.skips(".rustfs.sys/config"));
+
+        let decoded: WalkDirOptions = serde_json::from_str(r#"{"bucket":"b","base_dir":"","recursive":false,"report_notfound":false,"filter_prefix":null,"forward_to":null,"limit":0,"disk_id":""}"#).unwrap();
+        assert!(decoded.skip_internal);
     }
 
     /// Test DeleteOptions structure
@@ -838,11 +1353,64 @@ mod tests {
             incl_free_versions: true,
             read_data: false,
             healing: true,
+            projection: MetadataProjection::Full,
         };
 
         assert!(opts.incl_free_versions);
         assert!(!opts.read_data);
         assert!(opts.healing);
+        assert_eq!(opts.projection, MetadataProjection::Full);
+    }
+
+    /// Test that each metadata projection keeps only the requested fields
+    #[test]
+    fn test_metadata_projection() {
+        let mut fi = FileInfo::new("bucket/object", 2, 2);
+        fi.volume = "bucket".to_string();
+        fi.name = "object".to_string();
+        fi.version_id = Some(Uuid::new_v4());
+        fi.data_dir = Some(Uuid::new_v4());
+        fi.size = 1024;
+        fi.parts.push(ObjectPartInfo {
+            number: 1,
+            size: 1024,
+            ..Default::default()
+        });
+        fi.metadata
+            .insert(format!("{RESERVED_METADATA_PREFIX}actual-size"), "1024".to_string());
+        fi.metadata.insert("content-type".to_string(), "text/plain".to_string());
+
+        let full = MetadataProjection::Full.apply(fi.clone());
+        assert_eq!(full, fi);
+
+        let system = MetadataProjection::SystemOnly.apply(fi.clone());
+        assert_eq!(system.data_dir, fi.data_dir);
+        assert_eq!(system.erasure, fi.erasure);
+        assert_eq!(system.parts.len(), 1);
+        assert_eq!(system.size, 1024);
+        assert_eq!(system.metadata.len(), 1);
+        assert!(!system.metadata.contains_key("content-type"));
+
+        assert!(MetadataProjection::Full.includes_data());
+        assert!(MetadataProjection::SystemOnly.includes_data());
+        assert!(!MetadataProjection::UserOnly.includes_data());
+
+        let user = MetadataProjection::UserOnly.apply(fi.clone());
+        assert_eq!(user.name, "object");
+        assert_eq!(user.version_id, fi.version_id);
+        assert!(user.data_dir.is_none());
+        assert!(user.parts.is_empty());
+        assert_eq!(user.size, 0);
+        assert_eq!(user.erasure, Default::default());
+        assert_eq!(user.metadata.len(), 1);
+        assert_eq!(user.metadata.get("content-type").map(String::as_str), Some("text/plain"));
+    }
+
+    /// Test that ReadOptions without a projection deserializes to a full read
+    #[test]
+    fn test_read_options_projection_default() {
+        let opts: ReadOptions = serde_json::from_str(r#"{"incl_free_versions":false,"read_data":true,"healing":false}"#).unwrap();
+        assert_eq!(opts.projection, MetadataProjection::Full);
     }
 
     /// Test UpdateMetadataOpts structure
@@ -859,6 +1427,7 @@ mod tests {
         let opt = DiskOption {
             cleanup: true,
             health_check: false,
+            ..Default::default()
         };
 
         assert!(opt.cleanup);
@@ -879,6 +1448,96 @@ mod tests {
         assert!(!opts.noop);
     }
 
+    /// Test DiskInfo survives the JSON encoding used by the DiskInfo RPC
+    #[test]
+    fn test_disk_info_serde_roundtrip() {
+        let mut info = DiskInfo {
+            total: 1000,
+            free: 400,
+            used: 600,
+            fs_type: "xfs".to_string(),
+            endpoint: "http://node1:9000/data1".to_string(),
+            id: Some(Uuid::new_v4()),
+            rotational: true,
+            ..Default::default()
+        };
+        info.metrics.total_writes = 42;
+        info.metrics.api_calls.insert("read_all".to_string(), 7);
+
+        let json = serde_json::to_string(&info).unwrap();
+        let decoded: DiskInfo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, info);
+    }
+
+    /// Test effective free space keeps the fill headroom in reserve
+    #[test]
+    fn test_disk_info_effective_free() {
+        let info = DiskInfo {
+            total: 100_000,
+            free: 40_000,
+            used: 60_000,
+            ..Default::default()
+        };
+        assert_eq!(info.effective_free(), 39_000);
+
+        let full = DiskInfo {
+            total: 100_000,
+            free: 500,
+            used: 99_500,
+            ..Default::default()
+        };
+        assert_eq!(full.effective_free(), 0);
+    }
+
+    /// Test effective_free_space on a local disk
+    #[tokio::test]
+    async fn test_effective_free_space() {
+        let test_dir = "./test_effective_free_space";
+        fs::create_dir_all(&test_dir).await.unwrap();
+
+        let endpoint = Endpoint::try_from(test_dir).unwrap();
+        let disk = new_disk(&endpoint, &DiskOption::default()).await.unwrap();
+
+        let free = disk.effective_free_space(None).await.unwrap();
+        let info = disk.disk_info(&DiskInfoOptions::default()).await.unwrap();
+        assert!(free <= info.free);
+
+        disk.make_volume("test-volume").await.unwrap();
+        assert!(disk.effective_free_space(Some("test-volume")).await.is_ok());
+        assert_eq!(
+            disk.effective_free_space(Some("missing-volume")).await.unwrap_err(),
+            DiskError::VolumeNotFound
+        );
+
+        let _ = fs::remove_dir_all(&test_dir).await;
+    }
+
+    /// Test RetryPolicy backoff growth, cap and jitter bounds
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            max_elapsed: Duration::from_secs(10),
+        };
+
+        for _ in 0..20 {
+            let first = policy.backoff(0);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+            let second = policy.backoff(1);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+
+            let capped = policy.backoff(10);
+            assert!(capped >= Duration::from_millis(250) && capped <= Duration::from_millis(500));
+        }
+
+        assert_eq!(RetryPolicy::no_delay(3).backoff(2), Duration::ZERO);
+        assert_eq!(RetryPolicy::disabled().max_retries, 0);
+    }
+
     /// Test ReadMultipleReq structure
     #[test]
     fn test_read_multiple_req() {
@@ -890,6 +1549,7 @@ mod tests {
             metadata_only: false,
             abort404: true,
             max_results: 10,
+            pattern: None,
         };
 
         assert_eq!(req.bucket, "test-bucket");
@@ -901,6 +1561,24 @@ mod tests {
         assert_eq!(req.max_results, 10);
     }
 
+    /// Requests from peers without the pattern field still deserialize
+    #[test]
+    fn test_read_multiple_req_pattern_serde() {
+        let req = ReadMultipleReq {
+            bucket: "test-bucket".to_string(),
+            pattern: Some("*.json".to_string()),
+            ..Default::default()
+        };
+        let decoded: ReadMultipleReq = serde_json::from_str(&serde_json::to_string(&req).unwrap()).unwrap();
+        assert_eq!(decoded.pattern.as_deref(), Some("*.json"));
+
+        let legacy =
+            r#"{"bucket":"b","prefix":"p","files":["f"],"max_size":0,"metadata_only":false,"abort404":false,"max_results":0}"#;
+        let decoded: ReadMultipleReq = serde_json::from_str(legacy).unwrap();
+        assert!(decoded.pattern.is_none());
+        assert_eq!(decoded.files, ["f"]);
+    }
+
     /// Test ReadMultipleResp structure
     #[test]
     fn test_read_multiple_resp() {
@@ -994,6 +1672,7 @@ mod tests {
         let opt = DiskOption {
             cleanup: false,
             health_check: true,
+            ..Default::default()
         };
 
         let disk = new_disk(&endpoint, &opt).await;
@@ -119,6 +119,8 @@ pub async fn read_dir(path: impl AsRef<Path>, count: i32) -> std::io::Result<Vec
             volumes.push(name);
         } else if file_type.is_dir() {
             volumes.push(format!("{name}{SLASH_SEPARATOR_STR}"));
+        } else {
+            continue;
         }
         count -= 1;
         if count == 0 {
@@ -181,6 +183,60 @@ async fn reliable_rename(
     Ok(())
 }
 
+/// Like `rename_all`, but copies and then removes the source when it is on another device.
+/// Missing sources are reported as `FileNotFound`.
+pub async fn rename_all_or_copy(
+    src_file_path: impl AsRef<Path>,
+    dst_file_path: impl AsRef<Path>,
+    base_dir: impl AsRef<Path>,
+) -> Result<()> {
+    if let Some(parent) = dst_file_path.as_ref().parent()
+        && !file_exists(parent)
+    {
+        reliable_mkdir_all(parent, base_dir.as_ref()).await.map_err(to_file_error)?;
+    }
+
+    let (src, dst) = (src_file_path.as_ref().to_path_buf(), dst_file_path.as_ref().to_path_buf());
+    tokio::task::spawn_blocking(move || rename_or_copy_std(&src, &dst, |src, dst| super::fs::rename_std(src, dst)))
+        .await
+        .map_err(io::Error::other)?
+        .map_err(to_file_error)?;
+
+    Ok(())
+}
+
+/// Renames `src` to `dst`, falling back to copy-then-delete when they are on different devices.
+fn rename_or_copy_std(src: &Path, dst: &Path, rename: impl Fn(&Path, &Path) -> io::Result<()>) -> io::Result<()> {
+    match rename(src, dst) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            if std::fs::symlink_metadata(src)?.is_dir() {
+                copy_dir_all_std(src, dst)?;
+                std::fs::remove_dir_all(src)
+            } else {
+                std::fs::copy(src, dst)?;
+                std::fs::File::open(dst)?.sync_all()?;
+                std::fs::remove_file(src)
+            }
+        }
+        res => res,
+    }
+}
+
+fn copy_dir_all_std(src: &Path, dst: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all_std(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn reliable_mkdir_all(path: impl AsRef<Path>, base_dir: impl AsRef<Path>) -> io::Result<()> {
     let mut i = 0;
 
@@ -240,9 +296,71 @@ pub async fn os_mkdir_all(dir_path: impl AsRef<Path>, base_dir: impl AsRef<Path>
     Ok(())
 }
 
+/// Flushes the entries of directory `dir` to stable storage, so files created in it survive a crash.
+/// Does nothing on platforms where a directory cannot be opened for syncing.
+pub async fn sync_dir(dir: impl AsRef<Path>) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        fs::File::open(dir.as_ref()).await?.sync_all().await
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+        Ok(())
+    }
+}
+
 /// Check if a file exists.
 /// Returns true if the file exists, false otherwise.
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn file_exists(path: impl AsRef<Path>) -> bool {
     std::fs::metadata(path.as_ref()).map(|_| true).unwrap_or(false)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn cross_device(_: &Path, _: &Path) -> io::Result<()> {
+        Err(io::Error::from(io::ErrorKind::CrossesDevices))
+    }
+
+    #[test]
+    fn test_rename_or_copy_cross_device_file() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src.txt");
+        let dst = dir.path().join("dst.txt");
+        std::fs::write(&src, b"moved across devices").unwrap();
+
+        rename_or_copy_std(&src, &dst, cross_device).unwrap();
+
+        assert!(!src.exists());
+        assert_eq!(std::fs::read(&dst).unwrap(), b"moved across devices");
+    }
+
+    #[test]
+    fn test_rename_or_copy_cross_device_dir() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        let dst = dir.path().join("dst");
+        std::fs::create_dir_all(src.join("part")).unwrap();
+        std::fs::write(src.join("xl.meta"), b"meta").unwrap();
+        std::fs::write(src.join("part").join("part.1"), b"data").unwrap();
+
+        rename_or_copy_std(&src, &dst, cross_device).unwrap();
+
+        assert!(!src.exists());
+        assert_eq!(std::fs::read(dst.join("xl.meta")).unwrap(), b"meta");
+        assert_eq!(std::fs::read(dst.join("part").join("part.1")).unwrap(), b"data");
+    }
+
+    #[test]
+    fn test_rename_or_copy_other_errors_pass_through() {
+        let dir = TempDir::new().unwrap();
+        let err = rename_or_copy_std(&dir.path().join("missing"), &dir.path().join("dst"), |src, dst| std::fs::rename(src, dst))
+            .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::error::{DiskError, Result};
+use super::error_reduce::{OBJECT_OP_IGNORED_ERRS, reduce_read_quorum_errs};
+use super::{DiskAPI, FileReader, ReadOptions};
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use std::sync::Arc;
+
+/// Opens `volume/path` on all disks at once and returns as soon as `quorum` of them succeed.
+///
+/// The readers are returned in disk order, `None` for disks that are offline, failed or had not
+/// answered yet. Reads still in flight once the quorum is met are dropped, which cancels them.
+/// When the quorum can no longer be reached the errors are reduced with
+/// `reduce_read_quorum_errs`, so a missing object still reports `FileNotFound` while a scattered
+/// set of failures reports `ErasureReadQuorum`.
+pub async fn read_quorum<D>(
+    disks: &[Option<Arc<D>>],
+    volume: &str,
+    path: &str,
+    offset: usize,
+    length: usize,
+    quorum: usize,
+) -> Result<Vec<Option<FileReader>>>
+where
+    D: DiskAPI + ?Sized,
+{
+    let opts = ReadOptions::default();
+    let mut readers: Vec<Option<FileReader>> = disks.iter().map(|_| None).collect();
+    let mut errs: Vec<Option<DiskError>> = vec![None; disks.len()];
+
+    let mut futures = disks
+        .iter()
+        .enumerate()
+        .map(|(idx, disk)| {
+            let opts = &opts;
+            async move {
+                let res = match disk {
+                    Some(disk) => disk.read_file_stream(volume, path, offset, length, opts).await,
+                    None => Err(DiskError::DiskNotFound),
+                };
+                (idx, res)
+            }
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    let (mut succeeded, mut failed) = (0, 0);
+    while succeeded < quorum
+        && disks.len() - failed >= quorum
+        && let Some((idx, res)) = futures.next().await
+    {
+        match res {
+            Ok(reader) => {
+                readers[idx] = Some(reader);
+                succeeded += 1;
+            }
+            Err(err) => {
+                errs[idx] = Some(err);
+                failed += 1;
+            }
+        }
+    }
+
+    if succeeded >= quorum {
+        return Ok(readers);
+    }
+
+    // Only failures count, disks that never answered must not be taken for successes
+    let errs: Vec<Option<DiskError>> = errs.into_iter().filter(Option::is_some).collect();
+    Err(reduce_read_quorum_errs(&errs, OBJECT_OP_IGNORED_ERRS, quorum).unwrap_or(DiskError::ErasureReadQuorum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::endpoint::Endpoint;
+    use crate::disk::local::LocalDisk;
+    use bytes::Bytes;
+    use tempfile::TempDir;
+    use tokio::io::AsyncReadExt;
+
+    async fn new_disk(dir: &TempDir) -> Arc<LocalDisk> {
+        let endpoint = Endpoint::try_from(dir.path().to_str().unwrap()).unwrap();
+        let disk = LocalDisk::new(&endpoint, false).await.unwrap();
+        disk.make_volume("bucket").await.unwrap();
+        Arc::new(disk)
+    }
+
+    #[tokio::test]
+    async fn test_read_quorum() {
+        let dirs: Vec<TempDir> = (0..4).map(|_| TempDir::new().unwrap()).collect();
+        let mut disks = Vec::new();
+        for dir in dirs.iter() {
+            disks.push(Some(new_disk(dir).await));
+        }
+
+        // Two disks hold the shard, one misses it and one is offline
+        for disk in disks[..2].iter().flatten() {
+            disk.write_all("bucket", "object/part.1", Bytes::from_static(b"0123456789"))
+                .await
+                .unwrap();
+        }
+        disks[3] = None;
+
+        let mut readers = read_quorum(&disks, "bucket", "object/part.1", 2, 4, 2).await.unwrap();
+        assert_eq!(readers.iter().filter(|r| r.is_some()).count(), 2);
+        for reader in readers[..2].iter_mut() {
+            let mut buf = Vec::new();
+            reader.as_mut().unwrap().take(4).read_to_end(&mut buf).await.unwrap();
+            assert_eq!(buf, b"2345");
+        }
+
+        let err = read_quorum(&disks, "bucket", "object/part.1", 0, 10, 3).await.err().unwrap();
+        assert_eq!(err, DiskError::ErasureReadQuorum);
+
+        // A quorum of disks agreeing the file is missing reports just that
+        let err = read_quorum(&disks, "bucket", "missing/part.1", 0, 10, 2).await.err().unwrap();
+        assert_eq!(err, DiskError::FileNotFound);
+    }
+}
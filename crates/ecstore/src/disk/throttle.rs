@@ -0,0 +1,200 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::{Instant, Sleep};
+
+/// Token bucket refilled at a fixed number of bytes per second.
+///
+/// Each read or write may overdraw the bucket; the next one waits until the debt is paid off,
+/// so the long-run rate stays at the limit without splitting caller buffers.
+struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    last: Instant,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl TokenBucket {
+    fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            rate: bytes_per_sec.max(1) as f64,
+            tokens: 0.0,
+            last: Instant::now(),
+            sleep: None,
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        // Allow at most one second of burst after an idle period
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.last = now;
+    }
+
+    fn poll_acquire(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            if let Some(sleep) = self.sleep.as_mut() {
+                ready!(sleep.as_mut().poll(cx));
+                self.sleep = None;
+            }
+
+            self.refill();
+            if self.tokens >= 0.0 {
+                return Poll::Ready(());
+            }
+
+            let wait = Duration::from_secs_f64(-self.tokens / self.rate);
+            self.sleep = Some(Box::pin(tokio::time::sleep(wait)));
+        }
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.tokens -= n as f64;
+    }
+}
+
+/// Reader that limits the rate at which bytes are read from `inner`.
+///
+/// Works with any `AsyncRead`, so it can wrap the `FileReader` of a local or remote disk.
+pub struct ThrottledReader<R> {
+    inner: R,
+    bucket: TokenBucket,
+}
+
+impl<R> ThrottledReader<R> {
+    /// Wraps `inner`, capping reads at `bytes_per_sec`.
+    pub fn new(inner: R, bytes_per_sec: u64) -> Self {
+        Self {
+            inner,
+            bucket: TokenBucket::new(bytes_per_sec),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ThrottledReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.bucket.poll_acquire(cx));
+
+        let before = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        this.bucket.consume(buf.filled().len() - before);
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Writer that limits the rate at which bytes are written to `inner`.
+///
+/// Works with any `AsyncWrite`, so it can wrap the `FileWriter` of a local or remote disk.
+pub struct ThrottledWriter<W> {
+    inner: W,
+    bucket: TokenBucket,
+}
+
+impl<W> ThrottledWriter<W> {
+    /// Wraps `inner`, capping writes at `bytes_per_sec`.
+    pub fn new(inner: W, bytes_per_sec: u64) -> Self {
+        Self {
+            inner,
+            bucket: TokenBucket::new(bytes_per_sec),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for ThrottledWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.bucket.poll_acquire(cx));
+
+        let n = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.bucket.consume(n);
+
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::{FileReader, FileWriter};
+    use std::io::Cursor;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const SIZE: usize = 64 * 1024;
+    const RATE: u64 = 128 * 1024;
+    const CHUNK: usize = 4096;
+
+    fn min_elapsed() -> Duration {
+        // The last chunk may be read before its tokens are paid for
+        Duration::from_secs_f64((SIZE - CHUNK) as f64 / RATE as f64 * 0.9)
+    }
+
+    #[tokio::test]
+    async fn test_throttled_reader_rate() {
+        let inner: FileReader = Box::new(Cursor::new(vec![7u8; SIZE]));
+        let mut reader: FileReader = Box::new(ThrottledReader::new(inner, RATE));
+
+        let start = std::time::Instant::now();
+        let mut buf = vec![0u8; CHUNK];
+        let mut total = 0;
+        loop {
+            let n = reader.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            total += n;
+        }
+
+        assert_eq!(total, SIZE);
+        assert!(start.elapsed() >= min_elapsed(), "read took {:?}", start.elapsed());
+    }
+
+    #[tokio::test]
+    async fn test_throttled_writer_rate() {
+        let inner: FileWriter = Box::new(Cursor::new(Vec::new()));
+        let mut writer = ThrottledWriter::new(inner, RATE);
+
+        let start = std::time::Instant::now();
+        for chunk in vec![7u8; SIZE].chunks(CHUNK) {
+            writer.write_all(chunk).await.unwrap();
+        }
+        writer.shutdown().await.unwrap();
+
+        assert!(start.elapsed() >= min_elapsed(), "write took {:?}", start.elapsed());
+    }
+}
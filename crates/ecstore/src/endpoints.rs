@@ -146,6 +146,28 @@ impl Endpoints {
     }
 }
 
+/// Expands a single pool argument such as `http://node{1...4}:9000/data{1...8}` into its
+/// endpoints, split into erasure sets the same way the server command line is. Endpoints are
+/// returned set by set with `pool_idx` 0, `set_idx` and `disk_idx` assigned in that order.
+pub fn expand_endpoints(pattern: &str) -> Result<Vec<Endpoint>> {
+    let layout = DisksLayout::from_volumes(&[pattern])?;
+    let Some(pool) = layout.pools.first() else {
+        return Err(Error::other(format!("'{pattern}': no endpoints")));
+    };
+
+    let args: Vec<&str> = pool.iter().flatten().map(String::as_str).collect();
+    let mut endpoints = Endpoints::try_from(args.as_slice())?.into_inner();
+
+    let drives_per_set = layout.get_drives_per_set(0);
+    for (i, endpoint) in endpoints.iter_mut().enumerate() {
+        endpoint.set_pool_index(0);
+        endpoint.set_set_index(i / drives_per_set);
+        endpoint.set_disk_index(i % drives_per_set);
+    }
+
+    Ok(endpoints)
+}
+
 #[derive(Debug)]
 /// a temporary type to holds the list of endpoints
 struct PoolEndpointList {
@@ -653,6 +675,39 @@ mod test {
     use super::*;
     use std::path::Path;
 
+    #[test]
+    fn test_expand_endpoints() {
+        let endpoints = expand_endpoints("http://node{1...4}:9000/data{1...8}").unwrap();
+        assert_eq!(endpoints.len(), 32);
+
+        // Sets are spread across nodes, 32 drives make two sets of 16
+        assert_eq!(endpoints[0].to_string(), "http://node1:9000/data1");
+        assert_eq!(endpoints[1].to_string(), "http://node2:9000/data1");
+        assert_eq!(endpoints[4].to_string(), "http://node1:9000/data2");
+        for (i, ep) in endpoints.iter().enumerate() {
+            assert_eq!((ep.pool_idx, ep.set_idx, ep.disk_idx), (0, (i / 16) as i32, (i % 16) as i32));
+        }
+
+        // Zero padding follows the width of the range
+        let endpoints = expand_endpoints("http://node1:9000/disk{01...16}").unwrap();
+        assert_eq!(endpoints.len(), 16);
+        assert_eq!(endpoints[0].to_string(), "http://node1:9000/disk01");
+        assert_eq!(endpoints[15].to_string(), "http://node1:9000/disk16");
+        assert!(endpoints.iter().all(|ep| ep.set_idx == 0));
+    }
+
+    #[test]
+    fn test_expand_endpoints_invalid() {
+        for pattern in [
+            "http://node{1...4:9000/data{1...8}",
+            "http://node{1...{2...4}}:9000/data{1...8}",
+            "http://node{4...1}:9000/data{1...8}",
+            "http://node{a...d}:9000/data{1...8}",
+        ] {
+            assert!(expand_endpoints(pattern).is_err(), "{pattern}");
+        }
+    }
+
     #[test]
     fn test_new_endpoints() {
         let test_cases = [
@@ -181,7 +181,8 @@ where
 }
 
 pub fn bitrot_shard_file_size(size: usize, shard_size: usize, algo: HashAlgorithm) -> usize {
-    if algo != HashAlgorithm::HighwayHash256S {
+    // BitrotWriter prefixes every shard with its hash whenever the algorithm produces one
+    if algo.size() == 0 {
         return size;
     }
     size.div_ceil(shard_size) * algo.size() + size
@@ -199,7 +200,7 @@ pub async fn bitrot_verify<R: AsyncRead + Unpin + Send>(
     let mut left = want_size;
 
     if left != bitrot_shard_file_size(part_size, shard_size, algo.clone()) {
-        return Err(std::io::Error::other("bitrot shard file size mismatch"));
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "bitrot shard file size mismatch"));
     }
 
     while left > 0 {
@@ -215,7 +216,7 @@ pub async fn bitrot_verify<R: AsyncRead + Unpin + Send>(
 
         let actual_hash = algo.hash_encode(&buf);
         if actual_hash.as_ref() != &hash_buf[0..n] {
-            return Err(std::io::Error::other("bitrot hash mismatch"));
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "bitrot hash mismatch"));
         }
 
         left -= read;
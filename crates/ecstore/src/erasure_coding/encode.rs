@@ -79,6 +79,33 @@ impl<'a> MultiWriter<'a> {
             while let Some(()) = futures.next().await {}
         }
 
+        self.check_quorum()
+    }
+
+    /// Shuts every healthy writer down, which flushes buffered tails and finishes remote uploads.
+    /// Writers failing to close count as failed, like failed writes.
+    pub async fn shutdown(&mut self) -> std::io::Result<()> {
+        {
+            let mut futures = FuturesUnordered::new();
+            for (writer_opt, err) in self.writers.iter_mut().zip(self.errs.iter_mut()) {
+                if err.is_some() {
+                    continue;
+                }
+                if let Some(writer) = writer_opt.as_mut() {
+                    futures.push(async move {
+                        if let Err(e) = writer.shutdown().await {
+                            *err = Some(Error::from(e));
+                        }
+                    });
+                }
+            }
+            while let Some(()) = futures.next().await {}
+        }
+
+        self.check_quorum()
+    }
+
+    fn check_quorum(&self) -> std::io::Result<()> {
         let nil_count = self.errs.iter().filter(|&e| e.is_none()).count();
         if nil_count >= self.write_quorum {
             return Ok(());
@@ -111,13 +138,6 @@ impl<'a> MultiWriter<'a> {
                 .join(", ")
         )))
     }
-
-    pub async fn _shutdown(&mut self) -> std::io::Result<()> {
-        for writer in self.writers.iter_mut().flatten() {
-            writer.shutdown().await?;
-        }
-        Ok(())
-    }
 }
 
 impl Erasure {
@@ -176,7 +196,7 @@ impl Erasure {
         }
 
         let (reader, total) = task.await??;
-        // writers.shutdown().await?;
+        writers.shutdown().await?;
         Ok((reader, total))
     }
 }
@@ -80,6 +80,9 @@ impl super::Erasure {
             writers.write(shards).await?;
         }
 
+        let write_quorum = writers.iter().filter(|w| w.is_some()).count().max(1);
+        MultiWriter::new(writers, write_quorum).shutdown().await?;
+
         Ok(())
     }
 }
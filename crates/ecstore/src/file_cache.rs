@@ -170,6 +170,12 @@ impl OptimizedFileCache {
         results.into_iter().map(|(_, result)| result).collect()
     }
 
+    // Replace cache entries for a path with metadata that has not been written to disk
+    pub async fn put_metadata(&self, path: PathBuf, meta: FileMeta, data: Bytes) {
+        self.metadata_cache.insert(path.clone(), Arc::new(meta)).await;
+        self.file_content_cache.insert(path, data).await;
+    }
+
     // Invalidate cache entries for a path
     pub async fn invalidate(&self, path: &Path) {
         self.metadata_cache.remove(path).await;
@@ -0,0 +1,200 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transparent compression of the file streams exchanged between nodes.
+//!
+//! A remote disk configured with a [`Codec`] asks for compressed reads with `Accept-Encoding`
+//! and the peer answers with the first codec it supports. Uploads are only compressed, labelled
+//! with `Content-Encoding`, once the peer listed the codec in the `Accept-Encoding` response header
+//! of a `HEAD` request, so peers that cannot decode them keep receiving plain data.
+
+use crate::disk::{FileReader, FileWriter};
+use async_compression::tokio::{bufread, write};
+use std::{fmt, io, str::FromStr};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, BufReader};
+
+/// Codecs a peer decodes on uploads, sent back as `Accept-Encoding` when asked with `HEAD`.
+pub const ACCEPTED_ENCODINGS: &str = "gzip, zstd";
+
+/// A content coding for node to node transfers, named as in HTTP `Content-Encoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    Gzip,
+    Zstd,
+}
+
+impl Codec {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Codec::Gzip => "gzip",
+            Codec::Zstd => "zstd",
+        }
+    }
+
+    /// Compresses everything read from `reader`.
+    pub fn encode_reader<R>(self, reader: R) -> FileReader
+    where
+        R: AsyncBufRead + Send + Sync + Unpin + 'static,
+    {
+        match self {
+            Codec::Gzip => Box::new(bufread::GzipEncoder::new(reader)),
+            Codec::Zstd => Box::new(bufread::ZstdEncoder::new(reader)),
+        }
+    }
+
+    /// Decompresses everything read from `reader`.
+    pub fn decode_reader<R>(self, reader: R) -> FileReader
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        let reader = BufReader::new(reader);
+        match self {
+            Codec::Gzip => Box::new(bufread::GzipDecoder::new(reader)),
+            Codec::Zstd => Box::new(bufread::ZstdDecoder::new(reader)),
+        }
+    }
+
+    /// Compresses everything written before passing it on to `writer`.
+    /// The stream is only complete once the returned writer is shut down.
+    pub fn encode_writer<W>(self, writer: W) -> FileWriter
+    where
+        W: AsyncWrite + Send + Sync + Unpin + 'static,
+    {
+        match self {
+            Codec::Gzip => Box::new(write::GzipEncoder::new(writer)),
+            Codec::Zstd => Box::new(write::ZstdEncoder::new(writer)),
+        }
+    }
+
+    /// Decompresses everything written before passing it on to `writer`.
+    pub fn decode_writer<W>(self, writer: W) -> FileWriter
+    where
+        W: AsyncWrite + Send + Sync + Unpin + 'static,
+    {
+        match self {
+            Codec::Gzip => Box::new(write::GzipDecoder::new(writer)),
+            Codec::Zstd => Box::new(write::ZstdDecoder::new(writer)),
+        }
+    }
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Codec {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Ok(Codec::Gzip),
+            "zstd" => Ok(Codec::Zstd),
+            _ => Err(io::Error::other(format!("unsupported content encoding: {s}"))),
+        }
+    }
+}
+
+/// Picks the first codec listed in an `Accept-Encoding` value that is supported and not
+/// refused with `q=0`.
+pub fn negotiate(accept_encoding: &str) -> Option<Codec> {
+    accepted(accept_encoding).next()
+}
+
+/// Whether an `Accept-Encoding` value lists `codec` without refusing it with `q=0`.
+pub fn accepts(accept_encoding: &str, codec: Codec) -> bool {
+    accepted(accept_encoding).any(|c| c == codec)
+}
+
+fn accepted(accept_encoding: &str) -> impl Iterator<Item = Codec> + '_ {
+    accept_encoding.split(',').filter_map(|item| {
+        let mut parts = item.split(';');
+        let codec = parts.next()?.parse::<Codec>().ok()?;
+        let refused = parts.any(|param| {
+            param
+                .trim()
+                .strip_prefix("q=")
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .is_some_and(|q| q == 0.0)
+        });
+        (!refused).then_some(codec)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+    fn sample() -> Vec<u8> {
+        (0..256 * 1024).map(|i| (i % 97) as u8).collect()
+    }
+
+    /// Writes `data` through the writer built by `wrap` and returns what comes out the other end.
+    async fn write_through<F>(wrap: F, data: Vec<u8>) -> Vec<u8>
+    where
+        F: FnOnce(DuplexStream) -> FileWriter + Send + 'static,
+    {
+        let (tx, mut rx) = tokio::io::duplex(64 * 1024);
+        let writer = tokio::spawn(async move {
+            let mut writer = wrap(tx);
+            writer.write_all(&data).await.unwrap();
+            writer.shutdown().await.unwrap();
+        });
+
+        let mut out = Vec::new();
+        rx.read_to_end(&mut out).await.unwrap();
+        writer.await.unwrap();
+        out
+    }
+
+    #[tokio::test]
+    async fn test_codec_round_trip() {
+        let data = sample();
+
+        for codec in [Codec::Gzip, Codec::Zstd] {
+            // Uploads are encoded by the client's writer and decoded by the peer's writer
+            let encoded = write_through(move |w| codec.encode_writer(w), data.clone()).await;
+            assert!(encoded.len() < data.len() / 4, "{codec} did not compress");
+            let decoded = write_through(move |w| codec.decode_writer(w), encoded).await;
+            assert_eq!(decoded, data, "{codec} upload round trip");
+
+            // Downloads are encoded by the peer's reader and decoded by the client's reader
+            let mut decoded = Vec::new();
+            let mut reader = codec.decode_reader(codec.encode_reader(Cursor::new(data.clone())));
+            reader.read_to_end(&mut decoded).await.unwrap();
+            assert_eq!(decoded, data, "{codec} download round trip");
+        }
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate("zstd"), Some(Codec::Zstd));
+        assert_eq!(negotiate("br, gzip;q=0.8, zstd"), Some(Codec::Gzip));
+        assert_eq!(negotiate("gzip;q=0, zstd"), Some(Codec::Zstd));
+        assert_eq!(negotiate("identity, br"), None);
+        assert_eq!(negotiate(""), None);
+        assert_eq!("GZIP".parse::<Codec>().unwrap(), Codec::Gzip);
+    }
+
+    #[test]
+    fn test_accepts() {
+        assert!(accepts(ACCEPTED_ENCODINGS, Codec::Gzip));
+        assert!(accepts(ACCEPTED_ENCODINGS, Codec::Zstd));
+        assert!(!accepts("gzip;q=0, br", Codec::Gzip));
+        assert!(!accepts("", Codec::Zstd));
+    }
+}
@@ -166,6 +166,17 @@ mod tests {
         assert!(!signature1.is_empty(), "Signature should not be empty");
     }
 
+    #[test]
+    fn test_generate_signature_known_value() {
+        // HMAC-SHA256 over "{path_and_query}|{method}|{timestamp}", base64 encoded
+        let signature = generate_signature("test-secret", "http://example.com/api/test?x=1", &Method::GET, 1640995200);
+        assert_eq!(signature, "jSg8hiAus5EuSncEipXi7Lv1Ydlw40CtNgvvyTllfOs=");
+
+        // Scheme and host are not part of the signed data
+        let signature = generate_signature("test-secret", "https://other-host:9000/api/test?x=1", &Method::GET, 1640995200);
+        assert_eq!(signature, "jSg8hiAus5EuSncEipXi7Lv1Ydlw40CtNgvvyTllfOs=");
+    }
+
     #[test]
     fn test_generate_signature_different_inputs() {
         let secret = "test-secret";
@@ -13,15 +13,18 @@
 // limitations under the License.
 
 mod client;
+mod compression;
 mod http_auth;
 mod peer_rest_client;
 mod peer_s3_client;
 mod remote_disk;
 mod remote_locker;
+mod resumable_reader;
 
 pub use client::{
     TonicInterceptor, gen_tonic_signature_interceptor, node_service_time_out_client, node_service_time_out_client_no_auth,
 };
+pub use compression::{ACCEPTED_ENCODINGS, Codec, accepts, negotiate};
 pub use http_auth::{TONIC_RPC_PREFIX, build_auth_headers, gen_signature_headers, verify_rpc_signature};
 pub use peer_rest_client::PeerRestClient;
 pub use peer_s3_client::{LocalPeerS3Client, PeerS3Client, RemotePeerS3Client, S3PeerSys};
@@ -14,18 +14,25 @@
 
 use std::{
     path::PathBuf,
-    sync::{Arc, atomic::Ordering},
-    time::Duration,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
 use bytes::Bytes;
 use futures::lock::Mutex;
-use http::{HeaderMap, HeaderValue, Method, header::CONTENT_TYPE};
+use http::{
+    HeaderMap, HeaderValue, Method,
+    header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE},
+};
 use rustfs_protos::proto_gen::node_service::{
     CheckPartsRequest, DeletePathsRequest, DeleteRequest, DeleteVersionRequest, DeleteVersionsRequest, DeleteVolumeRequest,
-    DiskInfoRequest, ListDirRequest, ListVolumesRequest, MakeVolumeRequest, MakeVolumesRequest, ReadAllRequest,
-    ReadMultipleRequest, ReadPartsRequest, ReadVersionRequest, ReadXlRequest, RenameDataRequest, RenameFileRequest,
-    StatVolumeRequest, UpdateMetadataRequest, VerifyFileRequest, WriteAllRequest, WriteMetadataRequest,
+    DiskInfoRequest, ListDirRequest, ListVolumesRequest, MakeVolumeRequest, MakeVolumesRequest, MakeVolumesResponse,
+    ReadAllRequest, ReadMultipleRequest, ReadPartsRequest, ReadVersionRequest, ReadVersionResponse, ReadXlRequest,
+    ReadXlResponse, RenameDataRequest, RenameDataResponse, RenameFileRequest, StatPathsRequest, StatVolumeRequest,
+    TruncateRequest, UpdateMetadataRequest, VerifyFileRequest, WriteAllRequest, WriteMetadataRequest,
     node_service_client::NodeServiceClient,
 };
 use rustfs_utils::string::parse_bool_with_default;
@@ -41,10 +48,10 @@ use crate::{
 use crate::{
     disk::{
         CheckPartsResp, DeleteOptions, DiskAPI, DiskInfo, DiskInfoOptions, DiskLocation, DiskOption, FileInfoVersions,
-        ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp, UpdateMetadataOpts, VolumeInfo, WalkDirOptions,
+        ReadMultipleReq, ReadMultipleResp, ReadOptions, RenameDataResp, RetryPolicy, RpcTimeouts, SpanTimer, UpdateMetadataOpts,
+        VolumeInfo, WalkDirOptions,
         disk_store::{
-            CHECK_EVERY, CHECK_TIMEOUT_DURATION, ENV_RUSTFS_DRIVE_ACTIVE_MONITORING, SKIP_IF_SUCCESS_BEFORE,
-            get_max_timeout_duration,
+            CHECK_EVERY, CHECK_TIMEOUT_DURATION, ENV_RUSTFS_DRIVE_ACTIVE_MONITORING, SKIP_IF_SUCCESS_BEFORE, get_online_cache_ttl,
         },
         endpoint::Endpoint,
     },
@@ -52,15 +59,78 @@ use crate::{
 };
 use crate::{
     disk::{FileReader, FileWriter},
-    rpc::client::{TonicInterceptor, node_service_time_out_client},
+    rpc::{
+        Codec, accepts,
+        client::{TonicInterceptor, node_service_time_out_client},
+        resumable_reader::ResumableReader,
+    },
 };
 use rustfs_filemeta::{FileInfo, ObjectPartInfo, RawFileInfo};
+use rustfs_protos::evict_failed_connection;
 use rustfs_protos::proto_gen::node_service::RenamePartRequest;
 use rustfs_rio::{HttpReader, HttpWriter};
-use tokio::{io::AsyncWrite, net::TcpStream, time::timeout};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, BufReader, BufWriter},
+    net::TcpStream,
+    time::timeout,
+};
 use tonic::{Request, service::interceptor::InterceptedService, transport::Channel};
 use uuid::Uuid;
 
+/// Smallest accepted `DiskOption::chunk_size`.
+pub const MIN_HTTP_CHUNK_SIZE: usize = 4 << 10;
+/// Largest accepted `DiskOption::chunk_size`.
+pub const MAX_HTTP_CHUNK_SIZE: usize = 16 << 20;
+/// How long the peer's answer on upload compression is reused, so upgraded peers are picked up.
+const UPLOAD_COMPRESSION_PROBE_TTL: Duration = Duration::from_secs(60);
+
+/// Checks that a non-zero chunk size is a power of two within the accepted bounds.
+fn validate_chunk_size(chunk_size: usize) -> Result<()> {
+    if chunk_size != 0 && (!chunk_size.is_power_of_two() || !(MIN_HTTP_CHUNK_SIZE..=MAX_HTTP_CHUNK_SIZE).contains(&chunk_size)) {
+        return Err(Error::other(format!(
+            "invalid remote disk chunk size {chunk_size}: must be a power of two between {MIN_HTTP_CHUNK_SIZE} and {MAX_HTTP_CHUNK_SIZE}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Buffers an HTTP file stream so the peer is read in `chunk_size` pieces.
+fn chunked_reader<R>(chunk_size: usize, reader: R) -> FileReader
+where
+    R: AsyncRead + Send + Sync + Unpin + 'static,
+{
+    if chunk_size == 0 {
+        return Box::new(reader);
+    }
+
+    Box::new(BufReader::with_capacity(chunk_size, reader))
+}
+
+/// Opens a file download from the peer, asking for it compressed when `compression` is set.
+async fn open_http_read_stream(url: String, compression: Option<Codec>, chunk_size: usize) -> Result<FileReader> {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    if let Some(codec) = compression {
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static(codec.as_str()));
+    }
+    build_auth_headers(&url, &Method::GET, &mut headers);
+
+    let reader = HttpReader::new(url, Method::GET, headers, None).await?;
+    // Peers without a codec in common answer uncompressed
+    let codec = reader
+        .response_headers()
+        .get(CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<Codec>().ok());
+
+    let reader = chunked_reader(chunk_size, reader);
+    Ok(match codec {
+        Some(codec) => codec.decode_reader(reader),
+        None => reader,
+    })
+}
+
 #[derive(Debug)]
 pub struct RemoteDisk {
     pub id: Mutex<Option<Uuid>>,
@@ -74,17 +144,34 @@ pub struct RemoteDisk {
     health: Arc<DiskHealthTracker>,
     /// Cancellation token for monitoring tasks
     cancel_token: CancellationToken,
+    /// Retry policy for idempotent calls
+    retry: RetryPolicy,
+    /// Deadlines for read, write and metadata calls
+    timeouts: RpcTimeouts,
+    /// Result of the last connectivity probe of the health monitor, read by `is_online`
+    online: Arc<AtomicBool>,
+    /// How often the health monitor refreshes `online`
+    online_cache_ttl: Duration,
+    /// Set by `close`, after which every call fails with `DiskClosed`
+    closed: AtomicBool,
+    /// Buffer size for HTTP file streams, 0 for unbuffered
+    chunk_size: usize,
+    /// Codec for HTTP file streams, negotiated with the peer on reads
+    compression: Option<Codec>,
+    /// Whether the peer decodes uploads compressed with `compression`, and when it was asked
+    upload_compression: Mutex<Option<(Instant, bool)>>,
+    /// Set while this node scans the disk, on top of what the peer reports
+    scanning: AtomicBool,
+    /// Set while this node heals the disk, on top of what the peer reports
+    healing: AtomicBool,
 }
 
 impl RemoteDisk {
     pub async fn new(ep: &Endpoint, opt: &DiskOption) -> Result<Self> {
         // let root = fs::canonicalize(ep.url.path()).await?;
         let root = PathBuf::from(ep.get_file_path());
-        let addr = if let Some(port) = ep.url.port() {
-            format!("{}://{}:{}", ep.url.scheme(), ep.url.host_str().unwrap(), port)
-        } else {
-            format!("{}://{}", ep.url.scheme(), ep.url.host_str().unwrap())
-        };
+        let addr = ep.try_grid_host()?;
+        validate_chunk_size(opt.chunk_size)?;
 
         let env_health_check = std::env::var(ENV_RUSTFS_DRIVE_ACTIVE_MONITORING)
             .map(|v| parse_bool_with_default(&v, true))
@@ -99,6 +186,16 @@ impl RemoteDisk {
             health_check: opt.health_check && env_health_check,
             health: Arc::new(DiskHealthTracker::new()),
             cancel_token: CancellationToken::new(),
+            retry: opt.retry.clone(),
+            timeouts: opt.timeouts.clone(),
+            online: Arc::new(AtomicBool::new(true)),
+            online_cache_ttl: get_online_cache_ttl(),
+            closed: AtomicBool::new(false),
+            chunk_size: opt.chunk_size,
+            compression: opt.compression,
+            upload_compression: Mutex::new(None),
+            scanning: AtomicBool::new(false),
+            healing: AtomicBool::new(false),
         };
 
         // Start health monitoring
@@ -111,22 +208,33 @@ impl RemoteDisk {
     fn start_health_monitoring(&self) {
         if self.health_check {
             let health = Arc::clone(&self.health);
+            let online = Arc::clone(&self.online);
+            let refresh_every = self.online_cache_ttl;
             let cancel_token = self.cancel_token.clone();
             let addr = self.addr.clone();
 
             tokio::spawn(async move {
-                Self::monitor_remote_disk_health(addr, health, cancel_token).await;
+                Self::monitor_remote_disk_health(addr, health, online, refresh_every, cancel_token).await;
             });
         }
     }
 
     /// Monitor remote disk health periodically
-    async fn monitor_remote_disk_health(addr: String, health: Arc<DiskHealthTracker>, cancel_token: CancellationToken) {
+    async fn monitor_remote_disk_health(
+        addr: String,
+        health: Arc<DiskHealthTracker>,
+        online: Arc<AtomicBool>,
+        refresh_every: Duration,
+        cancel_token: CancellationToken,
+    ) {
         let mut interval = time::interval(CHECK_EVERY);
+        let mut refresh = time::interval(refresh_every.max(Duration::from_millis(1)));
+        refresh.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
 
         // Perform basic connectivity check
-        if Self::perform_connectivity_check(&addr).await.is_err() && health.swap_ok_to_faulty() {
+        if Self::probe_connectivity(&addr, &health, &online).await {
             warn!("Remote disk health check failed for {}: marking as faulty", addr);
+            evict_failed_connection(&addr).await;
 
             // Start recovery monitoring
             let health_clone = Arc::clone(&health);
@@ -167,8 +275,9 @@ impl RemoteDisk {
                     }
 
                     // Perform basic connectivity check
-                    if Self::perform_connectivity_check(&addr).await.is_err() && health.swap_ok_to_faulty() {
+                    if Self::probe_connectivity(&addr, &health, &online).await {
                         warn!("Remote disk health check failed for {}: marking as faulty", addr);
+                        evict_failed_connection(&addr).await;
 
                         // Start recovery monitoring
                         let health_clone = Arc::clone(&health);
@@ -180,6 +289,11 @@ impl RemoteDisk {
                         });
                     }
                 }
+                _ = refresh.tick() => {
+                    // Keep the online state fresh for `is_online`, failures here don't count towards faulty
+                    let state = Self::perform_connectivity_check(&addr).await.is_ok();
+                    online.store(state, Ordering::Release);
+                }
             }
         }
     }
@@ -204,6 +318,26 @@ impl RemoteDisk {
         }
     }
 
+    /// Runs one connectivity probe and returns true if its failure marked the disk faulty.
+    async fn probe_connectivity(addr: &str, health: &DiskHealthTracker, online: &AtomicBool) -> bool {
+        match Self::perform_connectivity_check(addr).await {
+            Ok(()) => {
+                online.store(true, Ordering::Release);
+                health.record_probe_success();
+                false
+            }
+            Err(_) => {
+                online.store(false, Ordering::Release);
+                health.record_probe_failure()
+            }
+        }
+    }
+
+    /// Whether background probes have marked the disk faulty
+    pub fn is_faulty(&self) -> bool {
+        self.health.is_faulty()
+    }
+
     /// Perform basic connectivity check for remote disk
     async fn perform_connectivity_check(addr: &str) -> Result<()> {
         let url = url::Url::parse(addr).map_err(|e| Error::other(format!("Invalid URL: {e}")))?;
@@ -224,18 +358,128 @@ impl RemoteDisk {
         }
     }
 
-    /// Execute operation with timeout and health tracking
-    async fn execute_with_timeout<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
+    /// Buffers an HTTP file stream so writes reach the peer in `chunk_size` pieces.
+    ///
+    /// The buffered tail is only sent by `shutdown`, a writer dropped before that fails the upload.
+    fn chunked_writer<W>(&self, writer: W) -> FileWriter
     where
-        F: FnOnce() -> Fut,
-        Fut: std::future::Future<Output = Result<T>>,
+        W: AsyncWrite + Send + Sync + Unpin + 'static,
     {
-        // Check if disk is faulty
+        if self.chunk_size == 0 {
+            return Box::new(writer);
+        }
+
+        Box::new(BufWriter::with_capacity(self.chunk_size, writer))
+    }
+
+    /// Opens `length` bytes at `offset` of a file on the peer, 0 meaning the rest of the file.
+    ///
+    /// A download that breaks off is requested again from where it stopped, within the retry policy.
+    async fn open_read_stream(&self, volume: &str, path: &str, offset: usize, length: usize, verify: bool) -> Result<FileReader> {
+        let (host, disk) = (self.endpoint.grid_host(), self.endpoint.to_string());
+        let (volume, path) = (volume.to_string(), path.to_string());
+        let url_from = move |consumed: usize| {
+            format!(
+                "{}/rustfs/rpc/read_file_stream?disk={}&volume={}&path={}&offset={}&length={}&verify={}",
+                host,
+                urlencoding::encode(&disk),
+                urlencoding::encode(&volume),
+                urlencoding::encode(&path),
+                offset + consumed,
+                if length == 0 { 0 } else { length - consumed },
+                verify
+            )
+        };
+
+        let (compression, chunk_size) = (self.compression, self.chunk_size);
+        let reader = open_http_read_stream(url_from(0), compression, chunk_size).await?;
+        let reopen = move |consumed: u64| open_http_read_stream(url_from(consumed as usize), compression, chunk_size);
+
+        Ok(Box::new(ResumableReader::new(
+            reader,
+            reopen,
+            self.retry.clone(),
+            (length > 0).then_some(length as u64),
+        )))
+    }
+
+    /// Returns the codec to compress uploads with, `None` unless compression is enabled and the
+    /// peer lists the codec as accepted. Peers predating upload compression store the body as
+    /// sent, so they are asked first and the answer is reused for `UPLOAD_COMPRESSION_PROBE_TTL`.
+    async fn upload_codec(&self) -> Option<Codec> {
+        let codec = self.compression?;
+
+        let mut cached = self.upload_compression.lock().await;
+        if let Some((checked_at, accepted)) = *cached
+            && checked_at.elapsed() < UPLOAD_COMPRESSION_PROBE_TTL
+        {
+            return accepted.then_some(codec);
+        }
+
+        let url = format!(
+            "{}/rustfs/rpc/put_file_stream?disk={}",
+            self.endpoint.grid_host(),
+            urlencoding::encode(&self.endpoint.to_string())
+        );
+        let mut headers = HeaderMap::new();
+        build_auth_headers(&url, &Method::HEAD, &mut headers);
+        let accepted = match HttpReader::new(url, Method::HEAD, headers, None).await {
+            Ok(reader) => reader
+                .response_headers()
+                .get(ACCEPT_ENCODING)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| accepts(v, codec)),
+            Err(err) => {
+                debug!("upload compression probe to {} failed: {err}", self.endpoint);
+                false
+            }
+        };
+
+        *cached = Some((Instant::now(), accepted));
+        accepted.then_some(codec)
+    }
+
+    /// Opens a file upload to the peer, compressed when compression is enabled and the peer
+    /// accepts it.
+    async fn open_write_stream(&self, url: String) -> Result<FileWriter> {
+        let codec = self.upload_codec().await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(codec) = codec {
+            headers.insert(CONTENT_ENCODING, HeaderValue::from_static(codec.as_str()));
+        }
+        build_auth_headers(&url, &Method::PUT, &mut headers);
+
+        let writer = self.chunked_writer(HttpWriter::new(url, Method::PUT, headers).await?);
+        Ok(match codec {
+            Some(codec) => codec.encode_writer(writer),
+            None => writer,
+        })
+    }
+
+    /// Fails once the disk is closed or while it is marked faulty.
+    fn check_usable(&self) -> Result<()> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(DiskError::DiskClosed);
+        }
+
         if self.health.is_faulty() {
             warn!("disk {} health is faulty, returning error", self.to_string());
             return Err(DiskError::FaultyDisk);
         }
 
+        Ok(())
+    }
+
+    /// Execute operation with timeout and health tracking
+    async fn execute_with_timeout<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        self.check_usable()?;
+
         // Record operation start
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -260,15 +504,91 @@ impl RemoteDisk {
                 // Timeout occurred, mark disk as potentially faulty
                 self.health.decrement_waiting();
                 warn!("Remote disk operation timeout after {:?}", timeout_duration);
-                Err(Error::other(format!("Remote disk operation timeout after {timeout_duration:?}")))
+                // The cached channel may be dead, reconnect on the next call
+                evict_failed_connection(&self.addr).await;
+                Err(DiskError::Io(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("Remote disk operation timeout after {timeout_duration:?}"),
+                )))
+            }
+        }
+    }
+
+    /// Execute an idempotent operation, retrying transient failures according to the retry policy.
+    ///
+    /// Only transport level errors and timeouts are retried (see `is_retryable`); errors reported by
+    /// the remote disk are returned as is.
+    async fn execute_with_retry<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let start = std::time::Instant::now();
+        let mut attempt = 0;
+
+        loop {
+            let err = match self.execute_with_timeout(&operation, timeout_duration).await {
+                Ok(res) => return Ok(res),
+                Err(err) => err,
+            };
+
+            if attempt >= self.retry.max_retries || !is_retryable(&err) {
+                return Err(err);
+            }
+
+            let backoff = self.retry.backoff(attempt);
+            if start.elapsed() + backoff > self.retry.max_elapsed {
+                return Err(err);
             }
+
+            attempt += 1;
+            debug!(
+                "remote disk {} call failed: {}, retry {}/{} in {:?}",
+                self.addr, err, attempt, self.retry.max_retries, backoff
+            );
+            time::sleep(backoff).await;
         }
     }
 
+    /// Makes all `volumes` with a single call to the peer and returns one result per volume, in order.
+    pub async fn make_volumes_each(&self, volumes: Vec<&str>) -> Result<Vec<Result<()>>> {
+        self.execute_with_timeout(
+            || async {
+                let mut client = self.get_client().await?;
+                let request = Request::new(MakeVolumesRequest {
+                    disk: self.endpoint.to_string(),
+                    volumes: volumes.iter().map(|s| (*s).to_string()).collect(),
+                });
+
+                let response = client.make_volumes(request).await?.into_inner();
+
+                decode_make_volumes_response(volumes.len(), response)
+            },
+            self.timeouts.metadata,
+        )
+        .await
+    }
+
     async fn get_client(&self) -> Result<NodeServiceClient<InterceptedService<Channel, TonicInterceptor>>> {
         node_service_time_out_client(&self.addr, TonicInterceptor::Signature(gen_tonic_signature_interceptor()))
             .await
-            .map_err(|err| Error::other(format!("can not get client, err: {err}")))
+            .map_err(|err| {
+                DiskError::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotConnected,
+                    format!("can not get client, err: {err}"),
+                ))
+            })
+    }
+}
+
+/// Whether a failed call may succeed when sent again: the peer could not be reached or did not
+/// answer in time. gRPC `Unavailable` and connect failures surface as `NotConnected`,
+/// `DeadlineExceeded` and local timeouts as `TimedOut`, anything else came from the peer.
+fn is_retryable(err: &DiskError) -> bool {
+    match err {
+        DiskError::Timeout => true,
+        DiskError::Io(err) => matches!(err.kind(), std::io::ErrorKind::NotConnected | std::io::ErrorKind::TimedOut),
+        _ => false,
     }
 }
 
@@ -282,8 +602,13 @@ impl DiskAPI for RemoteDisk {
 
     #[tracing::instrument(skip(self))]
     async fn is_online(&self) -> bool {
-        // If disk is marked as faulty, consider it offline
-        !self.health.is_faulty()
+        // If disk is closed or marked as faulty, consider it offline
+        if self.closed.load(Ordering::Acquire) || self.health.is_faulty() {
+            return false;
+        }
+
+        // Probing is left to the health monitor, callers poll this on hot paths
+        self.online.load(Ordering::Acquire)
     }
 
     #[tracing::instrument(skip(self))]
@@ -300,6 +625,12 @@ impl DiskAPI for RemoteDisk {
     }
     #[tracing::instrument(skip(self))]
     async fn close(&self) -> Result<()> {
+        if self.closed.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        // Stop the monitors. The pooled channel is shared with the other disks of the node and
+        // stays, the closed flag already keeps this disk from using it.
         self.cancel_token.cancel();
         Ok(())
     }
@@ -316,6 +647,14 @@ impl DiskAPI for RemoteDisk {
         Ok(())
     }
 
+    fn set_scanning(&self, scanning: bool) {
+        self.scanning.store(scanning, Ordering::Release);
+    }
+
+    fn set_healing(&self, healing: bool) {
+        self.healing.store(healing, Ordering::Release);
+    }
+
     #[tracing::instrument(skip(self))]
     fn path(&self) -> PathBuf {
         self.root.clone()
@@ -354,10 +693,7 @@ impl DiskAPI for RemoteDisk {
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(MakeVolumeRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -371,7 +707,7 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.metadata,
         )
         .await
     }
@@ -380,40 +716,29 @@ impl DiskAPI for RemoteDisk {
     async fn make_volumes(&self, volumes: Vec<&str>) -> Result<()> {
         info!("make_volumes");
 
-        self.execute_with_timeout(
-            || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
-                let request = Request::new(MakeVolumesRequest {
-                    disk: self.endpoint.to_string(),
-                    volumes: volumes.iter().map(|s| (*s).to_string()).collect(),
-                });
-
-                let response = client.make_volumes(request).await?.into_inner();
-
-                if !response.success {
-                    return Err(response.error.unwrap_or_default().into());
-                }
-
-                Ok(())
-            },
-            get_max_timeout_duration(),
-        )
-        .await
+        let results = self.make_volumes_each(volumes.clone()).await?;
+        let failed: Vec<(&str, &Error)> = volumes
+            .iter()
+            .zip(results.iter())
+            .filter_map(|(volume, res)| res.as_ref().err().map(|err| (*volume, err)))
+            .collect();
+
+        match failed.first() {
+            Some((_, err)) => {
+                warn!("make_volumes failed for {:?}", failed);
+                Err((*err).clone())
+            }
+            None => Ok(()),
+        }
     }
 
     #[tracing::instrument(skip(self))]
     async fn list_volumes(&self) -> Result<Vec<VolumeInfo>> {
         info!("list_volumes");
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(ListVolumesRequest {
                     disk: self.endpoint.to_string(),
                 });
@@ -432,7 +757,7 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(infos)
             },
-            Duration::ZERO,
+            self.timeouts.metadata,
         )
         .await
     }
@@ -441,12 +766,9 @@ impl DiskAPI for RemoteDisk {
     async fn stat_volume(&self, volume: &str) -> Result<VolumeInfo> {
         info!("stat_volume");
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(StatVolumeRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -462,7 +784,7 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(volume_info)
             },
-            get_max_timeout_duration(),
+            self.timeouts.metadata,
         )
         .await
     }
@@ -473,10 +795,7 @@ impl DiskAPI for RemoteDisk {
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(DeleteVolumeRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -490,7 +809,7 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            Duration::ZERO,
+            self.timeouts.metadata,
         )
         .await
     }
@@ -545,7 +864,7 @@ impl DiskAPI for RemoteDisk {
     //     Ok(())
     // }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete_version(
         &self,
         volume: &str,
@@ -554,6 +873,7 @@ impl DiskAPI for RemoteDisk {
         force_del_marker: bool,
         opts: DeleteOptions,
     ) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("delete_version");
 
         self.execute_with_timeout(
@@ -561,10 +881,7 @@ impl DiskAPI for RemoteDisk {
                 let file_info = serde_json::to_string(&fi)?;
                 let opts = serde_json::to_string(&opts)?;
 
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(DeleteVersionRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -584,17 +901,18 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete_versions(&self, volume: &str, versions: Vec<FileInfoVersions>, opts: DeleteOptions) -> Vec<Option<Error>> {
+        let _timer = SpanTimer::start();
         info!("delete_versions");
 
-        if self.health.is_faulty() {
-            return vec![Some(DiskError::FaultyDisk); versions.len()];
+        if let Err(err) = self.check_usable() {
+            return vec![Some(err); versions.len()];
         }
 
         let opts = match serde_json::to_string(&opts) {
@@ -648,7 +966,7 @@ impl DiskAPI for RemoteDisk {
                         .await
                         .map_err(|err| Error::other(format!("delete_versions failed: {err}")))
                 },
-                get_max_timeout_duration(),
+                self.timeouts.write,
             )
             .await;
 
@@ -667,7 +985,7 @@ impl DiskAPI for RemoteDisk {
         if !response.success {
             let mut errors = Vec::with_capacity(versions.len());
             for _ in 0..versions.len() {
-                errors.push(Some(Error::other(response.error.clone().map(|e| e.error_info).unwrap_or_default())));
+                errors.push(Some(response.error.clone().unwrap_or_default().into()));
             }
             return errors;
         }
@@ -678,7 +996,7 @@ impl DiskAPI for RemoteDisk {
                 if error.is_empty() {
                     None
                 } else {
-                    Some(Error::other(error.to_string()))
+                    Some(Error::from_remote(0, error.as_str()))
                 }
             })
             .collect()
@@ -691,10 +1009,7 @@ impl DiskAPI for RemoteDisk {
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(DeletePathsRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -709,22 +1024,20 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn write_metadata(&self, _org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("write_metadata {}/{}", volume, path);
         let file_info = serde_json::to_string(&fi)?;
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(WriteMetadataRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -740,23 +1053,21 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn update_metadata(&self, volume: &str, path: &str, fi: FileInfo, opts: &UpdateMetadataOpts) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("update_metadata");
         let file_info = serde_json::to_string(&fi)?;
         let opts_str = serde_json::to_string(&opts)?;
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(UpdateMetadataRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -773,12 +1084,12 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_version(
         &self,
         _org_volume: &str,
@@ -787,15 +1098,13 @@ impl DiskAPI for RemoteDisk {
         version_id: &str,
         opts: &ReadOptions,
     ) -> Result<FileInfo> {
+        let _timer = SpanTimer::start();
         info!("read_version");
         let opts_str = serde_json::to_string(opts)?;
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(ReadVersionRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -806,29 +1115,21 @@ impl DiskAPI for RemoteDisk {
 
                 let response = client.read_version(request).await?.into_inner();
 
-                if !response.success {
-                    return Err(response.error.unwrap_or_default().into());
-                }
-
-                let file_info = serde_json::from_str::<FileInfo>(&response.file_info)?;
-
-                Ok(file_info)
+                decode_read_version_response(response)
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_xl(&self, volume: &str, path: &str, read_data: bool) -> Result<RawFileInfo> {
+        let _timer = SpanTimer::start();
         info!("read_xl {}/{}/{}", self.endpoint.to_string(), volume, path);
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(ReadXlRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -838,20 +1139,14 @@ impl DiskAPI for RemoteDisk {
 
                 let response = client.read_xl(request).await?.into_inner();
 
-                if !response.success {
-                    return Err(response.error.unwrap_or_default().into());
-                }
-
-                let raw_file_info = serde_json::from_str::<RawFileInfo>(&response.raw_file_info)?;
-
-                Ok(raw_file_info)
+                decode_read_xl_response(response)
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_data(
         &self,
         src_volume: &str,
@@ -860,15 +1155,13 @@ impl DiskAPI for RemoteDisk {
         dst_volume: &str,
         dst_path: &str,
     ) -> Result<RenameDataResp> {
+        let _timer = SpanTimer::start();
         info!("rename_data {}/{}/{}/{}", self.addr, self.endpoint.to_string(), dst_volume, dst_path);
 
         self.execute_with_timeout(
             || async {
                 let file_info = serde_json::to_string(&fi)?;
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(RenameDataRequest {
                     disk: self.endpoint.to_string(),
                     src_volume: src_volume.to_string(),
@@ -880,15 +1173,9 @@ impl DiskAPI for RemoteDisk {
 
                 let response = client.rename_data(request).await?.into_inner();
 
-                if !response.success {
-                    return Err(response.error.unwrap_or_default().into());
-                }
-
-                let rename_data_resp = serde_json::from_str::<RenameDataResp>(&response.rename_data_resp)?;
-
-                Ok(rename_data_resp)
+                decode_rename_data_response(response)
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
@@ -897,37 +1184,41 @@ impl DiskAPI for RemoteDisk {
     async fn list_dir(&self, _origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>> {
         debug!("list_dir {}/{}", volume, dir_path);
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
+        self.execute_with_retry(
+            || async {
+                let mut client = self.get_client().await?;
+                let request = Request::new(ListDirRequest {
+                    disk: self.endpoint.to_string(),
+                    volume: volume.to_string(),
+                    dir_path: dir_path.to_string(),
+                    count,
+                });
 
-        let mut client = self
-            .get_client()
-            .await
-            .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
-        let request = Request::new(ListDirRequest {
-            disk: self.endpoint.to_string(),
-            volume: volume.to_string(),
-            dir_path: dir_path.to_string(),
-            count,
-        });
+                let response = client.list_dir(request).await?.into_inner();
 
-        let response = client.list_dir(request).await?.into_inner();
+                if !response.success {
+                    return Err(response.error.unwrap_or_default().into());
+                }
 
-        if !response.success {
-            return Err(response.error.unwrap_or_default().into());
-        }
+                let mut entries = response.volumes;
+                // The limit is applied by the peer, but never hand out more than asked for
+                if count > 0 {
+                    entries.truncate(count as usize);
+                }
 
-        Ok(response.volumes)
+                Ok(entries)
+            },
+            self.timeouts.read,
+        )
+        .await
     }
 
-    #[tracing::instrument(skip(self, wr))]
+    #[tracing::instrument(skip(self, wr), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn walk_dir<W: AsyncWrite + Unpin + Send>(&self, opts: WalkDirOptions, wr: &mut W) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("walk_dir {}", self.endpoint.to_string());
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
+        self.check_usable()?;
 
         let url = format!(
             "{}/rustfs/rpc/walk_dir?disk={}",
@@ -935,6 +1226,7 @@ impl DiskAPI for RemoteDisk {
             urlencoding::encode(self.endpoint.to_string().as_str()),
         );
 
+        let cancel = opts.cancel.clone();
         let opts = serde_json::to_vec(&opts)?;
 
         let mut headers = HeaderMap::new();
@@ -943,37 +1235,44 @@ impl DiskAPI for RemoteDisk {
 
         let mut reader = HttpReader::new(url, Method::GET, headers, Some(opts)).await?;
 
-        tokio::io::copy(&mut reader, wr).await?;
+        match cancel {
+            // Dropping the reader on cancellation aborts the HTTP stream
+            Some(cancel) => {
+                tokio::select! {
+                    res = tokio::io::copy(&mut reader, wr) => {
+                        res?;
+                    }
+                    _ = cancel.cancelled() => {}
+                }
+            }
+            None => {
+                tokio::io::copy(&mut reader, wr).await?;
+            }
+        }
 
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_file(&self, volume: &str, path: &str) -> Result<FileReader> {
+        let _timer = SpanTimer::start();
         info!("read_file {}/{}", volume, path);
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
-
-        let url = format!(
-            "{}/rustfs/rpc/read_file_stream?disk={}&volume={}&path={}&offset={}&length={}",
-            self.endpoint.grid_host(),
-            urlencoding::encode(self.endpoint.to_string().as_str()),
-            urlencoding::encode(volume),
-            urlencoding::encode(path),
-            0,
-            0
-        );
+        self.check_usable()?;
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        build_auth_headers(&url, &Method::GET, &mut headers);
-        Ok(Box::new(HttpReader::new(url, Method::GET, headers, None).await?))
+        self.open_read_stream(volume, path, 0, 0, false).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<FileReader> {
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn read_file_stream(
+        &self,
+        volume: &str,
+        path: &str,
+        offset: usize,
+        length: usize,
+        opts: &ReadOptions,
+    ) -> Result<FileReader> {
+        let _timer = SpanTimer::start();
         // warn!(
         //     "disk remote read_file_stream {}/{}/{} offset={} length={}",
         //     self.endpoint.to_string(),
@@ -983,33 +1282,17 @@ impl DiskAPI for RemoteDisk {
         //     length
         // );
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
-
-        let url = format!(
-            "{}/rustfs/rpc/read_file_stream?disk={}&volume={}&path={}&offset={}&length={}",
-            self.endpoint.grid_host(),
-            urlencoding::encode(self.endpoint.to_string().as_str()),
-            urlencoding::encode(volume),
-            urlencoding::encode(path),
-            offset,
-            length
-        );
+        self.check_usable()?;
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        build_auth_headers(&url, &Method::GET, &mut headers);
-        Ok(Box::new(HttpReader::new(url, Method::GET, headers, None).await?))
+        self.open_read_stream(volume, path, offset, length, opts.verify).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn append_file(&self, volume: &str, path: &str) -> Result<FileWriter> {
+        let _timer = SpanTimer::start();
         info!("append_file {}/{}", volume, path);
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
+        self.check_usable()?;
 
         let url = format!(
             "{}/rustfs/rpc/put_file_stream?disk={}&volume={}&path={}&append={}&size={}",
@@ -1021,14 +1304,12 @@ impl DiskAPI for RemoteDisk {
             0
         );
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        build_auth_headers(&url, &Method::PUT, &mut headers);
-        Ok(Box::new(HttpWriter::new(url, Method::PUT, headers).await?))
+        self.open_write_stream(url).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn create_file(&self, _origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<FileWriter> {
+        let _timer = SpanTimer::start();
         // warn!(
         //     "disk remote create_file {}/{}/{} file_size={}",
         //     self.endpoint.to_string(),
@@ -1037,9 +1318,7 @@ impl DiskAPI for RemoteDisk {
         //     file_size
         // );
 
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
+        self.check_usable()?;
 
         let url = format!(
             "{}/rustfs/rpc/put_file_stream?disk={}&volume={}&path={}&append={}&size={}",
@@ -1051,22 +1330,17 @@ impl DiskAPI for RemoteDisk {
             file_size
         );
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        build_auth_headers(&url, &Method::PUT, &mut headers);
-        Ok(Box::new(HttpWriter::new(url, Method::PUT, headers).await?))
+        self.open_write_stream(url).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("rename_file");
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(RenameFileRequest {
                     disk: self.endpoint.to_string(),
                     src_volume: src_volume.to_string(),
@@ -1083,21 +1357,19 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("rename_part {}/{}", src_volume, src_path);
 
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(RenamePartRequest {
                     disk: self.endpoint.to_string(),
                     src_volume: src_volume.to_string(),
@@ -1115,22 +1387,20 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()> {
+        let _timer = SpanTimer::start();
         info!("delete {}/{}/{}", self.endpoint.to_string(), volume, path);
 
         self.execute_with_timeout(
             || async {
                 let options = serde_json::to_string(&opt)?;
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(DeleteRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -1146,22 +1416,20 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        let _timer = SpanTimer::start();
         info!("verify_file");
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
                 let file_info = serde_json::to_string(&fi)?;
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(VerifyFileRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -1175,23 +1443,18 @@ impl DiskAPI for RemoteDisk {
                     return Err(response.error.unwrap_or_default().into());
                 }
 
-                let check_parts_resp = serde_json::from_str::<CheckPartsResp>(&response.check_parts_resp)?;
-
-                Ok(check_parts_resp)
+                parse_check_parts_resp(&response.check_parts_resp, fi.parts.len())
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
     #[tracing::instrument(skip(self))]
     async fn read_parts(&self, bucket: &str, paths: &[String]) -> Result<Vec<ObjectPartInfo>> {
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(ReadPartsRequest {
                     disk: self.endpoint.to_string(),
                     bucket: bucket.to_string(),
@@ -1207,22 +1470,20 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(read_parts_resp)
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn check_parts(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
+        let _timer = SpanTimer::start();
         info!("check_parts");
 
-        self.execute_with_timeout(
+        self.execute_with_retry(
             || async {
                 let file_info = serde_json::to_string(&fi)?;
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(CheckPartsRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
@@ -1236,26 +1497,23 @@ impl DiskAPI for RemoteDisk {
                     return Err(response.error.unwrap_or_default().into());
                 }
 
-                let check_parts_resp = serde_json::from_str::<CheckPartsResp>(&response.check_parts_resp)?;
-
-                Ok(check_parts_resp)
+                parse_check_parts_resp(&response.check_parts_resp, fi.parts.len())
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>> {
+        let _timer = SpanTimer::start();
         info!("read_multiple {}/{}/{}", self.endpoint.to_string(), req.bucket, req.prefix);
 
-        self.execute_with_timeout(
+        // A pattern travels with the request and is expanded by the peer against its own drive
+        self.execute_with_retry(
             || async {
                 let read_multiple_req = serde_json::to_string(&req)?;
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
+                let mut client = self.get_client().await?;
                 let request = Request::new(ReadMultipleRequest {
                     disk: self.endpoint.to_string(),
                     read_multiple_req,
@@ -1269,35 +1527,30 @@ impl DiskAPI for RemoteDisk {
 
                 let read_multiple_resps = response
                     .read_multiple_resps
-                    .into_iter()
-                    .filter_map(|json_str| serde_json::from_str::<ReadMultipleResp>(&json_str).ok())
-                    .collect();
+                    .iter()
+                    .map(|json_str| serde_json::from_str::<ReadMultipleResp>(json_str))
+                    .collect::<serde_json::Result<Vec<_>>>()?;
 
                 Ok(read_multiple_resps)
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
     #[tracing::instrument(skip(self))]
-    async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
-        info!("write_all");
-
+    async fn truncate(&self, volume: &str, path: &str, size: u64) -> Result<()> {
         self.execute_with_timeout(
             || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
-                let request = Request::new(WriteAllRequest {
+                let mut client = self.get_client().await?;
+                let request = Request::new(TruncateRequest {
                     disk: self.endpoint.to_string(),
                     volume: volume.to_string(),
                     path: path.to_string(),
-                    data,
+                    size,
                 });
 
-                let response = client.write_all(request).await?.into_inner();
+                let response = client.truncate(request).await?.into_inner();
 
                 if !response.success {
                     return Err(response.error.unwrap_or_default().into());
@@ -1305,25 +1558,113 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(())
             },
-            get_max_timeout_duration(),
+            self.timeouts.write,
         )
         .await
     }
 
     #[tracing::instrument(skip(self))]
-    async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
-        info!("read_all {}/{}", volume, path);
+    async fn read_range(&self, volume: &str, path: &str, offset: usize, length: usize, exact: bool) -> Result<Bytes> {
+        self.check_usable()?;
 
-        self.execute_with_timeout(
-            || async {
-                let mut client = self
-                    .get_client()
-                    .await
-                    .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
-                let request = Request::new(ReadAllRequest {
-                    disk: self.endpoint.to_string(),
-                    volume: volume.to_string(),
-                    path: path.to_string(),
+        // The peer refuses streams running past the end of the file, so the length is clamped to its size
+        let size = match self.stat_paths(volume, &[path.to_string()]).await?.pop().flatten() {
+            Some(fi) => fi.size.max(0) as usize,
+            None => return Err(DiskError::FileNotFound),
+        };
+        let readable = length.min(size.saturating_sub(offset));
+
+        // A zero length asks the peer for the rest of the file
+        let data = if readable == 0 {
+            Bytes::new()
+        } else {
+            let reader = self
+                .read_file_stream(volume, path, offset, readable, &ReadOptions::default())
+                .await?;
+            let mut buf = Vec::with_capacity(readable);
+            reader.take(readable as u64).read_to_end(&mut buf).await?;
+            Bytes::from(buf)
+        };
+
+        if exact && data.len() < length {
+            return Err(DiskError::LessData);
+        }
+
+        Ok(data)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn stat_paths(&self, volume: &str, paths: &[String]) -> Result<Vec<Option<FileInfo>>> {
+        self.check_usable()?;
+
+        self.execute_with_retry(
+            || async {
+                let mut client = self.get_client().await?;
+                let request = Request::new(StatPathsRequest {
+                    disk: self.endpoint.to_string(),
+                    volume: volume.to_string(),
+                    paths: paths.to_vec(),
+                });
+
+                let response = client.stat_paths(request).await?.into_inner();
+
+                if !response.success {
+                    return Err(response.error.unwrap_or_default().into());
+                }
+
+                let file_infos = response
+                    .file_infos
+                    .iter()
+                    .map(|json_str| serde_json::from_str::<Option<FileInfo>>(json_str))
+                    .collect::<serde_json::Result<Vec<_>>>()?;
+
+                Ok(file_infos)
+            },
+            self.timeouts.metadata,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
+        let _timer = SpanTimer::start();
+        info!("write_all");
+
+        self.execute_with_timeout(
+            || async {
+                let mut client = self.get_client().await?;
+                let request = Request::new(WriteAllRequest {
+                    disk: self.endpoint.to_string(),
+                    volume: volume.to_string(),
+                    path: path.to_string(),
+                    data,
+                });
+
+                let response = client.write_all(request).await?.into_inner();
+
+                if !response.success {
+                    return Err(response.error.unwrap_or_default().into());
+                }
+
+                Ok(())
+            },
+            self.timeouts.write,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
+    async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
+        let _timer = SpanTimer::start();
+        info!("read_all {}/{}", volume, path);
+
+        self.execute_with_retry(
+            || async {
+                let mut client = self.get_client().await?;
+                let request = Request::new(ReadAllRequest {
+                    disk: self.endpoint.to_string(),
+                    volume: volume.to_string(),
+                    path: path.to_string(),
                 });
 
                 let response = client.read_all(request).await?.into_inner();
@@ -1334,42 +1675,125 @@ impl DiskAPI for RemoteDisk {
 
                 Ok(response.data)
             },
-            get_max_timeout_duration(),
+            self.timeouts.read,
         )
         .await
     }
 
-    #[tracing::instrument(skip(self))]
+    #[tracing::instrument(skip(self), fields(endpoint = %self.endpoint, pool = self.endpoint.pool_idx, elapsed_ms = tracing::field::Empty))]
     async fn disk_info(&self, opts: &DiskInfoOptions) -> Result<DiskInfo> {
-        if self.health.is_faulty() {
-            return Err(DiskError::FaultyDisk);
-        }
-
+        let _timer = SpanTimer::start();
+        // The peer honors opts.noop and opts.metrics itself, so forward them as is.
         let opts = serde_json::to_string(&opts)?;
-        let mut client = self
-            .get_client()
-            .await
-            .map_err(|err| Error::other(format!("can not get client, err: {err}")))?;
-        let request = Request::new(DiskInfoRequest {
-            disk: self.endpoint.to_string(),
-            opts,
-        });
 
-        let response = client.disk_info(request).await?.into_inner();
+        let mut disk_info = self
+            .execute_with_retry(
+                || async {
+                    let mut client = self.get_client().await?;
+                    let request = Request::new(DiskInfoRequest {
+                        disk: self.endpoint.to_string(),
+                        opts: opts.clone(),
+                    });
+
+                    let response = client.disk_info(request).await?.into_inner();
 
-        if !response.success {
-            return Err(response.error.unwrap_or_default().into());
-        }
+                    if !response.success {
+                        return Err(response.error.unwrap_or_default().into());
+                    }
+
+                    Ok(serde_json::from_str::<DiskInfo>(&response.disk_info)?)
+                },
+                self.timeouts.metadata,
+            )
+            .await?;
 
-        let disk_info = serde_json::from_str::<DiskInfo>(&response.disk_info)?;
+        // Requests still waiting on this peer are only known locally.
+        disk_info.metrics.total_waiting = self.health.waiting_count();
+        disk_info.scanning |= self.scanning.load(Ordering::Acquire);
+        disk_info.healing |= self.healing.load(Ordering::Acquire);
 
         Ok(disk_info)
     }
 }
 
+/// Decode a `ReadVersionResponse`, restoring the remote error such as `FileVersionNotFound` on failure.
+fn decode_read_version_response(response: ReadVersionResponse) -> Result<FileInfo> {
+    if !response.success {
+        return Err(response.error.unwrap_or_default().into());
+    }
+
+    Ok(serde_json::from_str::<FileInfo>(&response.file_info)?)
+}
+
+/// Decode a `MakeVolumesResponse` for `count` volumes into one result per volume. Peers that do
+/// not report per volume results fail the whole batch with their error.
+fn decode_make_volumes_response(count: usize, response: MakeVolumesResponse) -> Result<Vec<Result<()>>> {
+    if response.success {
+        return Ok(vec![Ok(()); count]);
+    }
+
+    if response.errors.len() != count {
+        return Err(response.error.unwrap_or_default().into());
+    }
+
+    Ok(response
+        .errors
+        .into_iter()
+        .map(|err| {
+            if err.code == 0 && err.error_info.is_empty() {
+                Ok(())
+            } else {
+                Err(err.into())
+            }
+        })
+        .collect())
+}
+
+/// Decode a `RenameDataResponse`, restoring cross device and quorum errors reported by the peer.
+fn decode_rename_data_response(response: RenameDataResponse) -> Result<RenameDataResp> {
+    if !response.success {
+        return Err(response.error.unwrap_or_default().into());
+    }
+
+    Ok(serde_json::from_str::<RenameDataResp>(&response.rename_data_resp)?)
+}
+
+/// Decode a `ReadXlResponse`. An empty `xl.meta` is reported as `FileNotFound`, as the local disk does.
+fn decode_read_xl_response(response: ReadXlResponse) -> Result<RawFileInfo> {
+    if !response.success {
+        return Err(response.error.unwrap_or_default().into());
+    }
+
+    let raw_file_info = serde_json::from_str::<RawFileInfo>(&response.raw_file_info)?;
+    if raw_file_info.buf.is_empty() {
+        return Err(DiskError::FileNotFound);
+    }
+
+    Ok(raw_file_info)
+}
+
+/// Parse the `CheckPartsResp` of a `check_parts` or `verify_file` call, which must hold one status per part.
+fn parse_check_parts_resp(check_parts_resp: &str, parts: usize) -> Result<CheckPartsResp> {
+    let resp = serde_json::from_str::<CheckPartsResp>(check_parts_resp)?;
+    if resp.results.len() != parts {
+        return Err(Error::other(format!(
+            "check parts returned {} results for {} parts",
+            resp.results.len(),
+            parts
+        )));
+    }
+
+    Ok(resp)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::disk::{CHECK_PART_FILE_CORRUPT, CHECK_PART_FILE_NOT_FOUND, CHECK_PART_SUCCESS, has_part_err};
+    use crate::disk::{STORAGE_FORMAT_FILE, local::LocalDisk};
+    use rustfs_filemeta::MetacacheReader;
+    use rustfs_protos::proto_gen::node_service::{StatPathsResponse, TruncateResponse};
+    use std::io::Cursor;
     use std::sync::Once;
     use tokio::net::TcpListener;
     use tracing::Level;
@@ -1402,6 +1826,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1414,6 +1839,26 @@ mod tests {
         assert_eq!(remote_disk.host_name(), "example.com:9000");
     }
 
+    #[tokio::test]
+    async fn test_remote_disk_creation_without_host() {
+        let endpoint = Endpoint {
+            url: url::Url::parse("unix:/run/rustfs/data").unwrap(),
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+
+        let err = RemoteDisk::new(&endpoint, &disk_option).await.unwrap_err();
+        assert!(err.to_string().contains("has no host"), "{err}");
+    }
+
     #[tokio::test]
     async fn test_remote_disk_basic_properties() {
         let url = url::Url::parse("http://remote-server:9000").unwrap();
@@ -1428,6 +1873,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1460,6 +1906,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1486,6 +1933,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1517,6 +1965,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: true,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1527,6 +1976,425 @@ mod tests {
         assert!(!remote_disk.is_online().await);
     }
 
+    #[tokio::test]
+    async fn test_remote_disk_is_online_follows_health_monitor() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let url = url::Url::parse(&format!("http://{}:{}/data/rustfs0", addr.ip(), addr.port())).unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+
+        let mut remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+        remote_disk.online_cache_ttl = Duration::from_millis(20);
+        remote_disk.health_check = true;
+        remote_disk.start_health_monitoring();
+
+        // is_online only reads the state the monitor keeps, it never waits on a probe
+        for _ in 0..100 {
+            let online = tokio::time::timeout(Duration::from_millis(10), remote_disk.is_online()).await;
+            assert!(online.expect("is_online must not probe the peer"));
+        }
+
+        // The monitor notices the listener going away within a few refreshes
+        drop(listener);
+        let mut online = true;
+        for _ in 0..100 {
+            online = remote_disk.is_online().await;
+            if !online {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(!online);
+
+        // A faulty disk is reported offline whatever the last probe said
+        remote_disk.online.store(true, Ordering::Release);
+        remote_disk.health.swap_ok_to_faulty();
+        assert!(!remote_disk.is_online().await);
+
+        remote_disk.close().await.unwrap();
+    }
+
+    #[test]
+    fn test_decode_read_version_response() {
+        let version_id = Uuid::new_v4();
+        let fi = FileInfo {
+            volume: "bucket".to_string(),
+            name: "object".to_string(),
+            version_id: Some(version_id),
+            is_latest: true,
+            size: 4096,
+            num_versions: 2,
+            data_dir: Some(Uuid::new_v4()),
+            mod_time: Some(::time::OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()),
+            ..Default::default()
+        };
+
+        // Encoded the same way the node service does
+        let response = ReadVersionResponse {
+            success: true,
+            file_info: serde_json::to_string(&fi).unwrap(),
+            error: None,
+        };
+        let decoded = decode_read_version_response(response).unwrap();
+        assert_eq!(decoded.volume, fi.volume);
+        assert_eq!(decoded.name, fi.name);
+        assert_eq!(decoded.version_id, Some(version_id));
+        assert!(decoded.is_latest);
+        assert_eq!(decoded.size, fi.size);
+        assert_eq!(decoded.num_versions, fi.num_versions);
+        assert_eq!(decoded.data_dir, fi.data_dir);
+        assert_eq!(decoded.mod_time, fi.mod_time);
+
+        let response = ReadVersionResponse {
+            success: false,
+            file_info: String::new(),
+            error: Some(DiskError::FileVersionNotFound.into()),
+        };
+        assert_eq!(decode_read_version_response(response).unwrap_err(), DiskError::FileVersionNotFound);
+    }
+
+    #[test]
+    fn test_decode_make_volumes_response() {
+        let response = MakeVolumesResponse {
+            success: true,
+            error: None,
+            errors: Vec::new(),
+        };
+        assert_eq!(decode_make_volumes_response(3, response).unwrap(), vec![Ok(()), Ok(()), Ok(())]);
+
+        // Only the second volume failed, the per volume result says so
+        let response = MakeVolumesResponse {
+            success: false,
+            error: Some(DiskError::DiskFull.into()),
+            errors: vec![Default::default(), DiskError::DiskFull.into(), Default::default()],
+        };
+        assert_eq!(
+            decode_make_volumes_response(3, response).unwrap(),
+            vec![Ok(()), Err(DiskError::DiskFull), Ok(())]
+        );
+
+        // Peers without per volume results still report the batch error
+        let response = MakeVolumesResponse {
+            success: false,
+            error: Some(DiskError::VolumeAccessDenied.into()),
+            errors: Vec::new(),
+        };
+        assert_eq!(decode_make_volumes_response(3, response).unwrap_err(), DiskError::VolumeAccessDenied);
+    }
+
+    /// Answers every call as MakeVolumes, failing the volume named "full".
+    #[derive(Clone, Default)]
+    struct MockNode {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl tonic::server::NamedService for MockNode {
+        const NAME: &'static str = "node_service.NodeService";
+    }
+
+    impl tonic::server::UnaryService<MakeVolumesRequest> for MockNode {
+        type Response = MakeVolumesResponse;
+        type Future = tonic::codegen::BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<MakeVolumesRequest>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let errors = request
+                .into_inner()
+                .volumes
+                .iter()
+                .map(|volume| {
+                    if volume == "full" {
+                        DiskError::DiskFull.into()
+                    } else {
+                        Default::default()
+                    }
+                })
+                .collect();
+            Box::pin(async move {
+                Ok(tonic::Response::new(MakeVolumesResponse {
+                    success: false,
+                    error: Some(DiskError::DiskFull.into()),
+                    errors,
+                }))
+            })
+        }
+    }
+
+    impl tonic::server::UnaryService<StatPathsRequest> for MockNode {
+        type Response = StatPathsResponse;
+        type Future = tonic::codegen::BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<StatPathsRequest>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let paths = request.into_inner().paths;
+            if paths.iter().any(|path| path == "io-error") {
+                return Box::pin(async move {
+                    Ok(tonic::Response::new(StatPathsResponse {
+                        success: false,
+                        file_infos: Vec::new(),
+                        error: Some(DiskError::other("remote failure").into()),
+                    }))
+                });
+            }
+            let file_infos = paths
+                .into_iter()
+                .map(|path| {
+                    let fi = (path != "missing").then(|| FileInfo {
+                        name: path,
+                        size: 1,
+                        ..Default::default()
+                    });
+                    serde_json::to_string(&fi).unwrap()
+                })
+                .collect();
+            Box::pin(async move {
+                Ok(tonic::Response::new(StatPathsResponse {
+                    success: true,
+                    file_infos,
+                    error: None,
+                }))
+            })
+        }
+    }
+
+    impl tonic::server::UnaryService<TruncateRequest> for MockNode {
+        type Response = TruncateResponse;
+        type Future = tonic::codegen::BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+        fn call(&mut self, request: tonic::Request<TruncateRequest>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let error = (request.into_inner().path == "missing").then(|| DiskError::FileNotFound.into());
+            Box::pin(async move {
+                Ok(tonic::Response::new(TruncateResponse {
+                    success: error.is_none(),
+                    error,
+                }))
+            })
+        }
+    }
+
+    impl tonic::codegen::Service<http::Request<tonic::body::Body>> for MockNode {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = tonic::codegen::BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _cx: &mut std::task::Context<'_>) -> std::task::Poll<std::result::Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<tonic::body::Body>) -> Self::Future {
+            let svc = self.clone();
+            Box::pin(async move {
+                match req.uri().path() {
+                    "/node_service.NodeService/Truncate" => {
+                        let codec = tonic_prost::ProstCodec::<TruncateResponse, TruncateRequest>::default();
+                        Ok(tonic::server::Grpc::new(codec).unary(svc, req).await)
+                    }
+                    "/node_service.NodeService/StatPaths" => {
+                        let codec = tonic_prost::ProstCodec::<StatPathsResponse, StatPathsRequest>::default();
+                        Ok(tonic::server::Grpc::new(codec).unary(svc, req).await)
+                    }
+                    _ => {
+                        let codec = tonic_prost::ProstCodec::<MakeVolumesResponse, MakeVolumesRequest>::default();
+                        Ok(tonic::server::Grpc::new(codec).unary(svc, req).await)
+                    }
+                }
+            })
+        }
+    }
+
+    /// Starts a `MockNode` and returns a remote disk talking to it along with its call counter.
+    async fn mock_remote_disk() -> (RemoteDisk, Arc<std::sync::atomic::AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let mock = MockNode::default();
+        let calls = mock.calls.clone();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(mock)
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+
+        let url = url::Url::parse(&format!("http://{}:{}/data/rustfs0", addr.ip(), addr.port())).unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+        let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+
+        (remote_disk, calls)
+    }
+
+    #[tokio::test]
+    async fn test_make_volumes_single_rpc() {
+        let (remote_disk, calls) = mock_remote_disk().await;
+
+        // The whole batch goes out in one request and each volume gets its own result back
+        let results = remote_disk.make_volumes_each(vec!["a", "full", "b"]).await.unwrap();
+        assert_eq!(results, vec![Ok(()), Err(DiskError::DiskFull), Ok(())]);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(remote_disk.make_volumes(vec!["a", "full"]).await.unwrap_err(), DiskError::DiskFull);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stat_paths_rpc() {
+        let (remote_disk, calls) = mock_remote_disk().await;
+
+        let paths = vec!["a".to_string(), "missing".to_string(), "b".to_string()];
+        let file_infos = remote_disk.stat_paths("bucket", &paths).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(file_infos.len(), 3);
+        assert_eq!(file_infos[0].as_ref().map(|fi| (fi.name.as_str(), fi.size)), Some(("a", 1)));
+        assert!(file_infos[1].is_none());
+        assert_eq!(file_infos[2].as_ref().map(|fi| fi.name.as_str()), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_reported_io_error_not_retried() {
+        let (remote_disk, calls) = mock_remote_disk().await;
+
+        // The peer answered, so its io error is final even though stat_paths is retried on transport errors
+        let err = remote_disk.stat_paths("bucket", &["io-error".to_string()]).await.unwrap_err();
+        assert!(matches!(err, DiskError::Io(_)), "{err:?}");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_range_propagates_errors() {
+        let (remote_disk, calls) = mock_remote_disk().await;
+
+        // Errors come back as they are, nothing is read whole instead
+        let err = remote_disk.read_range("bucket", "io-error", 0, 8, false).await.unwrap_err();
+        assert!(matches!(err, DiskError::Io(_)), "{err:?}");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let err = remote_disk.read_range("bucket", "missing", 0, 8, false).await.unwrap_err();
+        assert_eq!(err, DiskError::FileNotFound);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Past the end of the one byte file there is nothing to stream
+        assert!(remote_disk.read_range("bucket", "a", 4, 8, false).await.unwrap().is_empty());
+        assert_eq!(remote_disk.read_range("bucket", "a", 4, 8, true).await.unwrap_err(), DiskError::LessData);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_truncate_rpc() {
+        let (remote_disk, calls) = mock_remote_disk().await;
+
+        remote_disk.truncate("bucket", "object/part.1", 4).await.unwrap();
+        assert_eq!(remote_disk.truncate("bucket", "missing", 4).await.unwrap_err(), DiskError::FileNotFound);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_decode_rename_data_response() {
+        let old_data_dir = Uuid::new_v4();
+        let resp = RenameDataResp {
+            old_data_dir: Some(old_data_dir),
+            sign: Some(vec![1, 2, 3]),
+        };
+
+        let response = RenameDataResponse {
+            success: true,
+            rename_data_resp: serde_json::to_string(&resp).unwrap(),
+            error: None,
+        };
+        let decoded = decode_rename_data_response(response).unwrap();
+        assert_eq!(decoded.old_data_dir, Some(old_data_dir));
+        assert_eq!(decoded.sign, Some(vec![1, 2, 3]));
+
+        let response = RenameDataResponse {
+            success: false,
+            rename_data_resp: String::new(),
+            error: Some(DiskError::other("Invalid cross-device link (os error 18)").into()),
+        };
+        assert_eq!(decode_rename_data_response(response).unwrap_err(), DiskError::CrossDeviceLink);
+
+        let response = RenameDataResponse {
+            success: false,
+            rename_data_resp: String::new(),
+            error: Some(DiskError::ErasureWriteQuorum.into()),
+        };
+        assert_eq!(decode_rename_data_response(response).unwrap_err(), DiskError::ErasureWriteQuorum);
+    }
+
+    #[test]
+    fn test_decode_read_xl_response() {
+        let mut meta = rustfs_filemeta::FileMeta::new();
+        meta.add_version(FileInfo {
+            volume: "bucket".to_string(),
+            name: "object".to_string(),
+            version_id: Some(Uuid::new_v4()),
+            mod_time: Some(::time::OffsetDateTime::now_utc()),
+            ..Default::default()
+        })
+        .unwrap();
+        let buf = meta.marshal_msg().unwrap();
+
+        let response = ReadXlResponse {
+            success: true,
+            raw_file_info: serde_json::to_string(&RawFileInfo { buf: buf.clone() }).unwrap(),
+            error: None,
+        };
+        let decoded = decode_read_xl_response(response).unwrap();
+        assert_eq!(decoded.buf, buf);
+        assert_eq!(rustfs_filemeta::FileMeta::load(&decoded.buf).unwrap().versions.len(), 1);
+
+        let response = ReadXlResponse {
+            success: false,
+            raw_file_info: String::new(),
+            error: Some(DiskError::FileNotFound.into()),
+        };
+        assert_eq!(decode_read_xl_response(response).unwrap_err(), DiskError::FileNotFound);
+
+        let response = ReadXlResponse {
+            success: true,
+            raw_file_info: serde_json::to_string(&RawFileInfo::default()).unwrap(),
+            error: None,
+        };
+        assert_eq!(decode_read_xl_response(response).unwrap_err(), DiskError::FileNotFound);
+    }
+
+    #[test]
+    fn test_parse_check_parts_resp() {
+        let resp = CheckPartsResp {
+            results: vec![CHECK_PART_SUCCESS, CHECK_PART_FILE_CORRUPT, CHECK_PART_FILE_NOT_FOUND],
+        };
+        let encoded = serde_json::to_string(&resp).unwrap();
+
+        let parsed = parse_check_parts_resp(&encoded, 3).unwrap();
+        assert_eq!(parsed.results[1], CHECK_PART_FILE_CORRUPT);
+        assert!(has_part_err(&parsed.results));
+
+        // A peer must report exactly one status per part
+        assert!(parse_check_parts_resp(&encoded, 2).is_err());
+    }
+
     #[tokio::test]
     async fn test_remote_disk_disk_id() {
         let url = url::Url::parse("http://remote-server:9000").unwrap();
@@ -1541,6 +2409,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1585,6 +2454,7 @@ mod tests {
             let disk_option = DiskOption {
                 cleanup: false,
                 health_check: false,
+                ..Default::default()
             };
 
             let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1611,6 +2481,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&valid_endpoint, &disk_option).await.unwrap();
@@ -1651,6 +2522,7 @@ mod tests {
         let disk_option = DiskOption {
             cleanup: false,
             health_check: false,
+            ..Default::default()
         };
 
         let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
@@ -1660,6 +2532,438 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_remote_disk_calls_after_close_fail() {
+        let url = url::Url::parse("http://closed-test-host:9000/data").unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+
+        let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+
+        let channel = tonic::transport::Endpoint::from_shared(remote_disk.addr.clone())
+            .unwrap()
+            .connect_lazy();
+        rustfs_common::GLOBAL_CONN_MAP
+            .write()
+            .await
+            .insert(remote_disk.addr.clone(), channel);
+
+        // Other disks of the node share the channel, it stays pooled
+        remote_disk.close().await.unwrap();
+        assert!(rustfs_common::has_cached_connection(&remote_disk.addr).await);
+
+        // Closing twice is fine
+        remote_disk.close().await.unwrap();
+
+        assert_eq!(remote_disk.make_volume("bucket").await.unwrap_err(), DiskError::DiskClosed);
+        assert_eq!(remote_disk.list_volumes().await.unwrap_err(), DiskError::DiskClosed);
+        assert!(matches!(remote_disk.read_file("bucket", "object").await, Err(DiskError::DiskClosed)));
+        assert_eq!(remote_disk.stat_paths("bucket", &[]).await.unwrap_err(), DiskError::DiskClosed);
+        assert!(!remote_disk.is_online().await);
+
+        rustfs_common::GLOBAL_CONN_MAP.write().await.remove(&remote_disk.addr);
+    }
+
+    /// Minimal peer serving `/rustfs/rpc/walk_dir` from a local disk, the way the admin rpc handler does.
+    async fn serve_walk_dir(listener: TcpListener, disk: Arc<LocalDisk>) {
+        use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+        while let Ok((stream, _)) = listener.accept().await {
+            let disk = disk.clone();
+            tokio::spawn(async move {
+                let mut conn = BufReader::new(stream);
+                loop {
+                    let mut request_line = String::new();
+                    if conn.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+                        return;
+                    }
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        conn.read_line(&mut line).await.unwrap();
+                        if line == "\r\n" {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    let mut body = vec![0u8; content_length];
+                    conn.read_exact(&mut body).await.unwrap();
+
+                    let mut out = Vec::new();
+                    if request_line.starts_with("GET ") {
+                        let opts: WalkDirOptions = serde_json::from_slice(&body).unwrap();
+                        disk.walk_dir(opts, &mut out).await.unwrap();
+                    }
+
+                    let head = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n", out.len());
+                    conn.get_mut().write_all(head.as_bytes()).await.unwrap();
+                    conn.get_mut().write_all(&out).await.unwrap();
+                }
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_walk_dir_matches_local() {
+        let test_dir = tempfile::TempDir::new().unwrap();
+
+        let local_endpoint = Endpoint::try_from(test_dir.path().to_str().unwrap()).unwrap();
+        let local = Arc::new(LocalDisk::new(&local_endpoint, false).await.unwrap());
+        local.make_volume("bucket").await.unwrap();
+        for object in ["a", "dir/b", "dir/sub/c"] {
+            let mut fi = FileInfo::new(object, 2, 2);
+            fi.version_id = Some(Uuid::new_v4());
+            fi.mod_time = Some(::time::OffsetDateTime::now_utc());
+            local
+                .write_all("bucket", &format!("{object}/{STORAGE_FORMAT_FILE}"), fi.marshal_xl().unwrap())
+                .await
+                .unwrap();
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_walk_dir(listener, local.clone()));
+
+        let url = url::Url::parse(&format!("http://{}:{}/data", addr.ip(), addr.port())).unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+        let remote = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+
+        let opts = || WalkDirOptions {
+            bucket: "bucket".to_string(),
+            recursive: true,
+            ..Default::default()
+        };
+
+        let mut local_out = Vec::new();
+        local.walk_dir(opts(), &mut local_out).await.unwrap();
+        let mut remote_out = Vec::new();
+        remote.walk_dir(opts(), &mut remote_out).await.unwrap();
+
+        assert_eq!(local_out, remote_out);
+
+        let entries = MetacacheReader::new(Cursor::new(remote_out)).read_all().await.unwrap();
+        let names: Vec<_> = entries.iter().filter(|e| e.is_object()).map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "dir/b", "dir/sub/c"]);
+    }
+
+    /// Records the size of every buffer the wrapped stream is handed.
+    #[derive(Default)]
+    struct SizeRecorder {
+        sizes: Arc<std::sync::Mutex<Vec<usize>>>,
+        remaining: usize,
+    }
+
+    impl AsyncRead for SizeRecorder {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            self.sizes.lock().unwrap().push(buf.remaining());
+            let n = buf.remaining().min(self.remaining);
+            buf.put_slice(&vec![7u8; n]);
+            self.remaining -= n;
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    impl AsyncWrite for SizeRecorder {
+        fn poll_write(
+            self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &[u8],
+        ) -> std::task::Poll<std::io::Result<usize>> {
+            self.sizes.lock().unwrap().push(buf.len());
+            std::task::Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: std::pin::Pin<&mut Self>, _cx: &mut std::task::Context<'_>) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    async fn new_chunked_remote_disk(chunk_size: usize) -> Result<RemoteDisk> {
+        let endpoint = Endpoint {
+            url: url::Url::parse("http://chunk-test-host:9000/data").unwrap(),
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            chunk_size,
+            ..Default::default()
+        };
+
+        RemoteDisk::new(&endpoint, &disk_option).await
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_chunk_size_validation() {
+        for chunk_size in [0, MIN_HTTP_CHUNK_SIZE, 1 << 20, MAX_HTTP_CHUNK_SIZE] {
+            assert!(new_chunked_remote_disk(chunk_size).await.is_ok(), "{chunk_size} should be accepted");
+        }
+        for chunk_size in [1024, 100_000, MAX_HTTP_CHUNK_SIZE * 2] {
+            assert!(new_chunked_remote_disk(chunk_size).await.is_err(), "{chunk_size} should be rejected");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_chunk_size_passed_to_streams() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let chunk_size = 64 << 10;
+        let remote = new_chunked_remote_disk(chunk_size).await.unwrap();
+
+        // Small writes reach the HTTP stream in chunk_size pieces
+        let sizes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut writer = remote.chunked_writer(SizeRecorder {
+            sizes: sizes.clone(),
+            ..Default::default()
+        });
+        for _ in 0..64 {
+            writer.write_all(&[1u8; 4096]).await.unwrap();
+        }
+        writer.shutdown().await.unwrap();
+        assert_eq!(*sizes.lock().unwrap(), vec![chunk_size; 4]);
+
+        // Small reads pull chunk_size pieces from the HTTP stream
+        let sizes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut reader = chunked_reader(
+            remote.chunk_size,
+            SizeRecorder {
+                sizes: sizes.clone(),
+                remaining: 4 * chunk_size,
+            },
+        );
+        let mut buf = [0u8; 1024];
+        let mut total = 0;
+        loop {
+            let n = reader.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            total += n;
+        }
+        assert_eq!(total, 4 * chunk_size);
+        assert!(sizes.lock().unwrap().iter().all(|&size| size == chunk_size));
+
+        // Without a chunk size the stream is handed through untouched
+        let remote = new_chunked_remote_disk(0).await.unwrap();
+        let sizes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut writer = remote.chunked_writer(SizeRecorder {
+            sizes: sizes.clone(),
+            ..Default::default()
+        });
+        writer.write_all(&[1u8; 4096]).await.unwrap();
+        writer.write_all(&[1u8; 4096]).await.unwrap();
+        assert_eq!(*sizes.lock().unwrap(), vec![4096, 4096]);
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_timeout_evicts_cached_channel() {
+        let url = url::Url::parse("http://evict-test-host:9000/data").unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let disk_option = DiskOption {
+            cleanup: false,
+            health_check: false,
+            ..Default::default()
+        };
+
+        let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+
+        let channel = tonic::transport::Endpoint::from_shared(remote_disk.addr.clone())
+            .unwrap()
+            .connect_lazy();
+        rustfs_common::GLOBAL_CONN_MAP
+            .write()
+            .await
+            .insert(remote_disk.addr.clone(), channel);
+        assert!(rustfs_common::has_cached_connection(&remote_disk.addr).await);
+
+        let result: Result<()> = remote_disk
+            .execute_with_timeout(
+                || async {
+                    time::sleep(Duration::from_secs(5)).await;
+                    Ok(())
+                },
+                Duration::from_millis(10),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(!rustfs_common::has_cached_connection(&remote_disk.addr).await);
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_rpc_timeouts() {
+        let url = url::Url::parse("http://timeout-test-host:9000/data").unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let timeouts = RpcTimeouts {
+            read: Duration::from_millis(50),
+            write: Duration::from_secs(1),
+            metadata: Duration::from_millis(100),
+        };
+        let disk_option = DiskOption {
+            timeouts: timeouts.clone(),
+            ..Default::default()
+        };
+        let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+        assert_eq!(remote_disk.timeouts, timeouts);
+
+        // A slow call is aborted at the configured deadline
+        let start = std::time::Instant::now();
+        let result: Result<()> = remote_disk
+            .execute_with_timeout(
+                || async {
+                    time::sleep(Duration::from_secs(5)).await;
+                    Ok(())
+                },
+                remote_disk.timeouts.read,
+            )
+            .await;
+        let elapsed = start.elapsed();
+
+        match result {
+            Err(DiskError::Io(err)) => assert_eq!(err.kind(), std::io::ErrorKind::TimedOut),
+            other => panic!("expected a timed out io error, got {other:?}"),
+        }
+        assert!(elapsed >= Duration::from_millis(50));
+        assert!(elapsed < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_remote_disk_retry_transient_errors() {
+        let url = url::Url::parse("http://retry-test-host:9000/data").unwrap();
+        let endpoint = Endpoint {
+            url,
+            is_local: false,
+            pool_idx: 0,
+            set_idx: 0,
+            disk_idx: 0,
+        };
+
+        let disk_option = DiskOption {
+            retry: RetryPolicy::no_delay(3),
+            ..Default::default()
+        };
+        let remote_disk = RemoteDisk::new(&endpoint, &disk_option).await.unwrap();
+
+        // Fails twice with a transport error, then succeeds
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let result = remote_disk
+            .execute_with_retry(
+                || async {
+                    if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                        return Err(tonic::Status::unavailable("transport error").into());
+                    }
+                    Ok(42)
+                },
+                Duration::from_secs(1),
+            )
+            .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // Errors reported by the remote disk are not retried
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let result: Result<()> = remote_disk
+            .execute_with_retry(
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err(DiskError::FileNotFound)
+                },
+                Duration::from_secs(1),
+            )
+            .await;
+        assert_eq!(result.unwrap_err(), DiskError::FileNotFound);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Neither are io errors the remote disk reported nor statuses other than unavailable
+        for err in [
+            DiskError::from_remote(DiskError::other("").to_u32(), "remote failure"),
+            tonic::Status::invalid_argument("bad request").into(),
+            tonic::Status::unauthenticated("no signature").into(),
+        ] {
+            let calls = std::sync::atomic::AtomicUsize::new(0);
+            let result: Result<()> = remote_disk
+                .execute_with_retry(
+                    || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        Err(err.clone())
+                    },
+                    Duration::from_secs(1),
+                )
+                .await;
+            assert!(result.is_err());
+            assert_eq!(calls.load(Ordering::SeqCst), 1, "{err} was retried");
+        }
+
+        // Deadlines are retried like timeouts, until max_retries
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let result: Result<()> = remote_disk
+            .execute_with_retry(
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err(tonic::Status::deadline_exceeded("slow peer").into())
+                },
+                Duration::from_secs(1),
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
     #[test]
     fn test_remote_disk_sync_properties() {
         let url = url::Url::parse("https://secure-remote:9000/data").unwrap();
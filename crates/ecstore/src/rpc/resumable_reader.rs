@@ -0,0 +1,278 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::disk::{FileReader, RetryPolicy, error::Result};
+use std::future::Future;
+use std::io::{self, ErrorKind};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use std::time::Instant;
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// Reader over a file stream from a peer that survives dropped connections.
+///
+/// It counts the bytes handed out, and when the stream fails with a network error, or ends
+/// before `length` bytes arrived, it calls `reopen` with that count to request the rest of the
+/// file and carries on from there. Reconnects back off as configured by the `RetryPolicy`; the
+/// error is returned once `max_retries` reconnects in a row brought no data.
+pub struct ResumableReader<F> {
+    reader: Option<FileReader>,
+    reopening: Option<JoinHandle<Result<FileReader>>>,
+    reopen: F,
+    retry: RetryPolicy,
+    length: Option<u64>,
+    consumed: u64,
+    attempt: usize,
+    failing_since: Option<Instant>,
+}
+
+impl<F, Fut> ResumableReader<F>
+where
+    F: Fn(u64) -> Fut,
+    Fut: Future<Output = Result<FileReader>> + Send + 'static,
+{
+    /// Wraps the already opened `reader`. `length` is the number of bytes the stream should
+    /// deliver, `None` when it runs to the end of the file.
+    pub fn new(reader: FileReader, reopen: F, retry: RetryPolicy, length: Option<u64>) -> Self {
+        Self {
+            reader: Some(reader),
+            reopening: None,
+            reopen,
+            retry,
+            length,
+            consumed: 0,
+            attempt: 0,
+            failing_since: None,
+        }
+    }
+
+    /// Starts reopening the stream after a backoff, or returns `err` when the retry budget is spent.
+    fn schedule_reopen(&mut self, err: io::Error) -> io::Result<()> {
+        self.reader = None;
+
+        let failing_since = *self.failing_since.get_or_insert_with(Instant::now);
+        let backoff = self.retry.backoff(self.attempt);
+        if self.attempt >= self.retry.max_retries || failing_since.elapsed() + backoff > self.retry.max_elapsed {
+            return Err(err);
+        }
+
+        self.attempt += 1;
+        debug!(
+            "remote stream failed after {} bytes: {}, resuming {}/{} in {:?}",
+            self.consumed, err, self.attempt, self.retry.max_retries, backoff
+        );
+
+        let reopen = (self.reopen)(self.consumed);
+        self.reopening = Some(tokio::spawn(async move {
+            tokio::time::sleep(backoff).await;
+            reopen.await
+        }));
+
+        Ok(())
+    }
+
+    fn is_complete(&self) -> bool {
+        self.length.is_some_and(|length| self.consumed >= length)
+    }
+}
+
+/// Whether a read error looks like a broken connection rather than bad data.
+fn is_recoverable(err: &io::Error) -> bool {
+    !matches!(
+        err.kind(),
+        ErrorKind::InvalidData | ErrorKind::InvalidInput | ErrorKind::NotFound | ErrorKind::PermissionDenied
+    )
+}
+
+impl<F, Fut> AsyncRead for ResumableReader<F>
+where
+    F: Fn(u64) -> Fut + Unpin,
+    Fut: Future<Output = Result<FileReader>> + Send + 'static,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(reopening) = this.reopening.as_mut() {
+                let res = ready!(Pin::new(reopening).poll(cx));
+                this.reopening = None;
+                match res.map_err(io::Error::other).and_then(|res| res.map_err(io::Error::from)) {
+                    Ok(reader) => this.reader = Some(reader),
+                    Err(err) => {
+                        this.schedule_reopen(err)?;
+                        continue;
+                    }
+                }
+            }
+
+            let Some(reader) = this.reader.as_mut() else {
+                return Poll::Ready(Ok(()));
+            };
+
+            let before = buf.filled().len();
+            match ready!(Pin::new(reader).poll_read(cx, buf)) {
+                Ok(()) => {
+                    let n = buf.filled().len() - before;
+                    if n > 0 {
+                        this.consumed += n as u64;
+                        this.attempt = 0;
+                        this.failing_since = None;
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    if buf.remaining() == 0 || this.length.is_none() || this.is_complete() {
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    this.schedule_reopen(io::Error::new(ErrorKind::UnexpectedEof, "remote stream ended early"))?;
+                }
+                // Only a clean end counts as complete, errors after the last byte (like a failed
+                // checksum over the whole file) are passed on, and there is nothing left to reopen
+                Err(err) if is_recoverable(&err) && !this.is_complete() => this.schedule_reopen(err)?,
+                Err(err) => return Poll::Ready(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disk::error::DiskError;
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::AsyncReadExt;
+
+    /// Serves `data` and then fails with `err` instead of ending.
+    struct Dropping {
+        data: Cursor<Vec<u8>>,
+        err: Option<ErrorKind>,
+    }
+
+    impl AsyncRead for Dropping {
+        fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+            let this = self.get_mut();
+            if this.data.position() < this.data.get_ref().len() as u64 {
+                return Pin::new(&mut this.data).poll_read(cx, buf);
+            }
+            match this.err {
+                Some(kind) => Poll::Ready(Err(io::Error::new(kind, "connection dropped"))),
+                None => Poll::Ready(Ok(())),
+            }
+        }
+    }
+
+    fn sample() -> Vec<u8> {
+        (0..100_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn dropping(data: &[u8], err: ErrorKind) -> FileReader {
+        Box::new(Dropping {
+            data: Cursor::new(data.to_vec()),
+            err: Some(err),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_resumable_reader_resumes_after_drop() {
+        let data = Arc::new(sample());
+        let reopened = Arc::new(AtomicUsize::new(0));
+
+        let reopen = {
+            let (data, reopened) = (data.clone(), reopened.clone());
+            move |consumed: u64| {
+                reopened.fetch_add(1, Ordering::SeqCst);
+                let rest = data[consumed as usize..].to_vec();
+                async move { Ok(Box::new(Cursor::new(rest)) as FileReader) }
+            }
+        };
+
+        let first = dropping(&data[..40_000], ErrorKind::ConnectionReset);
+        let mut reader = ResumableReader::new(first, reopen, RetryPolicy::no_delay(2), Some(data.len() as u64));
+
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).await.unwrap();
+        assert_eq!(out, *data);
+        assert_eq!(reopened.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resumable_reader_resumes_after_early_eof() {
+        let data = Arc::new(sample());
+
+        let reopen = {
+            let data = data.clone();
+            move |consumed: u64| {
+                let rest = data[consumed as usize..].to_vec();
+                async move { Ok(Box::new(Cursor::new(rest)) as FileReader) }
+            }
+        };
+
+        let first: FileReader = Box::new(Cursor::new(data[..10].to_vec()));
+        let mut reader = ResumableReader::new(first, reopen, RetryPolicy::no_delay(1), Some(data.len() as u64));
+
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).await.unwrap();
+        assert_eq!(out, *data);
+    }
+
+    #[tokio::test]
+    async fn test_resumable_reader_gives_up() {
+        let data = sample();
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let reopen = {
+            let attempts = attempts.clone();
+            move |_: u64| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async move { Err::<FileReader, _>(DiskError::other("peer unreachable")) }
+            }
+        };
+
+        let first = dropping(&data[..1000], ErrorKind::ConnectionReset);
+        let mut reader = ResumableReader::new(first, reopen, RetryPolicy::no_delay(3), None);
+
+        let mut out = Vec::new();
+        assert!(reader.read_to_end(&mut out).await.is_err());
+        assert_eq!(out, &data[..1000]);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // Bad data is not retried
+        let reopen = |_: u64| async { Ok(Box::new(Cursor::new(Vec::new())) as FileReader) };
+        let first = dropping(&data[..1000], ErrorKind::InvalidData);
+        let mut reader = ResumableReader::new(first, reopen, RetryPolicy::no_delay(3), None);
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn test_resumable_reader_error_after_last_byte() {
+        let data = sample();
+
+        // A stream failing after the last byte, like a verifying read with a bad checksum
+        for kind in [ErrorKind::InvalidData, ErrorKind::ConnectionReset] {
+            let reopen = |_: u64| async { Ok(Box::new(Cursor::new(Vec::new())) as FileReader) };
+            let first = dropping(&data, kind);
+            let mut reader = ResumableReader::new(first, reopen, RetryPolicy::no_delay(3), Some(data.len() as u64));
+
+            let mut out = Vec::new();
+            let err = reader.read_to_end(&mut out).await.unwrap_err();
+            assert_eq!(err.kind(), kind);
+            assert_eq!(out, data);
+        }
+    }
+}
@@ -102,7 +102,7 @@ impl Sets {
             }
 
             if !endpoint.is_local {
-                let host_port = format!("{}:{}", endpoint.url.host_str().unwrap(), endpoint.url.port().unwrap());
+                let host_port = endpoint.host_port();
                 if !unique[set_idx].contains(&host_port) {
                     unique[set_idx].push(host_port);
                 }
@@ -760,6 +760,7 @@ impl StorageAPI for Sets {
             &DiskOption {
                 cleanup: false,
                 health_check: false,
+                ..Default::default()
             },
         )
         .await;
@@ -30,6 +30,9 @@ use crate::bucket::utils::check_put_object_part_args;
 use crate::bucket::utils::{check_valid_bucket_name, check_valid_bucket_name_strict, is_meta_bucketname};
 use crate::config::GLOBAL_STORAGE_CLASS;
 use crate::config::storageclass;
+use crate::disk::disk_store::{
+    get_drive_compression, get_drive_direct_io, get_drive_http_chunk_size, get_drive_max_open_files, get_drive_write_checksum,
+};
 use crate::disk::endpoint::{Endpoint, EndpointType};
 use crate::disk::{DiskAPI, DiskInfo, DiskInfoOptions};
 use crate::error::{Error, Result};
@@ -205,6 +208,12 @@ impl ECStore {
                 &DiskOption {
                     cleanup: true,
                     health_check: true,
+                    direct_io: get_drive_direct_io(),
+                    max_open_files: get_drive_max_open_files(),
+                    write_checksum: get_drive_write_checksum(),
+                    chunk_size: get_drive_http_chunk_size(),
+                    compression: get_drive_compression(),
+                    ..Default::default()
                 },
             )
             .await;
@@ -1048,6 +1057,10 @@ pub async fn init_local_disks(endpoint_pools: EndpointServerPools) -> Result<()>
     let opt = &DiskOption {
         cleanup: true,
         health_check: true,
+        direct_io: get_drive_direct_io(),
+        max_open_files: get_drive_max_open_files(),
+        write_checksum: get_drive_write_checksum(),
+        ..Default::default()
     };
 
     let mut global_set_drives = GLOBAL_LOCAL_DISK_SET_DRIVES.write().await;
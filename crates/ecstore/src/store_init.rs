@@ -271,7 +271,7 @@ pub async fn load_format_erasure(disk: &DiskStore, heal: bool) -> disk::error::R
             }
         })?;
 
-    let mut fm = FormatV3::try_from(data.as_ref())?;
+    let mut fm = FormatV3::load(data.as_ref())?;
 
     if heal {
         let info = disk
@@ -323,12 +323,11 @@ pub async fn save_format_file(disk: &Option<DiskStore>, format: &Option<FormatV3
         return Err(DiskError::other("format is none"));
     };
 
-    let json_data = format.to_json()?;
+    let json_data = format.to_bytes()?;
 
     let tmpfile = Uuid::new_v4().to_string();
 
-    disk.write_all(RUSTFS_META_BUCKET, tmpfile.as_str(), json_data.into_bytes().into())
-        .await?;
+    disk.write_all(RUSTFS_META_BUCKET, tmpfile.as_str(), json_data.into()).await?;
 
     disk.rename_file(RUSTFS_META_BUCKET, tmpfile.as_str(), RUSTFS_META_BUCKET, FORMAT_CONFIG_FILE)
         .await?;
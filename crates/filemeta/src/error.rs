@@ -37,6 +37,9 @@ pub enum Error {
     #[error("Method not allowed")]
     MethodNotAllowed,
 
+    #[error("Outdated xl meta")]
+    OutdatedXLMeta,
+
     #[error("Unexpected error")]
     Unexpected,
 
@@ -86,6 +89,7 @@ impl PartialEq for Error {
             (Error::FileCorrupt, Error::FileCorrupt) => true,
             (Error::DoneForNow, Error::DoneForNow) => true,
             (Error::MethodNotAllowed, Error::MethodNotAllowed) => true,
+            (Error::OutdatedXLMeta, Error::OutdatedXLMeta) => true,
             (Error::FileNotFound, Error::FileNotFound) => true,
             (Error::FileVersionNotFound, Error::FileVersionNotFound) => true,
             (Error::VolumeNotFound, Error::VolumeNotFound) => true,
@@ -111,6 +115,7 @@ impl Clone for Error {
             Error::FileCorrupt => Error::FileCorrupt,
             Error::DoneForNow => Error::DoneForNow,
             Error::MethodNotAllowed => Error::MethodNotAllowed,
+            Error::OutdatedXLMeta => Error::OutdatedXLMeta,
             Error::VolumeNotFound => Error::VolumeNotFound,
             Error::Io(e) => Error::Io(std::io::Error::new(e.kind(), e.to_string())),
             Error::RmpSerdeDecode(s) => Error::RmpSerdeDecode(s.clone()),
@@ -268,6 +273,7 @@ mod tests {
             Error::FileCorrupt,
             Error::DoneForNow,
             Error::MethodNotAllowed,
+            Error::OutdatedXLMeta,
             Error::Unexpected,
             Error::Io(IoError::new(ErrorKind::NotFound, "test")),
             Error::RmpSerdeDecode("test decode error".to_string()),
@@ -317,6 +323,7 @@ mod tests {
             (Error::FileCorrupt, "File corrupt"),
             (Error::DoneForNow, "Done for now"),
             (Error::MethodNotAllowed, "Method not allowed"),
+            (Error::OutdatedXLMeta, "Outdated xl meta"),
             (Error::Unexpected, "Unexpected error"),
             (Error::RmpSerdeDecode("test".to_string()), "rmp serde decode error: test"),
             (Error::RmpSerdeEncode("test".to_string()), "rmp serde encode error: test"),
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Error, ReplicationState, ReplicationStatusType, Result, TRANSITION_COMPLETE, VersionPurgeStatusType};
+use crate::{Error, FileMeta, ReplicationState, ReplicationStatusType, Result, TRANSITION_COMPLETE, VersionPurgeStatusType};
 use bytes::Bytes;
 use rmp_serde::Serializer;
 use rustfs_utils::HashAlgorithm;
@@ -290,6 +290,21 @@ impl FileInfo {
         Ok(t)
     }
 
+    /// Encodes this version as a single-version xl.meta file, inline data included.
+    pub fn marshal_xl(&self) -> Result<Bytes> {
+        let mut meta = FileMeta::new();
+        meta.add_version(self.clone())?;
+        Ok(Bytes::from(meta.marshal_msg()?))
+    }
+
+    /// Decodes the latest version of an xl.meta file.
+    ///
+    /// Volume and name are not part of xl.meta and are left empty. Returns
+    /// `Error::OutdatedXLMeta` when the file was written by a newer major version.
+    pub fn unmarshal_xl(buf: &[u8]) -> Result<FileInfo> {
+        FileMeta::load(buf)?.into_fileinfo("", "", "", true, false, true)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_object_part(
         &mut self,
@@ -646,3 +661,93 @@ pub fn is_restored_object_on_disk(meta: &HashMap<String, String>) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_info_marshal_round_trip() {
+        let mut fi = FileInfo::new("bucket/object", 4, 2);
+        fi.volume = "bucket".to_string();
+        fi.name = "object".to_string();
+        fi.version_id = Some(Uuid::new_v4());
+        fi.data_dir = Some(Uuid::new_v4());
+        fi.mod_time = Some(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap());
+        fi.size = 3 * 1024 * 1024;
+        fi.metadata.insert("content-type".to_string(), "text/plain".to_string());
+        fi.metadata.insert("x-amz-meta-owner".to_string(), "alice".to_string());
+        fi.erasure.index = 3;
+
+        for number in 1..=2 {
+            let mut checksums = HashMap::new();
+            checksums.insert("CRC32C".to_string(), format!("sum-{number}"));
+            fi.parts.push(ObjectPartInfo {
+                etag: format!("etag-{number}"),
+                number,
+                size: 1024 * 1024 + number,
+                actual_size: 1024 * 1024,
+                mod_time: fi.mod_time,
+                checksums: Some(checksums),
+                ..Default::default()
+            });
+            fi.erasure.checksums.push(ChecksumInfo {
+                part_number: number,
+                algorithm: HashAlgorithm::HighwayHash256S,
+                hash: Bytes::from(vec![number as u8; 32]),
+            });
+        }
+
+        let decoded = FileInfo::unmarshal(&fi.marshal_msg().unwrap()).unwrap();
+        assert_eq!(decoded, fi);
+        assert_eq!(decoded.erasure.distribution.len(), 6);
+        assert_eq!(decoded.erasure.get_checksum_info(2).hash, Bytes::from(vec![2u8; 32]));
+        assert_eq!(decoded.parts[1].checksums.as_ref().unwrap()["CRC32C"], "sum-2");
+    }
+
+    #[test]
+    fn test_file_info_xl_round_trip() {
+        let mut fi = FileInfo::new("bucket/object", 4, 2);
+        fi.version_id = Some(Uuid::new_v4());
+        fi.data_dir = Some(Uuid::new_v4());
+        fi.mod_time = Some(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap());
+        fi.size = 2048;
+        fi.metadata.insert("content-type".to_string(), "text/plain".to_string());
+        fi.erasure.index = 2;
+        fi.data = Some(Bytes::from_static(b"inline object data"));
+        fi.add_object_part(1, "etag-1".to_string(), 2048, fi.mod_time, 2048, None, None);
+
+        let buf = fi.marshal_xl().unwrap();
+        let decoded = FileInfo::unmarshal_xl(&buf).unwrap();
+
+        assert_eq!(decoded.version_id, fi.version_id);
+        assert_eq!(decoded.data_dir, fi.data_dir);
+        assert_eq!(decoded.mod_time, fi.mod_time);
+        assert_eq!(decoded.size, fi.size);
+        assert_eq!(decoded.metadata.get("content-type"), Some(&"text/plain".to_string()));
+        assert_eq!(decoded.erasure.data_blocks, 4);
+        assert_eq!(decoded.erasure.parity_blocks, 2);
+        assert_eq!(decoded.erasure.index, 2);
+        assert_eq!(decoded.erasure.distribution, fi.erasure.distribution);
+        assert_eq!(decoded.parts.len(), 1);
+        assert_eq!(decoded.parts[0].etag, "etag-1");
+        assert_eq!(decoded.parts[0].size, 2048);
+        assert_eq!(decoded.data, fi.data);
+        assert!(decoded.is_latest);
+        assert_eq!(decoded.num_versions, 1);
+
+        assert_eq!(FileInfo::unmarshal_xl(&decoded.marshal_xl().unwrap()).unwrap(), decoded);
+    }
+
+    #[test]
+    fn test_file_info_unmarshal_xl_outdated() {
+        let mut fi = FileInfo::new("bucket/object", 2, 2);
+        fi.version_id = Some(Uuid::new_v4());
+        fi.mod_time = Some(OffsetDateTime::now_utc());
+
+        let mut buf = fi.marshal_xl().unwrap().to_vec();
+        buf[4..6].copy_from_slice(&2u16.to_le_bytes());
+
+        assert_eq!(FileInfo::unmarshal_xl(&buf).unwrap_err(), Error::OutdatedXLMeta);
+    }
+}
@@ -109,7 +109,7 @@ impl FileMeta {
         let major = byteorder::LittleEndian::read_u16(&buf[4..6]);
         let minor = byteorder::LittleEndian::read_u16(&buf[6..8]);
         if major > XL_FILE_VERSION_MAJOR {
-            return Err(Error::other("xl file version err"));
+            return Err(Error::OutdatedXLMeta);
         }
 
         Ok((&buf[8..], major, minor))
@@ -470,6 +470,7 @@ impl FileMeta {
     }
 
     pub fn update_object_version(&mut self, fi: FileInfo) -> Result<()> {
+        let mut found = false;
         for version in self.versions.iter_mut() {
             match version.header.version_type {
                 VersionType::Invalid | VersionType::Legacy => (),
@@ -479,6 +480,7 @@ impl FileMeta {
                     let ver_vid = version.header.version_id.or(Some(Uuid::nil()));
 
                     if ver_vid == fi_vid {
+                        found = true;
                         let mut ver = FileMetaVersion::try_from(version.meta.as_slice())?;
 
                         if let Some(ref mut obj) = ver.object {
@@ -518,6 +520,10 @@ impl FileMeta {
             }
         }
 
+        if !found {
+            return Err(Error::FileVersionNotFound);
+        }
+
         self.versions.sort_by(|a, b| {
             if a.header.mod_time != b.header.mod_time {
                 b.header.mod_time.cmp(&a.header.mod_time)
@@ -932,7 +938,6 @@ impl FileMeta {
         for ver in self.versions.iter() {
             let header = &ver.header;
 
-            // TODO: freeVersion
             if header.free_version() {
                 non_free_versions -= 1;
                 if include_free_versions && found_free_version.is_none() {
@@ -944,7 +949,8 @@ impl FileMeta {
                     }
                 }
 
-                if header.version_id != Some(vid) {
+                // Free versions are only returned by id when asked for
+                if !include_free_versions || header.version_id != Some(vid) {
                     continue;
                 }
             }
@@ -1005,13 +1011,30 @@ impl FileMeta {
         }
     }
 
-    pub fn into_file_info_versions(&self, volume: &str, path: &str, all_parts: bool) -> Result<FileInfoVersions> {
+    /// Lists every version. Free versions, left behind by deleting tiered objects, are kept
+    /// apart in `free_versions` and only filled in when `include_free_versions` is set.
+    pub fn into_file_info_versions(
+        &self,
+        volume: &str,
+        path: &str,
+        all_parts: bool,
+        include_free_versions: bool,
+    ) -> Result<FileInfoVersions> {
         let mut versions = Vec::new();
+        let mut free_versions = Vec::new();
         for version in self.versions.iter() {
+            if version.header.free_version() && !include_free_versions {
+                continue;
+            }
+
             let mut file_version = FileMetaVersion::default();
             file_version.unmarshal_msg(&version.meta)?;
             let fi = file_version.into_fileinfo(volume, path, all_parts);
-            versions.push(fi);
+            if version.header.free_version() {
+                free_versions.push(fi);
+            } else {
+                versions.push(fi);
+            }
         }
 
         let num = versions.len();
@@ -1041,7 +1064,7 @@ impl FileMeta {
             name: path.to_string(),
             latest_mod_time: versions[0].mod_time,
             versions,
-            ..Default::default()
+            free_versions,
         })
     }
 
@@ -2733,6 +2756,51 @@ mod test {
         assert_eq!(fm, newfm)
     }
 
+    #[test]
+    fn test_free_versions_only_when_requested() {
+        let mut fm = FileMeta::new();
+
+        let mut fi = FileInfo::new("object", 2, 2);
+        fi.version_id = Some(Uuid::new_v4());
+        fi.mod_time = Some(OffsetDateTime::now_utc());
+        fm.add_version(fi.clone()).unwrap();
+
+        let free_vid = Uuid::new_v4();
+        fm.add_version_filemata(FileMetaVersion {
+            version_type: VersionType::Delete,
+            delete_marker: Some(MetaDeleteMarker {
+                version_id: Some(free_vid),
+                mod_time: Some(OffsetDateTime::now_utc()),
+                meta_sys: HashMap::from([(format!("{RESERVED_METADATA_PREFIX_LOWER}{FREE_VERSION}"), vec![])]),
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let free_id = free_vid.to_string();
+        assert!(matches!(
+            fm.into_fileinfo("bucket", "object", &free_id, false, false, true),
+            Err(Error::FileVersionNotFound)
+        ));
+        let free = fm.into_fileinfo("bucket", "object", &free_id, false, true, true).unwrap();
+        assert_eq!(free.version_id, Some(free_vid));
+        assert!(free.tier_free_version());
+
+        let latest = fm.into_fileinfo("bucket", "object", "", false, false, true).unwrap();
+        assert_eq!(latest.version_id, fi.version_id);
+        assert_eq!(latest.num_versions, 1);
+
+        let fivs = fm.into_file_info_versions("bucket", "object", false, false).unwrap();
+        assert_eq!(fivs.versions.len(), 1);
+        assert!(fivs.free_versions.is_empty());
+
+        let fivs = fm.into_file_info_versions("bucket", "object", false, true).unwrap();
+        assert_eq!(fivs.versions.len(), 1);
+        assert_eq!(fivs.versions[0].version_id, fi.version_id);
+        assert_eq!(fivs.free_versions.len(), 1);
+        assert_eq!(fivs.free_versions[0].version_id, Some(free_vid));
+    }
+
     #[test]
     fn test_marshal_metaobject() {
         let obj = MetaObject {
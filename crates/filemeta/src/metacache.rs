@@ -188,7 +188,7 @@ impl MetaCacheEntry {
 
         let mut fm = FileMeta::new();
         fm.unmarshal_msg(&self.metadata)?;
-        fm.into_file_info_versions(bucket, self.name.as_str(), false)
+        fm.into_file_info_versions(bucket, self.name.as_str(), false, false)
     }
 
     pub fn matches(&self, other: Option<&MetaCacheEntry>, strict: bool) -> (Option<MetaCacheEntry>, bool) {
@@ -460,6 +460,11 @@ impl MetaCacheEntriesSorted {
 
 const METACACHE_STREAM_VERSION: u8 = 2;
 
+/// Writes metadata entries in the metacache stream format shared by local and remote `walk_dir`.
+///
+/// All values are msgpack encoded: the stream starts with the version as a `u8`, each entry
+/// is `true` followed by its name as `str` and its metadata as `bin`, and `false` ends the stream.
+/// The version is written even when the stream holds no entries.
 #[derive(Debug)]
 pub struct MetacacheWriter<W> {
     wr: W,
@@ -521,6 +526,7 @@ impl<W: AsyncWrite + Unpin> MetacacheWriter<W> {
     }
 
     pub async fn close(&mut self) -> Result<()> {
+        self.init().await?;
         rmp::encode::write_bool(&mut self.buf, false).map_err(|e| Error::other(format!("{e:?}")))?;
         self.flush().await?;
         Ok(())
@@ -900,4 +906,17 @@ mod tests {
 
         assert_eq!(objs, nobjs);
     }
+
+    #[tokio::test]
+    async fn test_writer_empty_stream_has_version() {
+        let mut f = Cursor::new(Vec::new());
+        let mut w = MetacacheWriter::new(&mut f);
+        w.close().await.unwrap();
+
+        let data = f.into_inner();
+        assert_eq!(data, vec![0xcc, METACACHE_STREAM_VERSION, 0xc2]);
+
+        let mut r = MetacacheReader::new(Cursor::new(data));
+        assert!(r.read_all().await.unwrap().is_empty());
+    }
 }
@@ -49,6 +49,8 @@ impl ItemState {
 pub struct DiskMetrics {
     pub last_minute: HashMap<String, TimedAction>,
     pub api_calls: HashMap<String, u64>,
+    #[serde(default)]
+    pub api_errors: HashMap<String, u64>,
     pub total_waiting: u32,
     pub total_errors_availability: u64,
     pub total_errors_timeout: u64,
@@ -398,6 +400,7 @@ mod tests {
         let metrics = DiskMetrics {
             last_minute,
             api_calls,
+            api_errors: HashMap::new(),
             total_waiting: 5,
             total_errors_availability: 2,
             total_errors_timeout: 1,
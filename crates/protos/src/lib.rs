@@ -16,12 +16,12 @@
 mod generated;
 
 use proto_gen::node_service::node_service_client::NodeServiceClient;
-use rustfs_common::{GLOBAL_CONN_MAP, GLOBAL_MTLS_IDENTITY, GLOBAL_ROOT_CERT, evict_connection};
+use rustfs_common::{GLOBAL_CONN_MAP, GLOBAL_MTLS_IDENTITY, GLOBAL_ROOT_CERT, MtlsIdentityPem, evict_connection};
 use std::{error::Error, time::Duration};
 use tonic::{
     Request, Status,
     service::interceptor::InterceptedService,
-    transport::{Certificate, Channel, ClientTlsConfig, Endpoint},
+    transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity},
 };
 use tracing::{debug, warn};
 
@@ -80,43 +80,11 @@ pub async fn create_new_channel(addr: &str) -> Result<Channel, Box<dyn Error>> {
         // Overall timeout for any RPC - fail fast on unresponsive peers
         .timeout(Duration::from_secs(RPC_TIMEOUT_SECS));
 
-    let root_cert = GLOBAL_ROOT_CERT.read().await;
     if addr.starts_with(RUSTFS_HTTPS_PREFIX) {
-        if root_cert.is_none() {
-            debug!("No custom root certificate configured; using system roots for TLS: {}", addr);
-            // If no custom root cert is configured, try to use system roots.
-            connector = connector.tls_config(ClientTlsConfig::new())?;
-        }
-        if let Some(cert_pem) = root_cert.as_ref() {
-            let ca = Certificate::from_pem(cert_pem);
-            // Derive the hostname from the HTTPS URL for TLS hostname verification.
-            let domain = addr
-                .trim_start_matches(RUSTFS_HTTPS_PREFIX)
-                .split('/')
-                .next()
-                .unwrap_or("")
-                .split(':')
-                .next()
-                .unwrap_or("");
-            let tls = if !domain.is_empty() {
-                let mut cfg = ClientTlsConfig::new().ca_certificate(ca).domain_name(domain);
-                let mtls_identity = GLOBAL_MTLS_IDENTITY.read().await;
-                if let Some(id) = mtls_identity.as_ref() {
-                    let identity = tonic::transport::Identity::from_pem(id.cert_pem.clone(), id.key_pem.clone());
-                    cfg = cfg.identity(identity);
-                }
-                cfg
-            } else {
-                // Fallback: configure TLS without explicit domain if parsing fails.
-                ClientTlsConfig::new().ca_certificate(ca)
-            };
-            connector = connector.tls_config(tls)?;
-            debug!("Configured TLS with custom root certificate for: {}", addr);
-        } else {
-            return Err(std::io::Error::other(
-                "HTTPS requested but no trusted roots are configured. Provide tls/ca.crt (or enable system roots via RUSTFS_TRUST_SYSTEM_CA=true)."
-            ).into());
-        }
+        let root_cert = GLOBAL_ROOT_CERT.read().await;
+        let mtls_identity = GLOBAL_MTLS_IDENTITY.read().await;
+        connector = connector.tls_config(client_tls_config(addr, root_cert.as_deref(), mtls_identity.as_ref())?)?;
+        debug!("Configured TLS with custom root certificate for: {}", addr);
     }
 
     let channel = connector.connect().await?;
@@ -130,9 +98,74 @@ pub async fn create_new_channel(addr: &str) -> Result<Channel, Box<dyn Error>> {
     Ok(channel)
 }
 
+/// Builds the TLS config for an `https://` peer address.
+///
+/// Server certificates are always verified against the configured root certificate, using the
+/// peer host name for verification. The optional mTLS identity is presented to the server.
+fn client_tls_config(
+    addr: &str,
+    root_cert: Option<&[u8]>,
+    identity: Option<&MtlsIdentityPem>,
+) -> Result<ClientTlsConfig, Box<dyn Error>> {
+    let Some(cert_pem) = root_cert else {
+        return Err(std::io::Error::other(
+            "HTTPS requested but no trusted roots are configured. Provide tls/ca.crt (or enable system roots via RUSTFS_TRUST_SYSTEM_CA=true).",
+        )
+        .into());
+    };
+
+    let mut cfg = ClientTlsConfig::new().ca_certificate(Certificate::from_pem(cert_pem));
+    // Derive the hostname from the HTTPS URL for TLS hostname verification.
+    if let Some(domain) = tls_domain(addr) {
+        cfg = cfg.domain_name(domain);
+    }
+    if let Some(id) = identity {
+        cfg = cfg.identity(Identity::from_pem(id.cert_pem.clone(), id.key_pem.clone()));
+    }
+
+    Ok(cfg)
+}
+
+/// Host part of an `https://` address, with the brackets of an IPv6 literal removed.
+fn tls_domain(addr: &str) -> Option<&str> {
+    let authority = addr.strip_prefix(RUSTFS_HTTPS_PREFIX)?.split('/').next()?;
+    let host = match authority.strip_prefix('[') {
+        Some(rest) => rest.split(']').next()?,
+        None => authority.split(':').next()?,
+    };
+
+    (!host.is_empty()).then_some(host)
+}
+
 /// Evict a connection from the cache after a failure.
 /// This should be called when an RPC fails to ensure fresh connections are tried.
 pub async fn evict_failed_connection(addr: &str) {
     warn!("Evicting failed gRPC connection: {}", addr);
     evict_connection(addr).await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tls_domain() {
+        assert_eq!(tls_domain("https://node1:9000"), Some("node1"));
+        assert_eq!(tls_domain("https://node1.example.com/path"), Some("node1.example.com"));
+        assert_eq!(tls_domain("https://[::1]:9000"), Some("::1"));
+        assert_eq!(tls_domain("http://node1:9000"), None);
+        assert_eq!(tls_domain("https://"), None);
+    }
+
+    #[test]
+    fn test_client_tls_config() {
+        // Certificates are verified by default, so HTTPS without trusted roots is refused
+        assert!(client_tls_config("https://node1:9000", None, None).is_err());
+
+        let identity = MtlsIdentityPem {
+            cert_pem: b"cert".to_vec(),
+            key_pem: b"key".to_vec(),
+        };
+        assert!(client_tls_config("https://node1:9000", Some(b"ca".as_slice()), Some(&identity)).is_ok());
+    }
+}
@@ -131,6 +131,7 @@ pin_project! {
         url:String,
         method: Method,
         headers: HeaderMap,
+        response_headers: HeaderMap,
         #[pin]
         inner: StreamReader<Pin<Box<dyn Stream<Item=std::io::Result<Bytes>>+Send+Sync>>, Bytes>,
     }
@@ -187,6 +188,7 @@ impl HttpReader {
             )));
         }
 
+        let response_headers = resp.headers().clone();
         let stream = resp
             .bytes_stream()
             .map_err(|e| Error::other(format!("HttpReader stream error: {e}")));
@@ -196,6 +198,7 @@ impl HttpReader {
             url,
             method,
             headers,
+            response_headers,
         })
     }
     pub fn url(&self) -> &str {
@@ -207,6 +210,10 @@ impl HttpReader {
     pub fn headers(&self) -> &HeaderMap {
         &self.headers
     }
+    /// Headers of the response the body is streamed from.
+    pub fn response_headers(&self) -> &HeaderMap {
+        &self.response_headers
+    }
 }
 
 impl AsyncRead for HttpReader {
@@ -266,7 +273,9 @@ impl Stream for ReceiverStream {
         match poll {
             Poll::Ready(Some(Some(bytes))) => Poll::Ready(Some(Ok(bytes))),
             Poll::Ready(Some(None)) => Poll::Ready(None), // Sender shutdown
-            Poll::Ready(None) => Poll::Ready(None),
+            // The writer was dropped without shutdown, fail the body so the peer discards the
+            // upload instead of keeping whatever reached it before buffered data was lost
+            Poll::Ready(None) => Poll::Ready(Some(Err(Error::other("HttpWriter dropped before shutdown")))),
             Poll::Pending => Poll::Pending,
         }
     }
@@ -513,3 +522,26 @@ impl AsyncWrite for HttpWriter {
 //         // println!("[test_http_writer_and_reader_ok] completed successfully");
 //     }
 // }
+
+#[cfg(test)]
+mod receiver_stream_tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_receiver_stream_fails_without_shutdown() {
+        let (sender, receiver) = mpsc::channel::<Option<Bytes>>(8);
+        let mut stream = ReceiverStream { receiver };
+        sender.send(Some(Bytes::from_static(b"data"))).await.unwrap();
+        sender.send(None).await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), Bytes::from_static(b"data"));
+        assert!(stream.next().await.is_none());
+
+        let (sender, receiver) = mpsc::channel::<Option<Bytes>>(8);
+        let mut stream = ReceiverStream { receiver };
+        sender.send(Some(Bytes::from_static(b"data"))).await.unwrap();
+        drop(sender);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_err());
+    }
+}
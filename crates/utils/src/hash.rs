@@ -16,6 +16,7 @@ use highway::{HighwayHash, HighwayHasher, Key};
 use md5::{Digest, Md5};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
+use xxhash_rust::xxh3::{Xxh3, xxh3_64};
 
 /// The fixed key for HighwayHash256. DO NOT change for compatibility.
 const HIGHWAY_HASH256_KEY: [u64; 4] = [3, 4, 2, 1];
@@ -36,6 +37,12 @@ pub enum HashAlgorithm {
     Md5,
     /// No hash (for testing or unprotected data)
     None,
+    /// BLAKE3 (256-bit)
+    Blake3,
+    /// CRC32C (Castagnoli, 32-bit)
+    Crc32c,
+    /// XXH3 (64-bit), not cryptographic
+    Xxh3,
 }
 
 enum HashEncoded {
@@ -44,6 +51,9 @@ enum HashEncoded {
     HighwayHash256([u8; 32]),
     HighwayHash256S([u8; 32]),
     Blake2b512(blake3::Hash),
+    Blake3(blake3::Hash),
+    Crc32c([u8; 4]),
+    Xxh3([u8; 8]),
     None,
 }
 
@@ -56,6 +66,9 @@ impl AsRef<[u8]> for HashEncoded {
             HashEncoded::HighwayHash256(hash) => hash.as_ref(),
             HashEncoded::HighwayHash256S(hash) => hash.as_ref(),
             HashEncoded::Blake2b512(hash) => hash.as_bytes(),
+            HashEncoded::Blake3(hash) => hash.as_bytes(),
+            HashEncoded::Crc32c(hash) => hash.as_ref(),
+            HashEncoded::Xxh3(hash) => hash.as_ref(),
             HashEncoded::None => &[],
         }
     }
@@ -94,6 +107,9 @@ impl HashAlgorithm {
                 HashEncoded::HighwayHash256S(u8x32_from_u64x4(hasher.finalize256()))
             }
             HashAlgorithm::BLAKE2b512 => HashEncoded::Blake2b512(blake3::hash(data)),
+            HashAlgorithm::Blake3 => HashEncoded::Blake3(blake3::hash(data)),
+            HashAlgorithm::Crc32c => HashEncoded::Crc32c(crc32c(data).to_be_bytes()),
+            HashAlgorithm::Xxh3 => HashEncoded::Xxh3(xxh3_64(data).to_be_bytes()),
             HashAlgorithm::None => HashEncoded::None,
         }
     }
@@ -109,10 +125,116 @@ impl HashAlgorithm {
             HashAlgorithm::HighwayHash256 => 32,
             HashAlgorithm::HighwayHash256S => 32,
             HashAlgorithm::BLAKE2b512 => 32, // blake3 outputs 32 bytes by default
+            HashAlgorithm::Blake3 => 32,
+            HashAlgorithm::Crc32c => 4,
+            HashAlgorithm::Xxh3 => 8,
             HashAlgorithm::Md5 => 16,
             HashAlgorithm::None => 0,
         }
     }
+
+    /// Create an incremental hash state for the algorithm.
+    pub fn new_state(&self) -> HashState {
+        match self {
+            HashAlgorithm::Md5 => HashState::Md5(Md5::new()),
+            HashAlgorithm::SHA256 => HashState::Sha256(Sha256::new()),
+            HashAlgorithm::HighwayHash256 | HashAlgorithm::HighwayHash256S => {
+                HashState::HighwayHash256(HighwayHasher::new(Key(HIGHWAY_HASH256_KEY)))
+            }
+            HashAlgorithm::BLAKE2b512 | HashAlgorithm::Blake3 => HashState::Blake3(Box::new(blake3::Hasher::new())),
+            HashAlgorithm::Crc32c => HashState::Crc32c(crc_fast::Digest::new(crc_fast::CrcAlgorithm::Crc32Iscsi)),
+            HashAlgorithm::Xxh3 => HashState::Xxh3(Box::new(Xxh3::new())),
+            HashAlgorithm::None => HashState::None,
+        }
+    }
+}
+
+/// Compute the SHA-256 hash of `data` as a lowercase hex string.
+pub fn sum_sha256_hex(data: &[u8]) -> String {
+    hex_simd::encode_to_string(Sha256::digest(data), hex_simd::AsciiCase::Lower)
+}
+
+/// Compute the BLAKE3 hash of `data` as a lowercase hex string.
+pub fn sum_blake3_hex(data: &[u8]) -> String {
+    hex_simd::encode_to_string(blake3::hash(data).as_bytes(), hex_simd::AsciiCase::Lower)
+}
+
+#[inline]
+fn crc32c(data: &[u8]) -> u32 {
+    crc_fast::checksum(crc_fast::CrcAlgorithm::Crc32Iscsi, data) as u32
+}
+
+/// Compute the CRC32C (Castagnoli) checksum of `data` as a lowercase hex string of its 4 big-endian bytes.
+pub fn sum_crc32c_hex(data: &[u8]) -> String {
+    hex_simd::encode_to_string(crc32c(data).to_be_bytes(), hex_simd::AsciiCase::Lower)
+}
+
+/// Incremental state of a [`HashAlgorithm`], producing the same digest as [`HashAlgorithm::hash_encode`].
+pub enum HashState {
+    Md5(Md5),
+    Sha256(Sha256),
+    HighwayHash256(HighwayHasher),
+    Blake3(Box<blake3::Hasher>),
+    Crc32c(crc_fast::Digest),
+    Xxh3(Box<Xxh3>),
+    None,
+}
+
+impl HashState {
+    /// Feed `data` into the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            HashState::Md5(hasher) => hasher.update(data),
+            HashState::Sha256(hasher) => hasher.update(data),
+            HashState::HighwayHash256(hasher) => hasher.append(data),
+            HashState::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            HashState::Crc32c(hasher) => hasher.update(data),
+            HashState::Xxh3(hasher) => hasher.update(data),
+            HashState::None => {}
+        }
+    }
+
+    /// Consume the state and return the digest.
+    pub fn finalize(self) -> Vec<u8> {
+        match self {
+            HashState::Md5(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HashState::HighwayHash256(hasher) => u8x32_from_u64x4(hasher.finalize256()).to_vec(),
+            HashState::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            HashState::Crc32c(hasher) => (hasher.finalize() as u32).to_be_bytes().to_vec(),
+            HashState::Xxh3(hasher) => hasher.digest().to_be_bytes().to_vec(),
+            HashState::None => Vec::new(),
+        }
+    }
+}
+
+/// Compute the XXH3 64-bit hash (seed 0) of `data` as a lowercase hex string.
+pub fn sum_xxh3_hex(data: &[u8]) -> String {
+    format!("{:016x}", xxh3_64(data))
+}
+
+/// Hash `data` with `algo` and check the digest against `expected_hex`.
+///
+/// The hex digests are compared case-insensitively in constant time.
+pub fn verify_bytes(data: &[u8], expected_hex: &str, algo: &HashAlgorithm) -> bool {
+    digest_matches_hex(algo.hash_encode(data).as_ref(), expected_hex)
+}
+
+/// Compare `digest` against a hex encoded digest without short-circuiting on the first difference.
+pub fn digest_matches_hex(digest: &[u8], expected_hex: &str) -> bool {
+    let actual = hex_simd::encode_to_string(digest, hex_simd::AsciiCase::Lower);
+    let expected = expected_hex.as_bytes();
+    if actual.len() != expected.len() {
+        return false;
+    }
+
+    actual
+        .bytes()
+        .zip(expected)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b.to_ascii_lowercase()))
+        == 0
 }
 
 use siphasher::sip::SipHasher;
@@ -168,6 +290,9 @@ mod tests {
         assert_eq!(HashAlgorithm::HighwayHash256S.size(), 32);
         assert_eq!(HashAlgorithm::SHA256.size(), 32);
         assert_eq!(HashAlgorithm::BLAKE2b512.size(), 32);
+        assert_eq!(HashAlgorithm::Blake3.size(), 32);
+        assert_eq!(HashAlgorithm::Crc32c.size(), 4);
+        assert_eq!(HashAlgorithm::Xxh3.size(), 8);
         assert_eq!(HashAlgorithm::None.size(), 0);
     }
 
@@ -191,6 +316,19 @@ mod tests {
         assert_eq!(hash, hash2);
     }
 
+    #[test]
+    fn test_hash_encode_md5_known_values() {
+        let encode = |data: &[u8]| {
+            let hash = HashAlgorithm::Md5.hash_encode(data);
+            hex_simd::encode_to_string(hash.as_ref(), hex_simd::AsciiCase::Lower)
+        };
+
+        // Each call starts from a fresh state, so earlier input never leaks into later digests
+        assert_eq!(encode(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(encode(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(encode(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+    }
+
     #[test]
     fn test_hash_encode_highway() {
         let data = b"test data";
@@ -227,6 +365,83 @@ mod tests {
         assert_eq!(hash, hash2);
     }
 
+    #[test]
+    fn test_sum_sha256_hex() {
+        assert_eq!(sum_sha256_hex(b""), EMPTY_STRING_SHA256_HASH);
+        assert_eq!(sum_sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn test_hash_encode_blake3() {
+        // Known answers from the BLAKE3 reference implementation
+        assert_eq!(sum_blake3_hex(b""), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+        assert_eq!(sum_blake3_hex(b"foo"), "04e0bb39f30b1a3feb89f536c93be15055482df748674b00d26e5a75777702e9");
+
+        let hash = HashAlgorithm::Blake3.hash_encode(b"foo");
+        assert_eq!(hash.as_ref().len(), 32);
+        assert_eq!(
+            hex_simd::encode_to_string(hash.as_ref(), hex_simd::AsciiCase::Lower),
+            sum_blake3_hex(b"foo")
+        );
+    }
+
+    #[test]
+    fn test_hash_encode_crc32c() {
+        // Standard CRC-32C check values
+        assert_eq!(sum_crc32c_hex(b"123456789"), "e3069283");
+        assert_eq!(sum_crc32c_hex(b""), "00000000");
+        assert_eq!(sum_crc32c_hex(&[0u8; 32]), "8a9136aa");
+        assert_eq!(sum_crc32c_hex(&[0xffu8; 32]), "62a8ab43");
+
+        let hash = HashAlgorithm::Crc32c.hash_encode(b"123456789");
+        assert_eq!(hash.as_ref(), &[0xe3, 0x06, 0x92, 0x83]);
+    }
+
+    #[test]
+    fn test_hash_encode_xxh3() {
+        // XXH3 64-bit reference values with the default seed
+        assert_eq!(sum_xxh3_hex(b""), "2d06800538d394c2");
+        assert_eq!(sum_xxh3_hex(b"abc"), "78af5f94892f3950");
+
+        let hash = HashAlgorithm::Xxh3.hash_encode(b"abc");
+        assert_eq!(hash.as_ref(), &[0x78, 0xaf, 0x5f, 0x94, 0x89, 0x2f, 0x39, 0x50]);
+    }
+
+    #[test]
+    fn test_verify_bytes() {
+        let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert!(verify_bytes(b"abc", expected, &HashAlgorithm::SHA256));
+        assert!(verify_bytes(b"abc", &expected.to_uppercase(), &HashAlgorithm::SHA256));
+
+        assert!(!verify_bytes(b"abd", expected, &HashAlgorithm::SHA256));
+        assert!(!verify_bytes(b"abc", &expected[..62], &HashAlgorithm::SHA256));
+        assert!(!verify_bytes(b"abc", expected, &HashAlgorithm::Md5));
+    }
+
+    #[test]
+    fn test_hash_state_matches_hash_encode() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let algorithms = [
+            HashAlgorithm::Md5,
+            HashAlgorithm::SHA256,
+            HashAlgorithm::HighwayHash256,
+            HashAlgorithm::HighwayHash256S,
+            HashAlgorithm::BLAKE2b512,
+            HashAlgorithm::Blake3,
+            HashAlgorithm::Crc32c,
+            HashAlgorithm::Xxh3,
+            HashAlgorithm::None,
+        ];
+
+        for algo in algorithms {
+            let mut state = algo.new_state();
+            for chunk in data.chunks(333) {
+                state.update(chunk);
+            }
+            assert_eq!(state.finalize(), algo.hash_encode(&data).as_ref(), "{algo:?}");
+        }
+    }
+
     #[test]
     fn test_different_data_different_hashes() {
         let data1 = b"test data 1";
@@ -0,0 +1,131 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::hash::{HashAlgorithm, HashState, digest_matches_hex};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// AsyncRead wrapper that hashes every byte read from the inner reader.
+///
+/// Once the inner reader is drained, `finalize` returns the digest of the whole stream,
+/// so a stream can be verified without buffering it or reading it twice.
+pub struct HashingReader<R> {
+    inner: R,
+    state: HashState,
+    read: u64,
+}
+
+impl<R> HashingReader<R> {
+    pub fn new(inner: R, algo: &HashAlgorithm) -> Self {
+        Self {
+            inner,
+            state: algo.new_state(),
+            read: 0,
+        }
+    }
+
+    /// Number of bytes read so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.read
+    }
+
+    /// Return the digest of all bytes read so far.
+    pub fn finalize(self) -> Vec<u8> {
+        self.state.finalize()
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for HashingReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        let res = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = &res {
+            let data = &buf.filled()[before..];
+            this.state.update(data);
+            this.read += data.len() as u64;
+        }
+        res
+    }
+}
+
+/// Drain `reader` and check its digest against `expected_hex`, see [`crate::hash::verify_bytes`].
+pub async fn verify_stream<R: AsyncRead + Unpin>(reader: R, expected_hex: &str, algo: &HashAlgorithm) -> std::io::Result<bool> {
+    let mut reader = HashingReader::new(reader, algo);
+    tokio::io::copy(&mut reader, &mut tokio::io::sink()).await?;
+
+    Ok(digest_matches_hex(&reader.finalize(), expected_hex))
+}
+
+/// Drain `reader` and return its digest as lowercase hex, a key under which identical content
+/// can be stored once.
+pub async fn content_key<R: AsyncRead + Unpin>(reader: R, algo: &HashAlgorithm) -> std::io::Result<String> {
+    let mut reader = HashingReader::new(reader, algo);
+    tokio::io::copy(&mut reader, &mut tokio::io::sink()).await?;
+
+    Ok(hex_simd::encode_to_string(reader.finalize(), hex_simd::AsciiCase::Lower))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_hashing_reader_matches_one_shot_hash() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 253) as u8).collect();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("object");
+        tokio::fs::write(&path, &data).await.unwrap();
+
+        for algo in [HashAlgorithm::SHA256, HashAlgorithm::HighwayHash256, HashAlgorithm::Crc32c] {
+            let file = tokio::fs::File::open(&path).await.unwrap();
+            let mut reader = HashingReader::new(file, &algo);
+
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).await.unwrap();
+
+            assert_eq!(out, data);
+            assert_eq!(reader.bytes_read(), data.len() as u64);
+            assert_eq!(reader.finalize(), algo.hash_encode(&data).as_ref());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_stream() {
+        let data = b"abc".repeat(10_000);
+        let expected = crate::hash::sum_sha256_hex(&data);
+
+        assert!(verify_stream(&data[..], &expected, &HashAlgorithm::SHA256).await.unwrap());
+        assert!(
+            !verify_stream(&data[..data.len() - 1], &expected, &HashAlgorithm::SHA256)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_content_key() {
+        let data = b"abc".repeat(10_000);
+
+        let key = content_key(&data[..], &HashAlgorithm::SHA256).await.unwrap();
+        assert_eq!(key, crate::hash::sum_sha256_hex(&data));
+        assert_ne!(key, content_key(&data[1..], &HashAlgorithm::SHA256).await.unwrap());
+    }
+}
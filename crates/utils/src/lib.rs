@@ -34,6 +34,9 @@ pub mod io;
 #[cfg(feature = "hash")]
 pub mod hash;
 
+#[cfg(all(feature = "hash", feature = "io"))]
+pub mod hash_reader;
+
 #[cfg(feature = "os")]
 pub mod os;
 
@@ -58,6 +61,9 @@ pub use certs::*;
 #[cfg(feature = "hash")]
 pub use hash::*;
 
+#[cfg(all(feature = "hash", feature = "io"))]
+pub use hash_reader::*;
+
 #[cfg(feature = "io")]
 pub use io::*;
 
@@ -12,14 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nix::fcntl::FallocateFlags;
 use nix::sys::stat::{self, stat};
 use nix::sys::statfs::{self, FsType, statfs};
 use std::fs::File;
 use std::io::{self, BufRead, Error, ErrorKind};
+use std::os::fd::AsFd;
 use std::path::Path;
 
 use super::{DiskInfo, IOStats};
 
+/// `O_DIRECT` open flag, for use with `OpenOptionsExt::custom_flags`.
+pub const O_DIRECT: i32 = nix::fcntl::OFlag::O_DIRECT.bits();
+
+/// Reserves `len` bytes of disk space for `fd` without changing the file size.
+pub fn fallocate(fd: impl AsFd, len: u64) -> std::io::Result<()> {
+    nix::fcntl::fallocate(fd, FallocateFlags::FALLOC_FL_KEEP_SIZE, 0, len as i64)?;
+    Ok(())
+}
+
 /// Returns total and free bytes available in a directory, e.g. `/`.
 pub fn get_info(p: impl AsRef<Path>) -> std::io::Result<DiskInfo> {
     let path_display = p.as_ref().display();
@@ -20,7 +20,7 @@ mod unix;
 mod windows;
 
 #[cfg(target_os = "linux")]
-pub use linux::{get_drive_stats, get_info, same_disk};
+pub use linux::{O_DIRECT, fallocate, get_drive_stats, get_info, same_disk};
 // pub use linux::same_disk;
 
 #[cfg(all(unix, not(target_os = "linux")))]
@@ -17,12 +17,15 @@ use super::router::Operation;
 use super::router::S3Router;
 use crate::server::RPC_PREFIX;
 use futures::StreamExt;
-use http::StatusCode;
+use http::header::{ACCEPT_ENCODING, CONTENT_ENCODING};
+use http::{HeaderMap, HeaderValue, StatusCode};
 use hyper::Method;
 use matchit::Params;
 use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
 use rustfs_ecstore::disk::DiskAPI;
+use rustfs_ecstore::disk::ReadOptions;
 use rustfs_ecstore::disk::WalkDirOptions;
+use rustfs_ecstore::rpc::{ACCEPTED_ENCODINGS, Codec, negotiate};
 use rustfs_ecstore::set_disk::DEFAULT_READ_BUFFER_SIZE;
 use rustfs_ecstore::store::find_local_disk;
 use rustfs_utils::net::bytes_stream;
@@ -33,7 +36,7 @@ use s3s::S3Result;
 use s3s::dto::StreamingBlob;
 use s3s::s3_error;
 use serde_urlencoded::from_bytes;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio_util::io::ReaderStream;
 use tracing::warn;
 
@@ -56,6 +59,12 @@ pub fn register_rpc_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<(
         AdminOperation(&PutFile {}),
     )?;
 
+    r.insert(
+        Method::HEAD,
+        format!("{}{}", RPC_PREFIX, "/put_file_stream").as_str(),
+        AdminOperation(&PutFile {}),
+    )?;
+
     r.insert(
         Method::GET,
         format!("{}{}", RPC_PREFIX, "/walk_dir").as_str(),
@@ -71,7 +80,7 @@ pub fn register_rpc_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<(
     Ok(())
 }
 
-// /rustfs/rpc/read_file_stream?disk={}&volume={}&path={}&offset={}&length={}"
+// /rustfs/rpc/read_file_stream?disk={}&volume={}&path={}&offset={}&length={}&verify={}"
 #[derive(Debug, Default, serde::Deserialize)]
 pub struct ReadFileQuery {
     disk: String,
@@ -79,6 +88,8 @@ pub struct ReadFileQuery {
     path: String,
     offset: usize,
     length: usize,
+    #[serde(default)]
+    verify: bool,
 }
 pub struct ReadFile {}
 #[async_trait::async_trait]
@@ -102,17 +113,48 @@ impl Operation for ReadFile {
         };
 
         let file = disk
-            .read_file_stream(&query.volume, &query.path, query.offset, query.length)
+            .read_file_stream(
+                &query.volume,
+                &query.path,
+                query.offset,
+                query.length,
+                &ReadOptions {
+                    verify: query.verify,
+                    ..Default::default()
+                },
+            )
             .await
             .map_err(|e| s3_error!(InternalError, "read file err {}", e))?;
 
-        Ok(S3Response::new((
-            StatusCode::OK,
-            Body::from(StreamingBlob::wrap(bytes_stream(
-                ReaderStream::with_capacity(file, DEFAULT_READ_BUFFER_SIZE),
-                query.length,
-            ))),
-        )))
+        let codec = req
+            .headers
+            .get(ACCEPT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .and_then(negotiate);
+
+        let Some(codec) = codec else {
+            return Ok(S3Response::new((
+                StatusCode::OK,
+                Body::from(StreamingBlob::wrap(bytes_stream(
+                    ReaderStream::with_capacity(file, DEFAULT_READ_BUFFER_SIZE),
+                    query.length,
+                ))),
+            )));
+        };
+
+        let file = BufReader::with_capacity(DEFAULT_READ_BUFFER_SIZE, file.take(query.length as u64));
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_ENCODING, HeaderValue::from_static(codec.as_str()));
+        Ok(S3Response::with_headers(
+            (
+                StatusCode::OK,
+                Body::from(StreamingBlob::wrap(ReaderStream::with_capacity(
+                    codec.encode_reader(file),
+                    DEFAULT_READ_BUFFER_SIZE,
+                ))),
+            ),
+            header,
+        ))
     }
 }
 
@@ -184,6 +226,13 @@ pub struct PutFile {}
 #[async_trait::async_trait]
 impl Operation for PutFile {
     async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        // Tells clients which codecs they may compress uploads with
+        if req.method == Method::HEAD {
+            let mut header = HeaderMap::new();
+            header.insert(ACCEPT_ENCODING, HeaderValue::from_static(ACCEPTED_ENCODINGS));
+            return Ok(S3Response::with_headers((StatusCode::OK, Body::empty()), header));
+        }
+
         let query = {
             if let Some(query) = req.uri.query() {
                 let input: PutFileQuery =
@@ -208,13 +257,31 @@ impl Operation for PutFile {
                 .map_err(|e| s3_error!(InternalError, "read file err {}", e))?
         };
 
+        let mut codec = match req.headers.get(CONTENT_ENCODING).and_then(|v| v.to_str().ok()) {
+            Some(encoding) => Some(
+                encoding
+                    .parse::<Codec>()
+                    .map_err(|e| s3_error!(InvalidArgument, "content encoding err {}", e))?,
+            ),
+            None => None,
+        };
+
         let mut body = req.input;
         while let Some(item) = body.next().await {
             let bytes = item.map_err(|e| s3_error!(InternalError, "body stream err {}", e))?;
+            // Only bodies with data are decoded, the empty probe request carries no stream
+            if let Some(codec) = codec.take() {
+                file = codec.decode_writer(file);
+            }
             let result = file.write_all(&bytes).await;
             result.map_err(|e| s3_error!(InternalError, "write file err {}", e))?;
         }
 
+        // Make sure buffered data reaches the disk before acknowledging the upload
+        file.shutdown()
+            .await
+            .map_err(|e| s3_error!(InternalError, "close file err {}", e))?;
+
         Ok(S3Response::new((StatusCode::OK, Body::empty())))
     }
 }
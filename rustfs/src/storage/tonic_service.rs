@@ -952,20 +952,39 @@ impl Node for NodeService {
     async fn make_volumes(&self, request: Request<MakeVolumesRequest>) -> Result<Response<MakeVolumesResponse>, Status> {
         let request = request.into_inner();
         if let Some(disk) = self.find_disk(&request.disk).await {
-            match disk.make_volumes(request.volumes.iter().map(|s| &**s).collect()).await {
-                Ok(_) => Ok(Response::new(MakeVolumesResponse {
-                    success: true,
-                    error: None,
-                })),
+            match disk.make_volumes_each(request.volumes.iter().map(|s| &**s).collect()).await {
+                Ok(results) => {
+                    let error = results.iter().find_map(|res| res.as_ref().err()).cloned();
+                    if error.is_none() {
+                        return Ok(Response::new(MakeVolumesResponse {
+                            success: true,
+                            error: None,
+                            errors: Vec::new(),
+                        }));
+                    }
+
+                    // Tell the caller why each volume failed, code 0 for those that were made
+                    let errors = results
+                        .into_iter()
+                        .map(|res| res.err().map(Into::into).unwrap_or_default())
+                        .collect();
+                    Ok(Response::new(MakeVolumesResponse {
+                        success: false,
+                        error: error.map(Into::into),
+                        errors,
+                    }))
+                }
                 Err(err) => Ok(Response::new(MakeVolumesResponse {
                     success: false,
                     error: Some(err.into()),
+                    errors: Vec::new(),
                 })),
             }
         } else {
             Ok(Response::new(MakeVolumesResponse {
                 success: false,
                 error: Some(DiskError::other("can not find disk".to_string()).into()),
+                errors: Vec::new(),
             }))
         }
     }
@@ -1426,6 +1445,62 @@ impl Node for NodeService {
         }
     }
 
+    async fn stat_paths(&self, request: Request<StatPathsRequest>) -> Result<Response<StatPathsResponse>, Status> {
+        let request = request.into_inner();
+        if let Some(disk) = self.find_disk(&request.disk).await {
+            match disk.stat_paths(&request.volume, &request.paths).await {
+                Ok(file_infos) => match file_infos
+                    .iter()
+                    .map(serde_json::to_string)
+                    .collect::<serde_json::Result<Vec<_>>>()
+                {
+                    Ok(file_infos) => Ok(Response::new(StatPathsResponse {
+                        success: true,
+                        file_infos,
+                        error: None,
+                    })),
+                    Err(err) => Ok(Response::new(StatPathsResponse {
+                        success: false,
+                        file_infos: Vec::new(),
+                        error: Some(DiskError::other(format!("encode data failed: {err}")).into()),
+                    })),
+                },
+                Err(err) => Ok(Response::new(StatPathsResponse {
+                    success: false,
+                    file_infos: Vec::new(),
+                    error: Some(err.into()),
+                })),
+            }
+        } else {
+            Ok(Response::new(StatPathsResponse {
+                success: false,
+                file_infos: Vec::new(),
+                error: Some(DiskError::other("can not find disk".to_string()).into()),
+            }))
+        }
+    }
+
+    async fn truncate(&self, request: Request<TruncateRequest>) -> Result<Response<TruncateResponse>, Status> {
+        let request = request.into_inner();
+        if let Some(disk) = self.find_disk(&request.disk).await {
+            match disk.truncate(&request.volume, &request.path, request.size).await {
+                Ok(_) => Ok(Response::new(TruncateResponse {
+                    success: true,
+                    error: None,
+                })),
+                Err(err) => Ok(Response::new(TruncateResponse {
+                    success: false,
+                    error: Some(err.into()),
+                })),
+            }
+        } else {
+            Ok(Response::new(TruncateResponse {
+                success: false,
+                error: Some(DiskError::other("can not find disk".to_string()).into()),
+            }))
+        }
+    }
+
     async fn lock(&self, request: Request<GenerallyLockRequest>) -> Result<Response<GenerallyLockResponse>, Status> {
         let request = request.into_inner();
         // Parse the request to extract resource and owner